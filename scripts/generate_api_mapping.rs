@@ -12,14 +12,16 @@ pub struct ApiFieldMapping {
     pub endpoint: &'static str,
     pub method: &'static str,
     pub json_path: &'static str,
+    pub read_endpoint: Option<&'static str>,
+    pub read_json_path: &'static str,
 }
 
 // Helper to resolve $ref pointers (keep as is)
 fn resolve_ref<'a>(spec: &'a Value, ref_path: &str) -> &'a Value {
     // ... (keep existing implementation) ...
-    let mut parts = ref_path.trim_start_matches("#/").split('/');
+    let parts = ref_path.trim_start_matches("#/").split('/');
     let mut current = spec;
-    while let Some(part) = parts.next() {
+    for part in parts {
         // Handle potential URL encoding in parts (e.g., "~1" for "/")
         let decoded_part = part.replace("~1", "/").replace("~0", "~");
         current = current.get(&decoded_part).unwrap_or_else(|| panic!("Failed to resolve ref part: '{}' in path '{}'", decoded_part, ref_path));
@@ -27,6 +29,52 @@ fn resolve_ref<'a>(spec: &'a Value, ref_path: &str) -> &'a Value {
     current
 }
 
+// Follow a $ref, if the schema is one
+fn deref<'a>(spec: &'a Value, schema: &'a Value) -> &'a Value {
+    match schema.get("$ref").and_then(|v| v.as_str()) {
+        Some(ref_path) => resolve_ref(spec, ref_path),
+        None => schema,
+    }
+}
+
+// Top-level properties of a schema, following $ref and allOf
+fn schema_properties<'a>(spec: &'a Value, schema: &'a Value, props: &mut HashMap<String, &'a Value>) {
+    let schema = deref(spec, schema);
+    if let Some(own) = schema.get("properties").and_then(|p| p.as_object()) {
+        props.extend(own.iter().map(|(name, prop)| (name.clone(), deref(spec, prop))));
+    }
+    if let Some(parts) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for part in parts {
+            schema_properties(spec, part, props);
+        }
+    }
+}
+
+// Properties of the object returned by GET on a path, if there is one. Paths that return lists
+// (e.g. collection endpoints that are POSTed to) have none.
+fn get_response_properties<'a>(spec: &'a Value, methods: &'a Value) -> HashMap<String, &'a Value> {
+    let mut props = HashMap::new();
+    let schema = methods
+        .get("get")
+        .and_then(|get| get.pointer("/responses/200/content/application~1json/schema"));
+    if let Some(schema) = schema {
+        schema_properties(spec, schema, &mut props);
+    }
+    props
+}
+
+// Where GET returns a written field. Booleans that are written as-is but read back wrapped, like
+// branch protection's `enforce_admins` (`{"url": ..., "enabled": true}`), are read from `enabled`.
+fn read_path(field_name: &str, write_schema: &Value, read_schema: &Value) -> String {
+    let written_bool = write_schema.get("type").and_then(|t| t.as_str()) == Some("boolean");
+    let read_enabled = read_schema.pointer("/properties/enabled").is_some();
+    if written_bool && read_enabled {
+        format!("{}.enabled", field_name)
+    } else {
+        field_name.to_string()
+    }
+}
+
 // Basic inference of resource type from path (keep as is)
 fn infer_resource_type(endpoint_path: &str) -> &'static str {
      // Handle {owner} as well as {org}
@@ -79,6 +127,8 @@ fn main() {
         for (endpoint_path, methods) in paths {
             let endpoint_str: &'static str = Box::leak(endpoint_path.clone().into_boxed_str());
             let resource_type_str = infer_resource_type(endpoint_path);
+            // Fields that GET on the same path returns, so their current values can be read back
+            let readable = get_response_properties(&spec, methods);
 
 
             for (method, method_obj) in methods.as_object().unwrap() {
@@ -100,9 +150,13 @@ fn main() {
                                 }
 
                                 if let Some(props) = schema_obj.get("properties").and_then(|p| p.as_object()) {
-                                    for (field_name, _field_schema) in props {
+                                    for (field_name, field_schema) in props {
                                         let config_key_str: &'static str = Box::leak(field_name.clone().into_boxed_str());
                                         let json_path_str: &'static str = config_key_str;
+                                        let read_json_path_str: &'static str = match readable.get(field_name) {
+                                            Some(read_schema) => Box::leak(read_path(field_name, deref(&spec, field_schema), read_schema).into_boxed_str()),
+                                            None => json_path_str,
+                                        };
 
                                         let current_mapping = ApiFieldMapping {
                                              resource_type: resource_type_str, // Use inferred type
@@ -110,22 +164,20 @@ fn main() {
                                             endpoint: endpoint_str,
                                             method: method_upper,
                                             json_path: json_path_str,
+                                            read_endpoint: readable.contains_key(field_name).then_some(endpoint_str),
+                                            read_json_path: read_json_path_str,
                                         };
 
                                         // --- Prioritization Logic ---
                                         let should_insert = match generated_map.get(field_name) {
                                             Some(existing_mapping) => {
-                                                // Check if the current one is the preferred repo update type
-                                                let is_current_preferred = endpoint_path.starts_with(preferred_repo_update_path_start)
-                                                                             && method_upper == preferred_repo_update_method;
                                                 // Check if the existing one is the preferred repo update type
                                                 let is_existing_preferred = existing_mapping.endpoint.starts_with(preferred_repo_update_path_start)
                                                                               && existing_mapping.method == preferred_repo_update_method;
 
-                                                // Prefer the current one ONLY if it's the preferred type AND the existing one is NOT
-                                                is_current_preferred && !is_existing_preferred
-                                                // OR if neither is preferred, fall back to overwriting (last seen wins among non-preferred)
-                                                 || (!is_current_preferred && !is_existing_preferred)
+                                                // Never replace a preferred mapping; otherwise last seen wins
+                                                // (a preferred current mapping always replaces a non-preferred one).
+                                                !is_existing_preferred
                                             }
                                             None => true, // No existing mapping, always insert
                                         };
@@ -150,6 +202,8 @@ fn main() {
     println!("// Please run `make generate-api-mappings` to regenerate.");
     println!("// This generator prioritizes PATCH /repos/{{owner}}/{{repo}} for repo settings."); // Added note
     println!("// WARNING: Collisions for other keys might still use the *last processed* mapping.");
+    println!("// read_endpoint is set when GET on the same path returns the field; otherwise it can't be read back.");
+    println!("// read_json_path is where that GET response holds the field, which can differ from json_path.");
     println!("use std::collections::HashMap;");
     println!("use crate::api_mapping::ApiFieldMapping;");
     println!("pub fn get_github_api_mapping() -> HashMap<&'static str, ApiFieldMapping> {{");
@@ -160,15 +214,21 @@ fn main() {
 
     for key in sorted_keys {
         if let Some(mapping) = generated_map.get(key) {
+             let read_endpoint = match mapping.read_endpoint {
+                 Some(endpoint) => format!("Some(\"{}\")", endpoint.escape_default()),
+                 None => "None".to_string(),
+             };
              println!(
                  // Use mapping.endpoint etc. correctly
-                "    map.insert(\"{}\", ApiFieldMapping {{ resource_type: \"{}\", config_key: \"{}\", endpoint: \"{}\", method: \"{}\", json_path: \"{}\" }});",
+                "    map.insert(\"{}\", ApiFieldMapping {{ resource_type: \"{}\", config_key: \"{}\", endpoint: \"{}\", method: \"{}\", json_path: \"{}\", read_endpoint: {}, read_json_path: \"{}\" }});",
                 mapping.config_key.escape_default(),
                 mapping.resource_type.escape_default(), // Use mapping's resource_type
                 mapping.config_key.escape_default(),
                 mapping.endpoint.escape_default(),
                 mapping.method.escape_default(),
-                mapping.json_path.escape_default()
+                mapping.json_path.escape_default(),
+                read_endpoint,
+                mapping.read_json_path.escape_default()
             );
         }
     }
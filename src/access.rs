@@ -0,0 +1,268 @@
+//! Effective access report: who can do what on which repository, and why.
+//!
+//! The matrix is computed from a `Config` generated from GitHub, so every path to a repository is
+//! taken into account: org owners, the org base permission, team assignments (including those
+//! inherited from parent teams) and direct collaborator grants.
+
+use crate::config::{Config, MembershipState, Permission, Team};
+use crate::github::{slugify_team_name, team_ref_matches};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Output format of the access report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+/// A user's effective permission on a repository, with every path that grants access.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccessEntry {
+    pub login: String,
+    pub repo: String,
+    /// The highest permission granted by any of the sources.
+    pub permission: Permission,
+    /// Human-readable descriptions of each grant, e.g. `team core (write)`.
+    pub sources: Vec<String>,
+}
+
+/// Rank a permission for comparison. Custom roles rank as their base role when it is known,
+/// and as `read` otherwise, since every custom role includes read access.
+fn permission_rank(permission: &Permission, custom_bases: &HashMap<String, Permission>) -> u8 {
+    match permission {
+        Permission::Read => 1,
+        Permission::Triage => 2,
+        Permission::Write => 3,
+        Permission::Maintain => 4,
+        Permission::Admin => 5,
+        Permission::Custom(name) => match custom_bases.get(name) {
+            Some(Permission::Custom(_)) | None => 1,
+            Some(base) => permission_rank(base, custom_bases),
+        },
+    }
+}
+
+fn team_slug(team: &Team) -> String {
+    team.slug.clone().unwrap_or_else(|| slugify_team_name(&team.name))
+}
+
+/// Members and maintainers of `team` and all of its descendants, each with the team they belong
+/// to directly.
+fn inherited_members<'a>(team: &'a Team, teams: &'a [Team]) -> Vec<(&'a str, &'a Team)> {
+    let mut result = Vec::new();
+    let mut queue = vec![team];
+    let mut seen = vec![team_slug(team)];
+    while let Some(current) = queue.pop() {
+        for login in current.members.iter().chain(current.maintainers.iter().flatten()) {
+            result.push((login.as_str(), current));
+        }
+        let slug = team_slug(current);
+        for child in teams {
+            let is_child = child.parent.as_deref().is_some_and(|p| team_ref_matches(p, &current.name, &slug));
+            if is_child && !seen.contains(&team_slug(child)) {
+                seen.push(team_slug(child));
+                queue.push(child);
+            }
+        }
+    }
+    result
+}
+
+///
+/// Compute every user's effective permission on every repository in `config`.
+///
+/// # Arguments
+/// * `config` - Org state generated from GitHub.
+/// * `base_permission` - The org's base repository permission for members, if any.
+/// * `custom_bases` - Base role of each custom repository role, used to rank custom roles.
+///
+/// # Returns
+/// Entries sorted by login and repository. Users without access to a repository are omitted.
+///
+pub fn compute_access_matrix(
+    config: &Config,
+    base_permission: Option<&Permission>,
+    custom_bases: &HashMap<String, Permission>,
+) -> Vec<AccessEntry> {
+    let mut grants: BTreeMap<(String, String), Vec<(Permission, String)>> = BTreeMap::new();
+    // Report logins as GitHub spells them for members, or as first seen for everyone else.
+    let mut spelling: HashMap<String, String> = config.users.iter().map(|u| (u.login.to_lowercase(), u.login.clone())).collect();
+    let mut grant = |login: &str, repo: &str, permission: &Permission, source: String| {
+        spelling.entry(login.to_lowercase()).or_insert_with(|| login.to_string());
+        grants
+            .entry((login.to_lowercase(), repo.to_string()))
+            .or_default()
+            .push((permission.clone(), format!("{} ({})", source, permission)));
+    };
+
+    let members = config.users.iter().filter(|u| !u.login.is_empty() && u.state != Some(MembershipState::Pending));
+    for user in members {
+        for repo in &config.repos {
+            if user.role == "admin" {
+                grant(&user.login, &repo.name, &Permission::Admin, "org owner".to_string());
+            } else if let Some(base) = base_permission {
+                grant(&user.login, &repo.name, base, "org base permission".to_string());
+            }
+        }
+    }
+
+    for assignment in &config.assignments {
+        let Some(team) = config.teams.iter().find(|t| team_ref_matches(&assignment.team, &t.name, &team_slug(t))) else {
+            continue;
+        };
+        let slug = team_slug(team);
+        for (login, via) in inherited_members(team, &config.teams) {
+            let source = if std::ptr::eq(via, team) {
+                format!("team {}", slug)
+            } else {
+                format!("team {} via {}", slug, team_slug(via))
+            };
+            grant(login, &assignment.repo, &assignment.permission, source);
+        }
+    }
+
+    for repo in &config.repos {
+        for collaborator in repo.collaborators.iter().flatten() {
+            grant(&collaborator.login, &repo.name, &collaborator.permission, "direct collaborator".to_string());
+        }
+    }
+
+    grants
+        .into_iter()
+        .map(|((login, repo), mut sources)| {
+            sources.sort_by(|a, b| permission_rank(&b.0, custom_bases).cmp(&permission_rank(&a.0, custom_bases)).then(a.1.cmp(&b.1)));
+            sources.dedup_by(|a, b| a.1 == b.1);
+            AccessEntry {
+                login: spelling.get(&login).cloned().unwrap_or(login),
+                repo,
+                permission: sources[0].0.clone(),
+                sources: sources.into_iter().map(|(_, source)| source).collect(),
+            }
+        })
+        .collect()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render the access matrix in the given format.
+pub fn render_access_matrix(entries: &[AccessEntry], format: ReportFormat) -> crate::error::AppResult<String> {
+    let mut output = String::new();
+    match format {
+        ReportFormat::Csv => {
+            output.push_str("login,repo,permission,sources\n");
+            for entry in entries {
+                output.push_str(&format!(
+                    "{},{},{},{}\n",
+                    csv_field(&entry.login),
+                    csv_field(&entry.repo),
+                    csv_field(entry.permission.as_str()),
+                    csv_field(&entry.sources.join("; "))
+                ));
+            }
+        }
+        ReportFormat::Json => {
+            output = serde_json::to_string_pretty(entries)?;
+            output.push('\n');
+        }
+        ReportFormat::Markdown => {
+            output.push_str("| User | Repository | Permission | Sources |\n");
+            output.push_str("|------|------------|------------|---------|\n");
+            for entry in entries {
+                output.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    entry.login,
+                    entry.repo,
+                    entry.permission,
+                    entry.sources.join("<br>").replace('|', "\\|")
+                ));
+            }
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Assignment, Collaborator, Repo, User};
+
+    fn user(login: &str, role: &str) -> User {
+        User { login: login.to_string(), role: role.to_string(), ..Default::default() }
+    }
+
+    fn sample_config() -> Config {
+        Config {
+            repos: vec![
+                Repo { name: "api".to_string(), ..Default::default() },
+                Repo {
+                    name: "web".to_string(),
+                    collaborators: Some(vec![Collaborator { login: "contractor".to_string(), permission: Permission::Admin }]),
+                    ..Default::default()
+                },
+            ],
+            teams: vec![
+                Team { name: "Engineering".to_string(), slug: Some("engineering".to_string()), members: vec!["bob".to_string()], ..Default::default() },
+                Team {
+                    name: "Core".to_string(),
+                    slug: Some("core".to_string()),
+                    maintainers: Some(vec!["carol".to_string()]),
+                    parent: Some("engineering".to_string()),
+                    ..Default::default()
+                },
+            ],
+            users: vec![
+                user("alice", "admin"),
+                user("bob", "member"),
+                user("carol", "member"),
+                User { state: Some(MembershipState::Pending), ..user("dave", "member") },
+            ],
+            assignments: vec![
+                Assignment { repo: "api".to_string(), team: "engineering".to_string(), permission: Permission::Write },
+                Assignment { repo: "api".to_string(), team: "core".to_string(), permission: Permission::Custom("deployer".to_string()) },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compute_access_matrix() {
+        let custom_bases = HashMap::from([("deployer".to_string(), Permission::Maintain)]);
+        let entries = compute_access_matrix(&sample_config(), Some(&Permission::Read), &custom_bases);
+        let find = |login: &str, repo: &str| entries.iter().find(|e| e.login == login && e.repo == repo).cloned();
+
+        assert_eq!(find("alice", "web").unwrap().permission, Permission::Admin);
+        assert_eq!(find("bob", "api").unwrap().sources, vec!["team engineering (write)", "org base permission (read)"]);
+        assert_eq!(find("bob", "web").unwrap().permission, Permission::Read);
+        let carol = find("carol", "api").unwrap();
+        assert_eq!(carol.permission, Permission::Custom("deployer".to_string()));
+        assert_eq!(carol.sources[1], "team engineering via core (write)");
+        assert_eq!(find("contractor", "web").unwrap().sources, vec!["direct collaborator (admin)"]);
+        assert!(find("contractor", "api").is_none());
+        assert!(find("dave", "api").is_none());
+    }
+
+    #[test]
+    fn test_render_access_matrix() {
+        let entries = vec![AccessEntry {
+            login: "bob".to_string(),
+            repo: "api".to_string(),
+            permission: Permission::Write,
+            sources: vec!["team engineering (write)".to_string(), "org base permission (read)".to_string()],
+        }];
+        let csv = render_access_matrix(&entries, ReportFormat::Csv).unwrap();
+        assert_eq!(csv, "login,repo,permission,sources\nbob,api,write,team engineering (write); org base permission (read)\n");
+        let json = render_access_matrix(&entries, ReportFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["permission"], "write");
+        let markdown = render_access_matrix(&entries, ReportFormat::Markdown).unwrap();
+        assert!(markdown.ends_with("| bob | api | write | team engineering (write)<br>org base permission (read) |\n"));
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use crate::github_api_mapping_generated::get_github_api_mapping;
 
 /// Describes how to map a config field to a GitHub API call.
 #[derive(Debug, Clone)]
@@ -10,6 +11,8 @@ pub struct ApiFieldMapping {
     pub endpoint: &'static str,      // e.g., "/repos/{org}/{repo}"
     pub method: &'static str,        // "PATCH", "PUT", etc.
     pub json_path: &'static str,     // e.g., "allow_merge_commit"
+    pub read_endpoint: Option<&'static str>, // GET endpoint returning the current value; None if it can't be read back
+    pub read_json_path: &'static str, // Dot-separated path to the value in the read response
     // Add more fields as needed for transformation, etc.
 }
 
@@ -24,6 +27,8 @@ pub fn get_repo_settings_mapping() -> HashMap<&'static str, ApiFieldMapping> {
         endpoint: "/repos/{org}/{repo}",
         method: "PATCH",
         json_path: "allow_merge_commit",
+        read_endpoint: Some("/repos/{org}/{repo}"),
+        read_json_path: "allow_merge_commit",
     });
     map.insert("allow_squash_merge", ApiFieldMapping {
         resource_type: "repo",
@@ -31,6 +36,8 @@ pub fn get_repo_settings_mapping() -> HashMap<&'static str, ApiFieldMapping> {
         endpoint: "/repos/{org}/{repo}",
         method: "PATCH",
         json_path: "allow_squash_merge",
+        read_endpoint: Some("/repos/{org}/{repo}"),
+        read_json_path: "allow_squash_merge",
     });
     map.insert("allow_rebase_merge", ApiFieldMapping {
         resource_type: "repo",
@@ -38,7 +45,73 @@ pub fn get_repo_settings_mapping() -> HashMap<&'static str, ApiFieldMapping> {
         endpoint: "/repos/{org}/{repo}",
         method: "PATCH",
         json_path: "allow_rebase_merge",
+        read_endpoint: Some("/repos/{org}/{repo}"),
+        read_json_path: "allow_rebase_merge",
     });
     // Add more fields as needed...
     map
-}
\ No newline at end of file
+}
+
+/// Organization fields that GitHub reports but that can't be changed through the API.
+/// They can be listed under `org_settings` so drift is reported, but sync never applies them.
+pub const ORG_READ_ONLY_SETTINGS: &[&str] = &["two_factor_requirement_enabled"];
+
+/// Organization profile fields accepted by `PATCH /orgs/{org}`. The generated mapping is keyed by
+/// field name alone, so these lose out to the same names on other endpoints (e.g. `/user`).
+const ORG_PROFILE_FIELDS: &[&str] = &[
+    "name",
+    "company",
+    "blog",
+    "email",
+    "twitter_username",
+    "location",
+    "description",
+    "web_commit_signoff_required",
+];
+
+/// Returns the mapping table for organization settings: every generated mapping that targets
+/// `PATCH /orgs/{org}`, plus the profile fields that collide with other endpoints.
+pub fn get_org_settings_mapping() -> HashMap<&'static str, ApiFieldMapping> {
+    let mut map: HashMap<&'static str, ApiFieldMapping> = get_github_api_mapping()
+        .into_iter()
+        .filter(|(_, m)| m.endpoint == "/orgs/{org}" && m.method == "PATCH")
+        .collect();
+    for &key in ORG_PROFILE_FIELDS {
+        map.insert(key, ApiFieldMapping {
+            resource_type: "org",
+            config_key: key,
+            endpoint: "/orgs/{org}",
+            method: "PATCH",
+            json_path: key,
+            read_endpoint: Some("/orgs/{org}"),
+            read_json_path: key,
+        });
+    }
+    map
+}
+
+/// Code security configuration fields that the generated mapping attributes to other endpoints.
+const CODE_SECURITY_CONFIGURATION_EXTRA_FIELDS: &[&str] = &["enforcement"];
+
+/// Returns the mapping table for code security configuration settings: every generated mapping
+/// that targets `PATCH /orgs/{org}/code-security/configurations/{configuration_id}`, plus the
+/// fields that collide with other endpoints.
+pub fn get_code_security_configuration_mapping() -> HashMap<&'static str, ApiFieldMapping> {
+    const ENDPOINT: &str = "/orgs/{org}/code-security/configurations/{configuration_id}";
+    let mut map: HashMap<&'static str, ApiFieldMapping> = get_github_api_mapping()
+        .into_iter()
+        .filter(|(_, m)| m.endpoint == ENDPOINT && m.method == "PATCH")
+        .collect();
+    for &key in CODE_SECURITY_CONFIGURATION_EXTRA_FIELDS {
+        map.insert(key, ApiFieldMapping {
+            resource_type: "org",
+            config_key: key,
+            endpoint: ENDPOINT,
+            method: "PATCH",
+            json_path: key,
+            read_endpoint: Some(ENDPOINT),
+            read_json_path: key,
+        });
+    }
+    map
+}
@@ -1,14 +1,15 @@
-///
-/// Configuration models for gh-config-cli.
-///
-/// This module defines the data structures used for representing repository, team, user, and webhook
-/// configuration. All structs are serializable/deserializable for use with YAML and JSON configuration files.
-///
+//!
+//! Configuration models for gh-config-cli.
+//!
+//! This module defines the data structures used for representing repository, team, user, and webhook
+//! configuration. All structs are serializable/deserializable for use with YAML and JSON configuration files.
+//!
 
 use serde::{Deserialize, Serialize};
 
 use serde_yaml::Value;
 use std::collections::{HashMap, BTreeMap};
+use std::fmt;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 ///
@@ -24,11 +25,92 @@ pub struct WebhookConfig {
     pub content_type: String,
     /// List of events that trigger the webhook.
     pub events: Vec<String>,
+    /// Where to read the secret used to sign payloads. GitHub never returns it, so it is sent
+    /// whenever the hook is created or updated but changes to it alone are not detected.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "serde_yaml::with::singleton_map")]
+    pub secret: Option<SecretRef>,
+    /// Whether deliveries are sent. Defaults to true.
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub active: bool,
+    /// Skip TLS certificate verification when delivering payloads. Defaults to false.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub insecure_ssl: bool,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        WebhookConfig {
+            url: String::new(),
+            content_type: "json".to_string(),
+            events: Vec::new(),
+            secret: None,
+            active: true,
+            insecure_ssl: false,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Reference to a secret value that is kept out of the config file.
+///
+/// Written as `{ env: NAME }` to read an environment variable, or `{ file: PATH }` to read a file
+/// (trailing newlines are trimmed).
+///
+pub enum SecretRef {
+    Env(String),
+    File(String),
+}
+
+impl SecretRef {
+    /// Reads the secret value.
+    pub fn resolve(&self) -> crate::error::AppResult<String> {
+        match self {
+            SecretRef::Env(name) => std::env::var(name)
+                .map_err(|_| crate::error::AppError::Validation(format!("Environment variable '{}' for secret is not set", name))),
+            SecretRef::File(path) => std::fs::read_to_string(path)
+                .map(|value| value.trim_end_matches(['\n', '\r']).to_string())
+                .map_err(crate::error::AppError::Io),
+        }
+    }
+}
+
+/// Accepts either a single item or a list of items, so the old single-`webhook` form keeps working.
+fn one_or_many<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+    Ok(Option::<OneOrMany<T>>::deserialize(deserializer)?.map(|value| match value {
+        OneOrMany::Many(items) => items,
+        OneOrMany::One(item) => vec![item],
+    }))
 }
 
 // Extensible settings: arbitrary key-value pairs for repo settings
 /// Arbitrary key-value pairs for repository settings (extensible).
 pub type RepoSettings = BTreeMap<String, Value>;
+/// Organization settings, keyed by GitHub API field name (e.g. `billing_email`).
+pub type OrgSettings = BTreeMap<String, Value>;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 ///
@@ -62,52 +144,857 @@ impl Default for BranchProtectionRule {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+///
+/// A user granted access to a repository directly rather than through a team.
+///
+pub struct Collaborator {
+    /// The user's GitHub login.
+    pub login: String,
+    /// The permission granted on the repository.
+    pub permission: Permission,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Which repositories can use an organization Actions secret or variable.
+///
+pub enum ActionsVisibility {
+    All,
+    Private,
+    Selected,
+}
+
+impl ActionsVisibility {
+    /// Returns the value used by the GitHub API for this visibility.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActionsVisibility::All => "all",
+            ActionsVisibility::Private => "private",
+            ActionsVisibility::Selected => "selected",
+        }
+    }
+}
+
+impl fmt::Display for ActionsVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+///
+/// A GitHub Actions secret.
+///
+/// The value is read from an environment variable or a file when it is sent, and is never written
+/// to the config. `visibility` and `selected_repositories` only apply to organization secrets.
+///
+pub struct ActionsSecret {
+    /// Name of the secret. GitHub stores names in upper case.
+    pub name: String,
+    /// Where to read the value, as `{ env: NAME }` or `{ file: PATH }`.
+    #[serde(with = "serde_yaml::with::singleton_map")]
+    pub value: SecretRef,
+    /// Which repositories can use an organization secret. Defaults to `private`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ActionsVisibility>,
+    /// Repositories that can use an organization secret whose visibility is `selected`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_repositories: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// A GitHub Actions configuration variable.
+///
+/// `visibility` and `selected_repositories` only apply to organization variables.
+///
+pub struct ActionsVariable {
+    /// Name of the variable. GitHub stores names in upper case.
+    pub name: String,
+    /// Value of the variable.
+    pub value: String,
+    /// Which repositories can use an organization variable. Defaults to `private`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ActionsVisibility>,
+    /// Repositories that can use an organization variable whose visibility is `selected`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_repositories: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// Users and teams who must approve deployments to an environment. GitHub allows up to six in total.
+///
+pub struct EnvironmentReviewers {
+    /// Logins of reviewing users.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<String>,
+    /// Reviewing teams, by display name or slug.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub teams: Vec<String>,
+}
+
+impl EnvironmentReviewers {
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty() && self.teams.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// Which branches can deploy to an environment: protected branches, or branches matching custom
+/// name patterns. Only one of the two can be used.
+///
+pub struct DeploymentBranchPolicy {
+    /// Only branches with branch protection rules can deploy.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub protected_branches: bool,
+    /// Branch name patterns (e.g. `release/*`) that can deploy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_branches: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// A deployment environment and its protection rules.
+///
+/// The protection rules listed are the complete set: a rule that is left out is removed.
+///
+pub struct Environment {
+    /// Name of the environment.
+    pub name: String,
+    /// Minutes to wait before deployments to the environment proceed (up to 43200).
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub wait_timer: u32,
+    /// Users and teams who must approve deployments.
+    #[serde(default, skip_serializing_if = "EnvironmentReviewers::is_empty")]
+    pub reviewers: EnvironmentReviewers,
+    /// Prevent the user who triggered a deployment from approving it.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub prevent_self_review: bool,
+    /// Branches that can deploy. Leaving it unset allows all branches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+    /// Environment secrets, matched by name. Leaving it unset means they are not managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionsSecret>>,
+    /// Environment variables, matched by name. Leaving it unset means they are not managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Vec<ActionsVariable>>,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Type of the values of a custom property.
+///
+pub enum CustomPropertyType {
+    String,
+    SingleSelect,
+    MultiSelect,
+    TrueFalse,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Who can set a custom property's value on a repository.
+///
+pub enum PropertyEditors {
+    OrgActors,
+    OrgAndRepoActors,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+///
+/// A custom property that repositories of the organization can have, matched by name.
+///
+pub struct CustomProperty {
+    pub name: String,
+    pub value_type: CustomPropertyType,
+    /// Whether every repository must have a value. Required properties need a `default_value`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+    /// Value of repositories that don't set one; a list for `multi_select` properties.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Values a `single_select` or `multi_select` property can take.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_values: Vec<String>,
+    /// Defaults to `org_actors`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values_editable_by: Option<PropertyEditors>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Which new repositories a code security configuration is applied to by default.
+///
+pub enum DefaultForNewRepos {
+    All,
+    None,
+    PrivateAndInternal,
+    Public,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Repositories a code security configuration is attached to, selected by visibility.
+///
+pub enum AttachScope {
+    All,
+    Public,
+    PrivateOrInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// A code security configuration of the organization, matched by name.
+///
+pub struct CodeSecurityConfiguration {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Fields of the code security configuration API, e.g. `secret_scanning: enabled`. Fields left
+    /// unset are not managed.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub settings: BTreeMap<String, Value>,
+    /// New repositories the configuration applies to. Leaving it unset means it is not managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_for_new_repos: Option<DefaultForNewRepos>,
+    /// Attach the configuration to all repositories with this visibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attach_to: Option<AttachScope>,
+    /// Repositories the configuration is attached to, by name. Unless `attach_to` or this list is
+    /// set, attachment is not managed; with `--prune`, other repositories are detached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repositories: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// Security and analysis features of a repository. Fields left unset are not managed.
+///
+pub struct RepoSecurity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<bool>,
+    /// Block pushes that contain supported secrets. Requires secret scanning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_push_protection: Option<bool>,
+    /// Scan for generic secrets such as private keys, in addition to provider patterns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_non_provider_patterns: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependabot_alerts: Option<bool>,
+    /// Automatic pull requests for vulnerable dependencies. Requires Dependabot alerts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependabot_security_updates: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_vulnerability_reporting: Option<bool>,
+    /// Whether CodeQL default setup is configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_scanning_default_setup: Option<bool>,
+}
+
+impl RepoSecurity {
+    /// Fill the fields left unset with those of `default`.
+    pub fn or(&self, default: &RepoSecurity) -> RepoSecurity {
+        RepoSecurity {
+            secret_scanning: self.secret_scanning.or(default.secret_scanning),
+            secret_scanning_push_protection: self.secret_scanning_push_protection.or(default.secret_scanning_push_protection),
+            secret_scanning_non_provider_patterns: self.secret_scanning_non_provider_patterns.or(default.secret_scanning_non_provider_patterns),
+            dependabot_alerts: self.dependabot_alerts.or(default.dependabot_alerts),
+            dependabot_security_updates: self.dependabot_security_updates.or(default.dependabot_security_updates),
+            private_vulnerability_reporting: self.private_vulnerability_reporting.or(default.private_vulnerability_reporting),
+            code_scanning_default_setup: self.code_scanning_default_setup.or(default.code_scanning_default_setup),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Which actions and reusable workflows may run.
+///
+pub enum AllowedActions {
+    All,
+    LocalOnly,
+    Selected,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Default permissions granted to the `GITHUB_TOKEN` in workflows.
+///
+pub enum WorkflowPermissions {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Which outside contributors need approval before workflows run on their fork pull requests.
+///
+pub enum ForkPrApproval {
+    FirstTimeContributorsNewToGithub,
+    FirstTimeContributors,
+    AllExternalContributors,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Which repositories of the organization can use GitHub Actions.
+///
+pub enum EnabledRepositories {
+    All,
+    None,
+    Selected,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Which repositories outside a private repository may use its actions and reusable workflows.
+///
+pub enum ActionsAccessLevel {
+    None,
+    User,
+    Organization,
+    Enterprise,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// Actions allowed when `allowed_actions` is `selected`, besides those in the org's own repositories.
+///
+pub struct SelectedActions {
+    /// Allow actions created by GitHub.
+    #[serde(default)]
+    pub github_owned_allowed: bool,
+    /// Allow actions by verified Marketplace creators.
+    #[serde(default)]
+    pub verified_allowed: bool,
+    /// Patterns of further allowed actions, e.g. `my-org/*` or `docker/login-action@v3`.
+    #[serde(default)]
+    pub patterns_allowed: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// GitHub Actions policy shared by repositories and the organization. Fields left unset are not
+/// managed.
+///
+pub struct ActionsPolicy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /// Only applies when `allowed_actions` is `selected`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_actions: Option<SelectedActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_workflow_permissions: Option<WorkflowPermissions>,
+    /// Whether workflows may create and approve pull requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_approve_pull_request_reviews: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fork_pr_approval: Option<ForkPrApproval>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// GitHub Actions settings of a repository.
+///
+pub struct RepoActions {
+    /// Whether Actions can run in the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Access to the repository's actions from other repositories. Only applies to private and
+    /// internal repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_level: Option<ActionsAccessLevel>,
+    #[serde(flatten)]
+    pub policy: ActionsPolicy,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// GitHub Actions settings of the organization.
+///
+pub struct OrgActions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled_repositories: Option<EnabledRepositories>,
+    /// Repositories that can use Actions when `enabled_repositories` is `selected`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_repositories: Vec<String>,
+    #[serde(flatten)]
+    pub policy: ActionsPolicy,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// A deploy key, matched by title.
+///
+/// GitHub can't change a deploy key, so one whose key or access changes is deleted and added again.
+///
+pub struct DeployKey {
+    pub title: String,
+    /// Public key in OpenSSH format, e.g. `ssh-ed25519 AAAA...`. Either this or `key_file` is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// File to read the public key from, such as the `.pub` file written by `rotate-deploy-key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_file: Option<String>,
+    /// Whether the key can only pull. Defaults to true.
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub read_only: bool,
+}
+
+impl DeployKey {
+    /// The public key as GitHub stores it: key type and key data, without the comment.
+    pub fn public_key(&self) -> crate::error::AppResult<String> {
+        let key = match (&self.key, &self.key_file) {
+            (Some(key), None) => key.clone(),
+            (None, Some(path)) => std::fs::read_to_string(path).map_err(crate::error::AppError::Io)?,
+            _ => {
+                return Err(crate::error::AppError::Validation(format!(
+                    "Deploy key '{}' needs exactly one of key or key_file",
+                    self.title
+                )))
+            }
+        };
+        Ok(key.split_whitespace().take(2).collect::<Vec<_>>().join(" "))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// An issue and pull request label, matched by name (case-insensitively).
+///
+pub struct Label {
+    pub name: String,
+    /// Hex color without the leading `#`, e.g. `d73a4a`.
+    pub color: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Former name of the label. An existing label with this name is renamed instead of a new one
+    /// being created, so issues keep it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+///
+/// An autolink reference, matched by key prefix. GitHub can't update autolinks, so a changed one
+/// is deleted and created again.
+///
+pub struct Autolink {
+    /// Prefix that triggers the link, e.g. `TICKET-`.
+    pub key_prefix: String,
+    /// URL with a `<num>` placeholder for the reference, e.g. `https://example.com/TICKET?q=<num>`.
+    pub url_template: String,
+    /// Whether the reference may contain letters as well as digits. Defaults to true.
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub is_alphanumeric: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// State of a milestone.
+///
+pub enum MilestoneState {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+///
+/// A milestone, matched by title.
+///
+pub struct Milestone {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Due date as `YYYY-MM-DD`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<String>,
+    /// Leaving it unset means the state is not managed; new milestones are open.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<MilestoneState>,
+    /// Former title of the milestone, which is renamed instead of a new one being created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_title: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Visibility of a repository.
+///
+/// `internal` is only available to organizations on GitHub Enterprise Cloud or GitHub Enterprise Server.
+///
+pub enum Visibility {
+    Public,
+    Private,
+    Internal,
+}
+
+impl Visibility {
+    /// Returns the value used by the GitHub API for this visibility.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Private => "private",
+            Visibility::Internal => "internal",
+        }
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 ///
 /// Repository configuration.
 ///
 /// Represents the configuration for a single repository, including its name,
-/// settings, visibility, webhook, branch protections, and any extra fields.
+/// settings, visibility, lifecycle, collaborators, webhooks, branch protections, and any extra fields.
 ///
 pub struct Repo {
     /// Name of the repository.
     pub name: String,
     #[serde(default = "RepoSettings::new")]
     pub settings: RepoSettings, // Now extensible
+    /// Short description. Leaving it unset means it is not managed; an empty string clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Homepage URL. Leaving it unset means it is not managed; an empty string clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// Topics, which GitHub stores in lower case. Leaving it unset means topics are not managed;
+    /// an empty list removes all of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topics: Option<Vec<String>>,
+    /// Default branch. If a branch of this name exists, the default is switched to it. Otherwise
+    /// the current default branch is renamed to it, but only when `rename_default_branch_from`
+    /// names the current default branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    /// Current default branch that may be renamed to `default_branch` if no branch of that name
+    /// exists. Checked against the repo's live default branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rename_default_branch_from: Option<String>,
+    /// Desired visibility. Leaving it unset means visibility is not managed.
     #[serde(default)]
-    pub visibility: Option<String>,
-    #[serde(default)]
-    pub webhook: Option<WebhookConfig>,
+    pub visibility: Option<Visibility>,
+    /// Whether the repository should be archived. Leaving it unset means archiving is not managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// Former names of the repository. If `name` does not exist but one of these does, sync renames it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous_names: Vec<String>,
+    /// Organization (or user) the repository should be transferred to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_to: Option<String>,
+    /// Users with direct access to the repository, including pending invitations. Leaving it unset
+    /// means direct collaborators are not managed; an empty list removes all of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collaborators: Option<Vec<Collaborator>>,
+    /// Deploy keys, matched by title. Leaving it unset means deploy keys are not managed; keys on
+    /// GitHub that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deploy_keys: Option<Vec<DeployKey>>,
+    /// Webhooks, matched by URL. Also accepts a single `webhook` for older configs. Hooks on GitHub
+    /// whose URL isn't listed are deleted with `--prune`. `None` leaves the repo's hooks unmanaged
+    /// unless there is a `default_webhook`.
+    #[serde(default, alias = "webhook", deserialize_with = "one_or_many", skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<Vec<WebhookConfig>>,
+    /// Labels, matched by name. Labels on GitHub that aren't listed are deleted with `--prune`.
+    /// `None` leaves the repo's labels unmanaged unless there are `default_labels`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<Label>>,
+    /// Autolink references, matched by key prefix. Autolinks on GitHub that aren't listed are
+    /// deleted with `--prune`. `None` leaves them unmanaged unless there are `default_autolinks`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autolinks: Option<Vec<Autolink>>,
+    /// Milestones, matched by title. Milestones on GitHub that aren't listed are deleted with
+    /// `--prune`. `None` leaves them unmanaged unless there are `default_milestones`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestones: Option<Vec<Milestone>>,
+    /// Custom property values, by property name. Properties that aren't listed are left alone, and a
+    /// `null` value removes the property from the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, Value>>,
+    /// Security and analysis features. Leaving it unset means they are not managed, unless there
+    /// is a `default_security`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security: Option<RepoSecurity>,
+    /// GitHub Actions permissions. Leaving it unset means they are not managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<RepoActions>,
+    /// Actions secrets, matched by name. Leaving it unset means secrets are not managed; secrets on
+    /// GitHub that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionsSecret>>,
+    /// Actions variables, matched by name. Leaving it unset means variables are not managed;
+    /// variables on GitHub that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Vec<ActionsVariable>>,
+    /// Deployment environments, matched by name. Leaving it unset means environments are not
+    /// managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environments: Option<Vec<Environment>>,
+    /// Delete environments that aren't listed in `environments` when syncing with
+    /// `--prune environments`. Environments GitHub manages itself, such as `github-pages`, are
+    /// never deleted.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub prune_environments: bool,
     #[serde(default)]
     pub branch_protections: Vec<BranchProtectionRule>,
     #[serde(flatten)]
     pub extra: HashMap<String, Value>, // For arbitrary fields/extensions
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)] // Added Clone
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// Visibility of a team within the organization.
+///
+/// `secret` teams are only visible to owners and team members; `closed` teams are visible to all
+/// organization members. Nested teams must be `closed`.
+///
+pub enum TeamPrivacy {
+    Secret,
+    Closed,
+}
+
+impl TeamPrivacy {
+    /// Returns the value used by the GitHub API for this privacy level.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamPrivacy::Secret => "secret",
+            TeamPrivacy::Closed => "closed",
+        }
+    }
+}
+
+impl fmt::Display for TeamPrivacy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+///
+/// Whether team members receive notifications when the team is @mentioned.
+///
+pub enum TeamNotificationSetting {
+    NotificationsEnabled,
+    NotificationsDisabled,
+}
+
+impl TeamNotificationSetting {
+    /// Returns the value used by the GitHub API for this notification setting.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamNotificationSetting::NotificationsEnabled => "notifications_enabled",
+            TeamNotificationSetting::NotificationsDisabled => "notifications_disabled",
+        }
+    }
+}
+
+impl fmt::Display for TeamNotificationSetting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 ///
 /// Represents a team within the organization.
 ///
-/// Each team has a name and a list of member usernames.
+/// Each team has a name, a list of member usernames and an optional list of maintainers. Description, privacy, parent team and
+/// notification setting are optional; leaving one unset means it is not managed.
 ///
 pub struct Team {
-    /// Name of the team.
+    /// Display name of the team.
     pub name: String,
+    /// URL-friendly identifier of the team. When set, the team is looked up by slug, so changing
+    /// `name` renames the existing team instead of creating a new one. When unset, the slug is
+    /// resolved from `name` through the API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
     /// List of usernames belonging to the team.
     pub members: Vec<String>,
+    /// Usernames holding the team maintainer role. Anyone listed here is a maintainer, even if
+    /// they are also listed under `members`. Leaving it unset means team roles are not managed:
+    /// members keep whatever role they already have and new members join as `member`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintainers: Option<Vec<String>>,
+    /// Description of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Privacy level of the team (`secret` or `closed`). New teams default to `closed`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<TeamPrivacy>,
+    /// Parent team (display name or slug), for nested teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Notification setting for @mentions of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_setting: Option<TeamNotificationSetting>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)] // Added Clone
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+///
+/// State of a user's organization membership.
+///
+/// `pending` users have been invited but have not accepted yet.
+///
+pub enum MembershipState {
+    Active,
+    Pending,
+}
+
+impl MembershipState {
+    /// Returns the value used by the GitHub API for this state.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MembershipState::Active => "active",
+            MembershipState::Pending => "pending",
+        }
+    }
+}
+
+impl fmt::Display for MembershipState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)] // Added Clone
 ///
 /// Represents a user within the organization.
 ///
-/// Each user has a login (username) and a role (e.g., admin, member).
+/// Each user has a login (username) and a role (e.g., admin, member). Users who don't have a
+/// GitHub account yet can be invited by `email` instead of `login`.
 ///
 pub struct User {
-    /// The user's GitHub login/username.
+    /// The user's GitHub login/username. May be empty for users invited by email.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub login: String,
     /// The user's role within the organization.
     pub role: String,
+    /// Email address to invite, for users without a known login.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Membership state as reported by GitHub. Informational; written by `sync-from-org`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<MembershipState>,
+    /// Teams (display name or slug) the user is added to when invited.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub teams: Vec<String>,
+}
+
+impl User {
+    /// A human-readable label for the user: their login, or their email if they have no login.
+    pub fn label(&self) -> &str {
+        if self.login.is_empty() {
+            self.email.as_deref().unwrap_or_default()
+        } else {
+            &self.login
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+///
+/// Permission level granted on a repository.
+///
+/// Both GitHub's role names (`read`, `write`) and the API's legacy names (`pull`, `push`) are
+/// accepted; config output always uses the role names. Any other value is treated as the name of
+/// an organization custom repository role.
+///
+pub enum Permission {
+    Read,
+    Triage,
+    Write,
+    Maintain,
+    Admin,
+    /// An organization custom repository role, by name.
+    Custom(String),
+}
+
+impl Permission {
+    /// Returns the canonical name used in config files.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Permission::Read => "read",
+            Permission::Triage => "triage",
+            Permission::Write => "write",
+            Permission::Maintain => "maintain",
+            Permission::Admin => "admin",
+            Permission::Custom(name) => name,
+        }
+    }
+
+    /// Returns the value expected in the `permission` field of GitHub API requests.
+    pub fn api_value(&self) -> &str {
+        match self {
+            Permission::Read => "pull",
+            Permission::Write => "push",
+            other => other.as_str(),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "read" | "pull" => Permission::Read,
+            "triage" => Permission::Triage,
+            "write" | "push" => Permission::Write,
+            "maintain" => Permission::Maintain,
+            "admin" => Permission::Admin,
+            _ => Permission::Custom(value),
+        }
+    }
+}
+
+impl From<&str> for Permission {
+    fn from(value: &str) -> Self {
+        Permission::from(value.to_string())
+    }
+}
+
+impl From<Permission> for String {
+    fn from(permission: Permission) -> Self {
+        permission.as_str().to_string()
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)] // Added Clone
@@ -119,22 +1006,47 @@ pub struct User {
 pub struct Assignment {
     /// The name of the repository.
     pub repo: String,
-    /// The name of the team.
+    /// The team, by display name or slug.
     pub team: String,
-    /// The permission level (e.g., admin, write, read).
-    pub permission: String,
+    /// The permission level (e.g., admin, maintain, write, triage, read, or a custom role).
+    pub permission: Permission,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 ///
 /// Top-level configuration for gh-config-cli.
 ///
 /// This struct represents the full configuration for an organization, including
-/// repositories, teams, users, assignments, default webhook, and branch protections.
+/// repositories, teams, users, assignments, outside collaborators, default webhook, and branch protections.
 ///
 pub struct Config {
     /// The name of the GitHub organization.
     pub org: String,
+    /// Organization-level settings, applied through `PATCH /orgs/{org}`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub org_settings: OrgSettings,
+    /// Organization webhooks, matched by URL. Org hooks not listed here are left untouched.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub org_webhooks: Vec<WebhookConfig>,
+    /// Organization GitHub Actions permissions. Leaving it unset means they are not managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<OrgActions>,
+    /// Custom properties repositories can have, matched by name. Leaving it unset means the schema
+    /// is not managed; properties that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_properties: Option<Vec<CustomProperty>>,
+    /// Code security configurations, matched by name. Leaving it unset means they are not managed;
+    /// the organization's own configurations that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_security_configurations: Option<Vec<CodeSecurityConfiguration>>,
+    /// Organization Actions secrets, matched by name. Leaving it unset means they are not managed;
+    /// org secrets that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionsSecret>>,
+    /// Organization Actions variables, matched by name. Leaving it unset means they are not
+    /// managed; org variables that aren't listed are deleted with `--prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Vec<ActionsVariable>>,
     /// List of repository configurations.
     #[serde(default)]
     pub repos: Vec<Repo>,
@@ -147,17 +1059,46 @@ pub struct Config {
     /// List of team-to-repo assignments.
     #[serde(default)]
     pub assignments: Vec<Assignment>,
+    /// Logins allowed to be outside collaborators (non-members with access to org repositories).
+    /// Leaving it unset means outside collaborators are not managed; otherwise anyone else is
+    /// removed from all of the organization's repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outside_collaborators: Option<Vec<String>>,
     /// Default webhook configuration for all repositories (if not overridden).
     #[serde(default)]
     pub default_webhook: Option<WebhookConfig>,
     /// Default branch protection rules for all repositories (if not overridden).
     #[serde(default)]
     pub default_branch_protections: Vec<BranchProtectionRule>,
+    /// Default security features for all repositories. Fields a repo's `security` sets override it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_security: Option<RepoSecurity>,
+    /// Labels for repositories without their own `labels`. They are created and updated, but
+    /// other labels of those repositories are kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_labels: Option<Vec<Label>>,
+    /// Autolinks for repositories without their own `autolinks`, without deleting other ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_autolinks: Option<Vec<Autolink>>,
+    /// Milestones for repositories without their own `milestones`, without deleting other ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_milestones: Option<Vec<Milestone>>,
     /// Extra fields for extensibility and custom/policy fields.
     #[serde(flatten)]
     pub extra: HashMap<String, Value>,
 }
 
+impl Repo {
+    /// The security features to manage for this repo, with `default` filling unset fields.
+    pub fn effective_security(&self, default: Option<&RepoSecurity>) -> Option<RepoSecurity> {
+        match (&self.security, default) {
+            (Some(security), Some(default)) => Some(security.or(default)),
+            (Some(security), None) => Some(security.clone()),
+            (None, default) => default.cloned(),
+        }
+    }
+}
+
 impl Config {
     /// Loads config from main file, optionally merging with defaults.config.yaml if present.
     pub fn from_file_with_defaults(main_path: &str, defaults_path: Option<&str>) -> crate::error::AppResult<Self> {
@@ -175,6 +1116,60 @@ impl Config {
         let config: Config = serde_yaml::from_value(main_config).map_err(crate::error::AppError::Serialization)?;
         Ok(config)
     }
+
+    /// Removes every reference to `login` from the config file at `path`: the user entry, team
+    /// members and maintainers, repo collaborators, environment reviewers and outside collaborators. The file is edited as
+    /// plain YAML, so keys this tool doesn't model are kept, but comments are not.
+    ///
+    /// Returns the number of entries removed. The file is only rewritten if something was removed
+    /// and `dry_run` is false.
+    pub fn remove_login_from_file(path: &str, login: &str, dry_run: bool) -> crate::error::AppResult<usize> {
+        let file = std::fs::File::open(path).map_err(crate::error::AppError::Io)?;
+        let mut value: Value = serde_yaml::from_reader(file).map_err(crate::error::AppError::Serialization)?;
+        let removed = remove_login_from_value(&mut value, login);
+        if removed > 0 && !dry_run {
+            let yaml = serde_yaml::to_string(&value).map_err(crate::error::AppError::Serialization)?;
+            std::fs::write(path, yaml).map_err(crate::error::AppError::Io)?;
+        }
+        Ok(removed)
+    }
+}
+
+/// Removes every reference to `login` (case-insensitively) from a raw config document.
+/// Returns the number of entries removed.
+fn remove_login_from_value(config: &mut Value, login: &str) -> usize {
+    let is_login = |v: &Value| v.as_str().is_some_and(|s| s.eq_ignore_ascii_case(login));
+    let has_login = |v: &Value| v.get("login").is_some_and(is_login);
+    let retain = |list: Option<&mut Value>, keep: &dyn Fn(&Value) -> bool| -> usize {
+        match list.and_then(Value::as_sequence_mut) {
+            Some(seq) => {
+                let before = seq.len();
+                seq.retain(|item| keep(item));
+                before - seq.len()
+            }
+            None => 0,
+        }
+    };
+
+    let mut removed = retain(config.get_mut("users"), &|u| !has_login(u));
+    removed += retain(config.get_mut("outside_collaborators"), &|l| !is_login(l));
+    if let Some(teams) = config.get_mut("teams").and_then(Value::as_sequence_mut) {
+        for team in teams {
+            removed += retain(team.get_mut("members"), &|m| !is_login(m));
+            removed += retain(team.get_mut("maintainers"), &|m| !is_login(m));
+        }
+    }
+    if let Some(repos) = config.get_mut("repos").and_then(Value::as_sequence_mut) {
+        for repo in repos {
+            removed += retain(repo.get_mut("collaborators"), &|c| !has_login(c));
+            if let Some(environments) = repo.get_mut("environments").and_then(Value::as_sequence_mut) {
+                for environment in environments {
+                    removed += retain(environment.get_mut("reviewers").and_then(|r| r.get_mut("users")), &|u| !is_login(u));
+                }
+            }
+        }
+    }
+    removed
 }
 
 /// Recursively merges defaults into main config (main config takes precedence).
@@ -210,12 +1205,12 @@ fn merge_with_defaults(main: Value, defaults: Value) -> Value {
             let mut used = vec![false; main_seq.len()];
             for d in &defaults_seq {
                 if let Value::Mapping(d_map) = d {
-                    if let Some(Value::String(d_name)) = d_map.get(&Value::String("name".to_string())) {
+                    if let Some(Value::String(d_name)) = d_map.get(Value::String("name".to_string())) {
                         // Try to find a matching item in main_seq
                         let mut found = false;
                         for (i, m) in main_seq.iter().enumerate() {
                             if let Value::Mapping(m_map) = m {
-                                if let Some(Value::String(m_name)) = m_map.get(&Value::String("name".to_string())) {
+                                if let Some(Value::String(m_name)) = m_map.get(Value::String("name".to_string())) {
                                     if m_name == d_name {
                                         // Merge recursively
                                         merged.push(merge_with_defaults(m.clone(), d.clone()));
@@ -249,6 +1244,7 @@ fn merge_with_defaults(main: Value, defaults: Value) -> Value {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[allow(dead_code)]
 ///
 /// Branch protection settings for a repository.
 ///
@@ -316,9 +1312,9 @@ repos:
         // Main config takes precedence
         println!("config.repos.len() = {}", config.repos.len());
         let repo = &config.repos[0];
-        assert_eq!(repo.settings.get("allow_merge_commit").unwrap().as_bool().unwrap(), true);
-        assert_eq!(repo.settings.get("allow_squash_merge").unwrap().as_bool().unwrap(), true);
-        assert_eq!(repo.settings.get("allow_rebase_merge").unwrap().as_bool().unwrap(), true);
+        assert!(repo.settings.get("allow_merge_commit").unwrap().as_bool().unwrap());
+        assert!(repo.settings.get("allow_squash_merge").unwrap().as_bool().unwrap());
+        assert!(repo.settings.get("allow_rebase_merge").unwrap().as_bool().unwrap());
         // Custom default is filled in
         assert_eq!(repo.settings.get("custom_default").unwrap().as_i64().unwrap(), 42);
         // Custom policy: main config wins
@@ -379,6 +1375,150 @@ assignments: []
         assert_eq!(repo.branch_protections.len(), 1);
         assert_eq!(repo.branch_protections[0].pattern, "release/*");
     }
+
+    #[test]
+    fn test_repo_lifecycle_deserialization() {
+        let yaml = r#"
+name: new-name
+archived: true
+previous_names: [old-name]
+transfer_to: other-org
+"#;
+        let repo: Repo = serde_yaml::from_str(yaml).expect("deserialize");
+        assert_eq!(repo.archived, Some(true));
+        assert_eq!(repo.previous_names, vec!["old-name"]);
+        assert_eq!(repo.transfer_to.as_deref(), Some("other-org"));
+        // Lifecycle fields are not part of the repo's extra fields.
+        assert!(repo.extra.is_empty());
+    }
+
+    #[test]
+    fn test_team_properties_deserialization() {
+        let yaml = r#"
+name: platform
+members: [alice]
+description: Platform engineering
+privacy: closed
+parent: engineering
+notification_setting: notifications_disabled
+"#;
+        let team: Team = serde_yaml::from_str(yaml).expect("deserialize");
+        assert_eq!(team.description.as_deref(), Some("Platform engineering"));
+        assert_eq!(team.privacy, Some(TeamPrivacy::Closed));
+        assert_eq!(team.parent.as_deref(), Some("engineering"));
+        assert_eq!(team.notification_setting, Some(TeamNotificationSetting::NotificationsDisabled));
+
+        let minimal: Team = serde_yaml::from_str("name: platform\nmembers: []\n").expect("deserialize");
+        assert!(minimal.privacy.is_none() && minimal.parent.is_none());
+    }
+
+    #[test]
+    fn test_collaborators_deserialization() {
+        let yaml = r#"
+org: test-org
+outside_collaborators: [contractor]
+repos:
+  - name: managed
+    collaborators:
+      - login: contractor
+        permission: push
+  - name: cleared
+    collaborators: []
+  - name: unmanaged
+"#;
+        let config: Config = serde_yaml::from_str(yaml).expect("deserialize");
+        assert_eq!(config.outside_collaborators, Some(vec!["contractor".to_string()]));
+        let collaborators = config.repos[0].collaborators.as_ref().expect("collaborators");
+        assert_eq!(collaborators[0].permission, Permission::Write);
+        assert_eq!(config.repos[1].collaborators, Some(vec![]));
+        assert_eq!(config.repos[2].collaborators, None);
+    }
+
+    #[test]
+    fn test_remove_login_from_value() {
+        let yaml = r#"
+org: test-org
+custom_key: kept
+outside_collaborators: [Alice, carol]
+repos:
+  - name: repo1
+    collaborators:
+      - login: alice
+        permission: admin
+      - login: bob
+        permission: read
+  - name: repo2
+    environments:
+      - name: production
+        reviewers:
+          users: [alice, bob]
+teams:
+  - name: core
+    members: [alice, bob]
+    maintainers: [ALICE]
+users:
+  - login: alice
+    role: admin
+  - login: bob
+    role: member
+"#;
+        let mut value: Value = serde_yaml::from_str(yaml).expect("deserialize");
+        assert_eq!(remove_login_from_value(&mut value, "alice"), 6);
+        assert_eq!(remove_login_from_value(&mut value, "alice"), 0);
+
+        let config: Config = serde_yaml::from_value(value).expect("config");
+        assert_eq!(config.outside_collaborators, Some(vec!["carol".to_string()]));
+        assert_eq!(config.repos[0].collaborators.as_ref().unwrap().len(), 1);
+        assert_eq!(config.teams[0].members, vec!["bob".to_string()]);
+        assert_eq!(config.repos[1].environments.as_ref().unwrap()[0].reviewers.users, vec!["bob".to_string()]);
+        assert_eq!(config.teams[0].maintainers, Some(Vec::new()));
+        assert_eq!(config.users.len(), 1);
+        assert!(config.extra.contains_key("custom_key"));
+    }
+
+    #[test]
+    fn test_user_membership_fields() {
+        let yaml = r#"
+org: test-org
+users:
+  - login: alice
+    role: member
+    state: pending
+  - email: new.hire@example.com
+    role: member
+    teams: [core-team]
+"#;
+        let config: Config = serde_yaml::from_str(yaml).expect("deserialize");
+        assert_eq!(config.users[0].state, Some(MembershipState::Pending));
+        assert_eq!(config.users[0].label(), "alice");
+        assert!(config.users[1].login.is_empty());
+        assert_eq!(config.users[1].label(), "new.hire@example.com");
+        assert_eq!(config.users[1].teams, vec!["core-team".to_string()]);
+    }
+
+    #[test]
+    fn test_permission_aliases_and_canonical_output() {
+        let parse = |s: &str| serde_yaml::from_str::<Permission>(s).expect("deserialize");
+        assert_eq!(parse("pull"), Permission::Read);
+        assert_eq!(parse("read"), Permission::Read);
+        assert_eq!(parse("Push"), Permission::Write);
+        assert_eq!(parse("maintain"), Permission::Maintain);
+        assert_eq!(parse("security-auditor"), Permission::Custom("security-auditor".to_string()));
+
+        assert_eq!(serde_yaml::to_string(&Permission::Write).unwrap().trim(), "write");
+        assert_eq!(Permission::Write.api_value(), "push");
+        assert_eq!(Permission::Read.api_value(), "pull");
+        assert_eq!(Permission::Triage.api_value(), "triage");
+    }
+
+    #[test]
+    fn test_visibility_deserialization() {
+        let repo: Repo = serde_yaml::from_str("name: repo1\nvisibility: internal\n").expect("deserialize");
+        assert_eq!(repo.visibility, Some(Visibility::Internal));
+
+        // A typo must not silently fall back to some other visibility.
+        assert!(serde_yaml::from_str::<Repo>("name: repo1\nvisibility: pubic\n").is_err());
+    }
 }
 
 
@@ -434,5 +1574,80 @@ default_webhook:
         assert_eq!(config.users.len(), 2);
         assert_eq!(config.assignments.len(), 1);
         assert!(config.default_webhook.is_some());
+        let webhooks = config.repos[0].webhooks.as_ref().expect("single webhook is accepted");
+        assert_eq!(webhooks.len(), 1);
+        assert!(webhooks[0].active);
+        assert!(!webhooks[0].insecure_ssl);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_multiple_webhooks_with_secrets() {
+        let yaml = r#"
+name: repo1
+webhooks:
+  - url: https://ci.example.com/hook
+    content_type: json
+    events: [push]
+    secret:
+      env: CI_HOOK_SECRET
+  - url: https://chat.example.com/hook
+    content_type: form
+    events: [pull_request]
+    active: false
+    insecure_ssl: true
+    secret:
+      file: /run/secrets/chat
+"#;
+        let repo: crate::config::Repo = serde_yaml::from_str(yaml).expect("deserialize");
+        let webhooks = repo.webhooks.expect("webhooks");
+        assert_eq!(webhooks[0].secret, Some(crate::config::SecretRef::Env("CI_HOOK_SECRET".to_string())));
+        assert!(webhooks[0].active);
+        assert!(!webhooks[1].active);
+        assert!(webhooks[1].insecure_ssl);
+        assert_eq!(webhooks[1].secret, Some(crate::config::SecretRef::File("/run/secrets/chat".to_string())));
+
+        let serialized = serde_yaml::to_string(&webhooks[0]).expect("serialize");
+        assert!(!serialized.contains("active"));
+        assert!(serialized.contains("env: CI_HOOK_SECRET"));
+
+        std::env::set_var("GH_CONFIG_TEST_HOOK_SECRET", "s3cret");
+        assert_eq!(crate::config::SecretRef::Env("GH_CONFIG_TEST_HOOK_SECRET".to_string()).resolve().unwrap(), "s3cret");
+        assert!(crate::config::SecretRef::Env("GH_CONFIG_TEST_UNSET_SECRET".to_string()).resolve().is_err());
+    }
+
+    #[test]
+    fn test_actions_secrets_and_variables() {
+        let yaml = r#"
+org: my-org
+secrets:
+  - name: NPM_TOKEN
+    value:
+      env: NPM_TOKEN
+    visibility: selected
+    selected_repositories: [web]
+variables:
+  - name: REGION
+    value: eu-west-1
+repos:
+  - name: web
+    secrets:
+      - name: DEPLOY_KEY
+        value:
+          file: /run/secrets/deploy
+    variables: []
+"#;
+        let config: crate::config::Config = serde_yaml::from_str(yaml).expect("deserialize");
+        let org_secret = &config.secrets.as_ref().expect("org secrets")[0];
+        assert_eq!(org_secret.value, crate::config::SecretRef::Env("NPM_TOKEN".to_string()));
+        assert_eq!(org_secret.visibility, Some(crate::config::ActionsVisibility::Selected));
+        assert_eq!(org_secret.selected_repositories, vec!["web"]);
+        assert_eq!(config.variables.as_ref().expect("org variables")[0].value, "eu-west-1");
+        let repo = &config.repos[0];
+        assert_eq!(repo.secrets.as_ref().expect("repo secrets")[0].value, crate::config::SecretRef::File("/run/secrets/deploy".to_string()));
+        assert_eq!(repo.variables, Some(vec![]));
+
+        // Secret values can't be written inline
+        let inline = "name: web\nsecrets:\n  - name: TOKEN\n    value: hunter2\n";
+        assert!(serde_yaml::from_str::<crate::config::Repo>(inline).is_err());
+    }
+}
@@ -5,6 +5,8 @@ use thiserror::Error;
 pub enum AppError {
     #[error("GitHub API error: {0}")]
     GitHubApi(String),
+    #[error("Validation error: {0}")]
+    Validation(String),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("HTTP error: {0}")]
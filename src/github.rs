@@ -9,22 +9,76 @@
     Public APIs are documented for maintainability. Internal response structs are used for deserialization.
 */
 
-use crate::config::{Assignment, Repo, RepoSettings, Team, User, WebhookConfig, Config};
+use crate::access::{compute_access_matrix, render_access_matrix, ReportFormat};
+use crate::config::{ActionsAccessLevel, ActionsPolicy, ActionsSecret, ActionsVariable, ActionsVisibility, AllowedActions, Assignment, AttachScope, Autolink, CodeSecurityConfiguration, Collaborator, CustomProperty, CustomPropertyType, DefaultForNewRepos, DeployKey, DeploymentBranchPolicy, EnabledRepositories, Environment, EnvironmentReviewers, ForkPrApproval, Label, MembershipState, Milestone, MilestoneState, OrgActions, OrgSettings, Permission, PropertyEditors, Repo, RepoActions, RepoSecurity, RepoSettings, SecretRef, SelectedActions, Team, TeamNotificationSetting, TeamPrivacy, User, Visibility, WebhookConfig, WorkflowPermissions, Config};
+use crate::api_mapping::{ApiFieldMapping, get_code_security_configuration_mapping, get_org_settings_mapping, ORG_READ_ONLY_SETTINGS};
 use crate::github_api_mapping_generated::get_github_api_mapping;
 use crate::error::{AppError, AppResult};
+use crate::state::{actions_secret_key, webhook_secret_key, StateFile, WriteOnlyStatus};
 use colored::*;
-use log::{debug, info, error};
+use log::{debug, info, error, warn};
 use reqwest::Client;
 use serde_json::json;
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value; // Make sure Value is imported
-use std::collections::{HashMap, HashSet}; // Added HashSet
+use std::collections::{BTreeMap, HashMap, HashSet}; // Added HashSet
 use similar::{ChangeTag, TextDiff};
 use std::fs::File;
 use std::io::Write;
+use std::sync::Mutex;
 
 const GITHUB_API_BASE_URL: &str = "https://api.github.com";
 
+/// A kind of resource `sync --prune` may delete when it isn't in the config. Each kind has to be
+/// named explicitly, so cleaning up stale webhooks never removes org members as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PruneScope {
+    /// Org members and pending invitations not in `users`.
+    Members,
+    /// Repo webhooks.
+    Webhooks,
+    /// Actions secrets of the org, repos and environments.
+    Secrets,
+    /// Actions variables of the org, repos and environments.
+    Variables,
+    /// Repo deployment environments.
+    Environments,
+    /// Org code security configurations and their repo attachments.
+    CodeSecurity,
+    /// The org's custom property schema.
+    Properties,
+    /// Repo labels.
+    Labels,
+    /// Repo autolinks.
+    Autolinks,
+    /// Repo milestones.
+    Milestones,
+    /// Repo deploy keys.
+    DeployKeys,
+    /// Direct repo collaborators and invitations, and org outside collaborators.
+    Collaborators,
+}
+
+/// The `rel="next"` URL from a GitHub `Link` response header, if there is another page.
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == "rel=\"next\"")
+            .then(|| url.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+    })
+}
+
+/// The next page of a paginated response, from its `Link` header.
+fn response_next_page_url(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::LINK)
+        .and_then(|link| link.to_str().ok())
+        .and_then(next_page_url)
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 struct RepoResponse {
@@ -32,1548 +86,7914 @@ struct RepoResponse {
     allow_squash_merge: bool,
     allow_rebase_merge: bool,
     private: bool,
+    visibility: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct TeamResponse {
-    name: String,
+/// Settings keys that change repository visibility. These are only honoured through the
+/// top-level `visibility` field so they always go through the visibility safety checks.
+const VISIBILITY_SETTING_KEYS: [&str; 2] = ["visibility", "private"];
+
+/// Determine a repository's visibility from the GitHub API `visibility` field, falling back
+/// to the `private` flag for older GitHub Enterprise Server versions that omit it.
+fn visibility_from_api(visibility: Option<&str>, private: bool) -> Visibility {
+    match visibility {
+        Some("internal") => Visibility::Internal,
+        Some("private") => Visibility::Private,
+        Some("public") => Visibility::Public,
+        _ if private => Visibility::Private,
+        _ => Visibility::Public,
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct MembershipResponse {
-    role: String,
+/// Refuse to make a repository public unless the caller explicitly allowed visibility changes.
+fn check_visibility_transition(repo_name: &str, current: Visibility, desired: Visibility, allow_visibility_change: bool) -> AppResult<()> {
+    if desired == Visibility::Public && current != Visibility::Public && !allow_visibility_change {
+        return Err(AppError::Validation(format!(
+            "Refusing to change visibility of repo '{}' from {} to public. Re-run with --allow-visibility-change to confirm.",
+            repo_name, current
+        )));
+    }
+    Ok(())
 }
 
-#[derive(Debug, Deserialize)]
-struct TeamRepoResponse {
-    name: String,
-    permissions: PermissionDetails,
+/// Check the visibility change of every existing repo in the config, following `previous_names`,
+/// so a refused change stops sync before anything is written. Repos being transferred are skipped.
+fn check_visibility_transitions(repos: &[Repo], existing: &HashMap<String, serde_json::Value>, allow_visibility_change: bool) -> AppResult<()> {
+    for repo in repos.iter().filter(|r| r.transfer_to.is_none()) {
+        let Some(desired) = repo.visibility else { continue };
+        let Some(current) = resolve_current_repo_name(repo, |name| existing.contains_key(name)).map(|name| &existing[name]) else {
+            continue;
+        };
+        let current = visibility_from_api(current["visibility"].as_str(), current["private"].as_bool().unwrap_or(false));
+        check_visibility_transition(&repo.name, current, desired, allow_visibility_change)?;
+    }
+    Ok(())
 }
 
-#[derive(Debug, Deserialize)]
-struct PermissionDetails {
-    pull: bool,
-    push: bool,
-    admin: bool,
+/// Changes needed to bring a repo's description, homepage, topics and default branch in line with
+/// the config.
+#[derive(Debug, Default, PartialEq)]
+struct RepoMetadataPlan {
+    /// Fields to PATCH on the repo.
+    patch: serde_json::Map<String, serde_json::Value>,
+    /// Topics to replace the current ones with.
+    topics: Option<Vec<String>>,
+    /// The current and the desired default branch, if it changes.
+    default_branch: Option<(String, String)>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct WebhookResponse {
-    id: Option<i64>,
-    url: String,
-    config: WebhookConfigResponse,
-    events: Vec<String>,
+/// Check that a missing `default_branch` may be created by renaming the current default branch,
+/// which the repo must opt in to with `rename_default_branch_from` naming that branch.
+fn check_default_branch_rename(repo: &Repo, current_branch: &str, desired_branch: &str) -> AppResult<()> {
+    match repo.rename_default_branch_from.as_deref() {
+        Some(from) if from == current_branch => Ok(()),
+        Some(from) => Err(AppError::Validation(format!(
+            "Repo {} has rename_default_branch_from '{}', but its default branch is '{}'",
+            repo.name, from, current_branch
+        ))),
+        None => Err(AppError::Validation(format!(
+            "Default branch '{}' of repo {} doesn't exist; create it, or set rename_default_branch_from: {} to rename the current default branch",
+            desired_branch, repo.name, current_branch
+        ))),
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct WebhookConfigResponse {
-    url: String,
-    content_type: String,
+/// Topics as GitHub stores them: lower case, sorted and without duplicates.
+fn normalize_topics(topics: &[String]) -> Vec<String> {
+    let mut topics: Vec<String> = topics.iter().map(|t| t.trim().to_lowercase()).collect();
+    topics.sort();
+    topics.dedup();
+    topics
 }
 
-///
-/// Client for interacting with the GitHub API for organization management.
-///
-/// The `GitHubClient` provides methods to manage repositories, teams, users, webhooks,
-/// and configuration synchronization for a GitHub organization. It handles authentication,
-/// API requests, and high-level orchestration of organization state.
-///
-pub struct GitHubClient {
-    /// Reqwest HTTP client for making API requests.
-    client: Client,
-    /// GitHub personal access token for authentication.
-    token: String,
-    /// Name of the GitHub organization to operate on.
-    pub org: String,
+/// Plan the metadata changes for a repo from its current API object. Unset fields aren't managed,
+/// and an empty description or homepage matches a missing one.
+fn plan_repo_metadata(repo: &Repo, current: &serde_json::Value) -> RepoMetadataPlan {
+    let mut plan = RepoMetadataPlan::default();
+    for (key, desired) in [("description", &repo.description), ("homepage", &repo.homepage)] {
+        let Some(desired) = desired else { continue };
+        if current[key].as_str().unwrap_or_default() != desired {
+            plan.patch.insert(key.to_string(), json!(desired));
+        }
+    }
+    if let Some(topics) = &repo.topics {
+        let topics = normalize_topics(topics);
+        let current_topics: Vec<String> = current["topics"].as_array().into_iter().flatten().filter_map(|t| t.as_str().map(str::to_string)).collect();
+        if topics != normalize_topics(&current_topics) {
+            plan.topics = Some(topics);
+        }
+    }
+    if let (Some(desired), Some(current)) = (&repo.default_branch, current["default_branch"].as_str()) {
+        if desired != current {
+            plan.default_branch = Some((current.to_string(), desired.clone()));
+        }
+    }
+    plan
 }
 
-impl GitHubClient {
-    /// Create a new GitHubClient for the given organization and token.
-    ///
-    /// # Arguments
-    /// * `token` - GitHub personal access token.
-    /// * `org` - Name of the GitHub organization.
-    pub fn new(token: &str, org: &str) -> Self {
-        GitHubClient {
-            client: Client::new(),
-            token: token.to_string(),
-            org: org.to_string(),
+/// Normalize repo descriptions, homepages, topics and default branches for diffing. Fields the
+/// local config doesn't set aren't managed, so they are dropped from the GitHub side.
+fn normalize_repo_metadata_for_diff(local: &mut Config, github: &mut Config) {
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        for (local_field, github_field) in [(&mut local_repo.description, &mut github_repo.description), (&mut local_repo.homepage, &mut github_repo.homepage)] {
+            match local_field {
+                // Export leaves out empty values
+                Some(value) if value.is_empty() => *local_field = None,
+                Some(_) => {}
+                None => *github_field = None,
+            }
+        }
+        match &mut local_repo.topics {
+            Some(topics) => {
+                *topics = normalize_topics(topics);
+                github_repo.topics = Some(normalize_topics(github_repo.topics.as_deref().unwrap_or_default()));
+            }
+            None => github_repo.topics = None,
+        }
+        if local_repo.default_branch.is_none() {
+            github_repo.default_branch = None;
         }
     }
+}
 
-    async fn send_patch(&self, url: &str, body: serde_json::Value) -> AppResult<()> {
-        // --- Add Enhanced Logging ---
-        debug!("Attempting to build PATCH request for URL: '{}'", url);
-        if url.trim().is_empty() {
-            error!("URL passed to send_patch() is empty!");
-            return Err(AppError::GitHubApi("Internal error: Attempted PATCH with empty URL".to_string()));
+/// Render a string as a YAML scalar, quoting it only when needed.
+fn yaml_scalar(value: &str) -> String {
+    serde_yaml::to_string(value).unwrap_or_default().trim().to_string()
+}
+
+/// Build a `Team` from GitHub's team object and its members' team roles.
+fn team_from_response(team: TeamResponse, roles: &HashMap<String, &'static str>) -> Team {
+    let logins_with_role = |wanted: &str| {
+        let mut logins: Vec<String> = roles.iter().filter(|(_, role)| **role == wanted).map(|(login, _)| login.clone()).collect();
+        logins.sort();
+        logins
+    };
+    Team {
+        name: team.name,
+        slug: Some(team.slug),
+        members: logins_with_role(TEAM_ROLE_MEMBER),
+        maintainers: Some(logins_with_role(TEAM_ROLE_MAINTAINER)),
+        // GitHub reports a missing description as either null or an empty string
+        description: team.description.filter(|d| !d.is_empty()),
+        privacy: team.privacy,
+        parent: team.parent.map(|p| p.slug),
+        notification_setting: team.notification_setting,
+    }
+}
+
+/// Derive the slug GitHub generates for a team name: lowercase, with every run of characters other
+/// than ASCII letters, digits and underscores replaced by a single dash.
+pub(crate) fn slugify_team_name(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
         }
-        if self.token.trim().is_empty() {
-            error!("GitHub token is empty!");
-            return Err(AppError::GitHubApi("GitHub token is empty".to_string()));
-        } else {
-            debug!("Token length: {}", self.token.len());
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Whether a team reference from the config (display name or slug) refers to the team with the given
+/// name and slug.
+pub(crate) fn team_ref_matches(reference: &str, name: &str, slug: &str) -> bool {
+    reference == slug || reference.eq_ignore_ascii_case(name) || slugify_team_name(reference) == slug
+}
+
+/// Whether a configured team refers to the GitHub team with the given name and slug. An explicit
+/// `slug` is authoritative, since the display name may be about to change.
+fn team_matches(team: &Team, name: &str, slug: &str) -> bool {
+    match &team.slug {
+        Some(configured) => configured == slug,
+        None => team_ref_matches(&team.name, name, slug),
+    }
+}
+
+/// Compute the PATCH body for the team properties that drifted from the config.
+/// The parent team is not included since it has to be resolved to an ID first.
+fn team_property_changes(team: &Team, current: &TeamResponse) -> serde_json::Map<String, serde_json::Value> {
+    let mut changes = serde_json::Map::new();
+    // Only a team pinned by slug can be renamed; otherwise the name is how it was found.
+    if team.slug.is_some() && current.name != team.name {
+        changes.insert("name".to_string(), json!(team.name));
+    }
+    if let Some(description) = &team.description {
+        if current.description.as_deref().unwrap_or("") != description {
+            changes.insert("description".to_string(), json!(description));
         }
-        debug!("PATCH body: {:?}", body); // Log the body being sent
-        // --- End Enhanced Logging ---
+    }
+    if let Some(privacy) = team.privacy {
+        if current.privacy != Some(privacy) {
+            changes.insert("privacy".to_string(), json!(privacy.as_str()));
+        }
+    }
+    if let Some(notification_setting) = team.notification_setting {
+        if current.notification_setting != Some(notification_setting) {
+            changes.insert("notification_setting".to_string(), json!(notification_setting.as_str()));
+        }
+    }
+    changes
+}
 
-        // Try building step-by-step again
-        let client_ref = &self.client;
-        let builder_result = client_ref.patch(url); // Initial builder
+const TEAM_ROLE_MEMBER: &str = "member";
+const TEAM_ROLE_MAINTAINER: &str = "maintainer";
+
+/// Map each configured team member to their desired team role.
+/// Someone listed as both a member and a maintainer is a maintainer. Without a `maintainers` list,
+/// roles are not managed: current members keep their role in `current` and new members join as members.
+fn desired_team_roles(team: &Team, current: &HashMap<String, &'static str>) -> BTreeMap<String, &'static str> {
+    let Some(maintainers) = &team.maintainers else {
+        return team
+            .members
+            .iter()
+            .map(|login| (login.clone(), current.get(login).copied().unwrap_or(TEAM_ROLE_MEMBER)))
+            .collect();
+    };
+    let mut roles: BTreeMap<String, &'static str> =
+        team.members.iter().map(|login| (login.clone(), TEAM_ROLE_MEMBER)).collect();
+    roles.extend(maintainers.iter().map(|login| (login.clone(), TEAM_ROLE_MAINTAINER)));
+    roles
+}
 
-        // --- Log after initial build ---
-        debug!("reqwest::Client::patch succeeded for URL: '{}'", url); // Check if this logs
-        // ---
+/// Work out which team memberships to add or re-role (PUT) and which members to remove.
+fn plan_team_memberships(
+    desired: &BTreeMap<String, &'static str>,
+    current: &HashMap<String, &'static str>,
+) -> (Vec<(String, &'static str)>, Vec<String>) {
+    let to_put = desired
+        .iter()
+        .filter(|(login, role)| current.get(login.as_str()) != Some(*role))
+        .map(|(login, role)| (login.clone(), *role))
+        .collect();
+    let mut to_remove: Vec<String> = current.keys().filter(|login| !desired.contains_key(*login)).cloned().collect();
+    to_remove.sort();
+    (to_put, to_remove)
+}
 
-        let auth_header_value = format!("Bearer {}", self.token);
-        if auth_header_value.contains('\n') || auth_header_value.contains('\r') {
-             error!("Authorization header value contains invalid characters (newline/CR)");
-             return Err(AppError::GitHubApi("Invalid characters in token for Authorization header".to_string()));
+/// Order teams so every parent team defined in the config is synced before its children.
+/// Teams keep their config order otherwise; teams caught in a parent cycle are appended unchanged.
+fn order_teams_parent_first(teams: &[Team]) -> Vec<&Team> {
+    let refers_to = |reference: &str, team: &Team| {
+        team_ref_matches(reference, &team.name, team.slug.as_deref().unwrap_or(&slugify_team_name(&team.name)))
+    };
+    let mut ordered: Vec<&Team> = Vec::with_capacity(teams.len());
+    let mut remaining: Vec<&Team> = teams.iter().collect();
+
+    while !remaining.is_empty() {
+        let (ready, blocked): (Vec<&Team>, Vec<&Team>) = remaining.iter().partition(|t| {
+            // Ready unless the parent is a configured team that hasn't been placed yet
+            t.parent.as_deref().is_none_or(|p| !remaining.iter().any(|other| refers_to(p, other)))
+        });
+        if ready.is_empty() {
+            ordered.extend(blocked);
+            break;
         }
+        ordered.extend(ready);
+        remaining = blocked;
+    }
+    ordered
+}
 
-        // Chain headers
-        let request_builder_headers = builder_result
-            .header(reqwest::header::AUTHORIZATION, auth_header_value)
-            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
-            .header(reqwest::header::USER_AGENT, "gh-config");
+/// Convert a GitHub webhook object into a config webhook with sorted events. The secret is never
+/// returned by GitHub, so it is always `None`.
+fn webhook_from_response(hook: &WebhookResponse) -> WebhookConfig {
+    let mut events = hook.events.clone();
+    events.sort();
+    let insecure_ssl = match &hook.config.insecure_ssl {
+        Some(serde_json::Value::String(value)) => value == "1",
+        Some(serde_json::Value::Number(value)) => value.as_i64() == Some(1),
+        _ => false,
+    };
+    WebhookConfig {
+        url: hook.config.url.clone(),
+        content_type: hook.config.content_type.clone(),
+        events,
+        secret: None,
+        active: hook.active.unwrap_or(true),
+        insecure_ssl,
+    }
+}
 
-        // --- Log after headers added ---
-        debug!("Request builder headers configured."); // Check if this logs
-        // ---
+/// Convert GitHub's webhook objects into config webhooks with sorted events.
+fn webhook_configs(hooks: Vec<WebhookResponse>) -> Vec<WebhookConfig> {
+    hooks.iter().map(webhook_from_response).collect()
+}
 
-        // Add JSON body
-        let request_builder_final = request_builder_headers.json(&body);
+/// Whether a webhook on GitHub already matches the desired config, ignoring event order and the
+/// secret (which can't be read back).
+fn webhook_matches(current: &WebhookResponse, desired: &WebhookConfig) -> bool {
+    let mut desired = WebhookConfig { secret: None, ..desired.clone() };
+    desired.events.sort();
+    webhook_from_response(current) == desired
+}
 
-        // --- Log after body added ---
-        debug!("Request builder body configured (using .json())."); // Check if this logs
-        // ----
+/// A single change needed to bring a hooks collection in line with the config.
+#[derive(Debug, PartialEq)]
+enum WebhookChange<'a> {
+    Create(&'a WebhookConfig),
+    Update(i64, &'a WebhookConfig),
+    Delete(i64, String),
+}
 
-        // Send the request
-        debug!("Attempting final send..."); // Check if this logs
-        let response_result = request_builder_final.send().await; // Store result before unwrapping
+/// Plan the changes to a hooks collection. Hooks are matched by URL; hooks whose URL isn't in
+/// `desired` are deleted only when `delete_unlisted` is set. Hooks whose URL is in `stale_secrets`
+/// are updated even if everything GitHub returns matches, so their secret is sent again. Hooks
+/// GitHub returned without an ID can't be addressed and are skipped with a warning.
+fn plan_webhook_changes<'a>(
+    desired: &'a [WebhookConfig],
+    current: &[WebhookResponse],
+    delete_unlisted: bool,
+    stale_secrets: &HashSet<&str>,
+) -> Vec<WebhookChange<'a>> {
+    let mut changes = Vec::new();
+    for webhook in desired {
+        match current.iter().find(|h| h.config.url == webhook.url) {
+            Some(hook) if webhook_matches(hook, webhook) && !stale_secrets.contains(webhook.url.as_str()) => {}
+            Some(WebhookResponse { id: Some(id), .. }) => changes.push(WebhookChange::Update(*id, webhook)),
+            Some(_) => warn!("Webhook {} has no ID in the GitHub response; skipping update", webhook.url),
+            None => changes.push(WebhookChange::Create(webhook)),
+        }
+    }
+    if delete_unlisted {
+        for hook in current {
+            if desired.iter().any(|w| w.url == hook.config.url) {
+                continue;
+            }
+            match hook.id {
+                Some(id) => changes.push(WebhookChange::Delete(id, hook.config.url.clone())),
+                None => warn!("Webhook {} has no ID in the GitHub response; skipping delete", hook.config.url),
+            }
+        }
+    }
+    changes
+}
 
-        match response_result {
-             Ok(response) => {
-                 // --- Original status check logic ---
-                 debug!("Request send successful."); // Log success before status check
-                 let status = response.status();
-                 debug!("PATCH {} returned status: {}", url, status);
-                 if status.is_success() {
-                     Ok(())
-                 } else {
-                     let text = response.text().await?;
-                     error!("PATCH {} failed with status {}: {}", url, status, text);
-                     Err(AppError::GitHubApi(text))
-                 }
-             }
-             Err(e) => {
-                 error!("Request builder send() failed: {}", e);
-                 // Check if the error source is the builder error we saw
-                 if e.is_builder() {
-                     error!("Confirmed: Failure is a builder error during send().");
-                 } else if e.is_connect() {
-                     error!("Confirmed: Failure is a connection error during send().");
-                 } else if e.is_timeout() {
-                     error!("Confirmed: Failure is a timeout error during send().");
-                 } // Add other checks from reqwest::Error if needed
-                 Err(AppError::from(e)) // Propagate the reqwest error
-             }
-         }
+/// Compare a write-only value with the one last applied, per the state file. No value counts as
+/// changed if one was recorded, since that value is still set on GitHub.
+fn write_only_status(state: &StateFile, key: &str, value: Option<&SecretRef>) -> AppResult<WriteOnlyStatus> {
+    match value {
+        Some(value) => Ok(state.status(key, &value.resolve()?)),
+        None if state.entries().contains_key(key) => Ok(WriteOnlyStatus::Changed),
+        None => Ok(WriteOnlyStatus::Unchanged),
+    }
+}
 
+/// Build the request body for creating or updating a webhook, resolving its secret.
+fn webhook_body(webhook: &WebhookConfig) -> AppResult<serde_json::Value> {
+    let mut config = json!({
+        "url": webhook.url,
+        "content_type": webhook.content_type,
+        "insecure_ssl": if webhook.insecure_ssl { "1" } else { "0" },
+    });
+    if let Some(secret) = &webhook.secret {
+        config["secret"] = json!(secret.resolve()?);
     }
+    Ok(json!({
+        "active": webhook.active,
+        "events": webhook.events,
+        "config": config,
+    }))
+}
 
-    async fn send_post(&self, url: &str, body: serde_json::Value) -> AppResult<()> {
-        // --- Add Similar Enhanced Logging ---
-        debug!("Attempting to build POST request for URL: '{}'", url);
-        if url.trim().is_empty() { /* ... */ }
-        if self.token.trim().is_empty() { /* ... */ } else { debug!("Token length: {}", self.token.len()); }
-        debug!("POST body: {:?}", body);
+/// The webhooks sync manages for a repo, and whether hooks that aren't listed may be deleted.
+/// Returns `None` when the repo's webhooks aren't managed at all: it has no `webhooks` and there
+/// is no `default_webhook`. Unlisted hooks are only deleted for explicit lists when pruning.
+fn repo_webhook_plan<'a>(repo: &'a Repo, default_webhook: Option<&'a WebhookConfig>, prune: bool) -> Option<(&'a [WebhookConfig], bool)> {
+    repo_list_plan(repo.webhooks.as_deref(), default_webhook.map(std::slice::from_ref), prune)
+}
 
-        let client_ref = &self.client;
-        let builder_result = client_ref.post(url); // Initial builder
-        debug!("reqwest::Client::post succeeded for URL: '{}'", url);
+/// The items sync manages for one of a repo's lists, and whether unlisted ones may be deleted. The
+/// repo's own list wins; otherwise the org default is applied, without deleting anything.
+fn repo_list_plan<'a, T>(own: Option<&'a [T]>, default: Option<&'a [T]>, prune: bool) -> Option<(&'a [T], bool)> {
+    match (own, default) {
+        (Some(own), _) => Some((own, prune)),
+        (None, Some(default)) => Some((default, false)),
+        (None, None) => None,
+    }
+}
 
-        let auth_header_value = format!("Bearer {}", self.token);
-        if auth_header_value.contains('\n') || auth_header_value.contains('\r') { /* ... */ }
+/// Render a webhook as YAML. Fields are indented by `indent`; list items get a leading `- `.
+fn webhook_yaml(webhook: &WebhookConfig, indent: &str, list_item: bool) -> String {
+    let field_indent = if list_item { format!("{}  ", indent) } else { indent.to_string() };
+    let first_indent = if list_item { format!("{}- ", indent) } else { indent.to_string() };
+    let mut yaml = format!("{}url: {}\n", first_indent, webhook.url);
+    yaml.push_str(&format!("{}content_type: {}\n", field_indent, webhook.content_type));
+    yaml.push_str(&format!("{}events:\n", field_indent));
+    let mut events = webhook.events.clone();
+    events.sort();
+    for event in &events {
+        yaml.push_str(&format!("{}- {}\n", field_indent, event));
+    }
+    if !webhook.active {
+        yaml.push_str(&format!("{}active: false\n", field_indent));
+    }
+    if webhook.insecure_ssl {
+        yaml.push_str(&format!("{}insecure_ssl: true\n", field_indent));
+    }
+    yaml
+}
 
-        let request_builder_headers = builder_result
-            .header(reqwest::header::AUTHORIZATION, auth_header_value)
-            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
-            .header(reqwest::header::USER_AGENT, "gh-config");
-        debug!("Request builder headers configured.");
+/// Sort a webhook list by URL, with sorted events and no secrets, for diffing.
+fn normalize_webhook_list(webhooks: &mut [WebhookConfig]) {
+    for webhook in webhooks.iter_mut() {
+        webhook.events.sort();
+        webhook.secret = None;
+    }
+    webhooks.sort_by(|a, b| a.url.cmp(&b.url));
+}
 
-        let request_builder_final = request_builder_headers.json(&body);
-        debug!("Request builder body configured (using .json()).");
+/// Keep only the org webhooks the local config lists (by URL) on the GitHub side of a diff, and
+/// sort both sides by URL with sorted events.
+fn normalize_org_webhooks_for_diff(local: &mut Config, github: &mut Config) {
+    let listed: HashSet<String> = local.org_webhooks.iter().map(|wh| wh.url.clone()).collect();
+    github.org_webhooks.retain(|wh| listed.contains(&wh.url));
+    normalize_webhook_list(&mut local.org_webhooks);
+    normalize_webhook_list(&mut github.org_webhooks);
+}
 
-        debug!("Attempting final send...");
-        let response_result = request_builder_final.send().await; // Store result
+/// Org settings that drifted, grouped by (endpoint, method) into request bodies. Read-only
+/// settings that drifted are returned separately, since they can only be reported.
+type OrgSettingsUpdates = BTreeMap<(&'static str, &'static str), serde_json::Map<String, serde_json::Value>>;
+
+fn org_settings_updates<'a>(desired: &'a OrgSettings, current: &OrgSettings) -> (OrgSettingsUpdates, Vec<&'a str>) {
+    let mapping = get_org_settings_mapping();
+    let mut updates = OrgSettingsUpdates::new();
+    let mut read_only_drift = Vec::new();
+    for (key, v_desired) in desired {
+        if current.get(key) == Some(v_desired) {
+            continue;
+        }
+        if ORG_READ_ONLY_SETTINGS.contains(&key.as_str()) {
+            read_only_drift.push(key.as_str());
+        } else if let Some(field_map) = mapping.get(key.as_str()) {
+            updates
+                .entry((field_map.endpoint, field_map.method))
+                .or_default()
+                .insert(field_map.json_path.to_string(), serde_json::to_value(v_desired).unwrap_or(serde_json::Value::Null));
+        }
+    }
+    (updates, read_only_drift)
+}
 
-        match response_result {
-            Ok(response) => {
-                debug!("Request send successful.");
-                let status = response.status();
-                debug!("POST {} returned status: {}", url, status);
-                if status.is_success() {
-                    Ok(())
-                } else {
-                    let text = response.text().await?;
-                    error!("POST {} failed with status {}: {}", url, status, text);
-                    Err(AppError::GitHubApi(text))
-                }
-            }
-            Err(e) => {
-                error!("Request builder send() failed: {}", e);
-                if e.is_builder() { error!("Confirmed: Failure is a builder error during send()."); }
-                // ... other error checks ...
-                Err(AppError::from(e))
+/// Reject org settings that have no API mapping, since they could never be applied.
+fn check_org_settings(settings: &OrgSettings) -> AppResult<()> {
+    let mapping = get_org_settings_mapping();
+    let mut unknown: Vec<&str> = settings
+        .keys()
+        .map(String::as_str)
+        .filter(|key| !mapping.contains_key(key) && !ORG_READ_ONLY_SETTINGS.contains(key))
+        .collect();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    unknown.sort();
+    Err(AppError::Validation(format!("Unknown org_settings keys: {}", unknown.join(", "))))
+}
+
+/// Keep only the org settings the local config manages on the GitHub side of a diff.
+fn normalize_org_settings_for_diff(local: &Config, github: &mut Config) {
+    github.org_settings.retain(|key, _| local.org_settings.contains_key(key));
+}
+
+/// Reject repo collaborators that the `outside_collaborators` allow-list would remove again. Anyone
+/// granted repo access who isn't listed in `users` is an outside collaborator, so they must be allowed.
+fn check_outside_collaborators(config: &Config) -> AppResult<()> {
+    let Some(allowed) = &config.outside_collaborators else {
+        return Ok(());
+    };
+    let known: HashSet<String> = config.users.iter().map(|u| u.login.to_lowercase()).chain(allowed.iter().map(|l| l.to_lowercase())).collect();
+    let mut missing: Vec<String> = config
+        .repos
+        .iter()
+        .flat_map(|repo| repo.collaborators.iter().flatten().filter(|c| !known.contains(&c.login.to_lowercase())).map(move |c| format!("{} (repo {})", c.login, repo.name)))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    missing.sort();
+    Err(AppError::Validation(format!(
+        "Repo collaborators missing from outside_collaborators would be removed again by the same sync: {}. Add them to outside_collaborators or users.",
+        missing.join(", ")
+    )))
+}
+
+/// Drop collaborator lists the local config doesn't manage from the GitHub side of a diff and sort
+/// the rest by login, so the comparison doesn't depend on config order.
+fn normalize_collaborators_for_diff(local: &mut Config, github: &mut Config) {
+    if local.outside_collaborators.is_none() {
+        github.outside_collaborators = None;
+    }
+    for config in [&mut *local, &mut *github] {
+        if let Some(logins) = config.outside_collaborators.as_mut() {
+            logins.sort_by_key(|l| l.to_lowercase());
+        }
+        for repo in &mut config.repos {
+            if let Some(collaborators) = repo.collaborators.as_mut() {
+                collaborators.sort_by_key(|c| c.login.to_lowercase());
             }
         }
     }
+    let managed: HashSet<&str> = local.repos.iter().filter(|r| r.collaborators.is_some()).map(|r| r.name.as_str()).collect();
+    for repo in &mut github.repos {
+        if !managed.contains(repo.name.as_str()) {
+            repo.collaborators = None;
+        } else if repo.collaborators.is_none() {
+            repo.collaborators = Some(Vec::new());
+        }
+    }
+}
 
-    async fn send_put(&self, url: &str, body: Option<serde_json::Value>) -> AppResult<()> {
-        let mut request = self
-            .client
-            .put(url)
-            .header("Authorization", format!("Bearer {}", self.token))
-            .header("Accept", "application/vnd.github+json")
-            .header("User-Agent", "gh-config");
-        if let Some(body) = body {
-            request = request.json(&body);
-        }
-        let response = request.send().await?;
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            let status = response.status();
-            let text = response.text().await?;
-            error!("PUT {} failed with status {}: {}", url, status, text);
-            Err(AppError::GitHubApi(text))
+/// Drop optional team properties the local config doesn't manage from the GitHub side of a diff.
+/// Local teams identified by name only are matched through their derived slug and take over the
+/// GitHub side's name, so old configs that use the slug as `name` don't diff forever.
+fn normalize_team_properties_for_diff(local: &mut Config, github: &mut Config) {
+    for team in &mut github.teams {
+        let slug = team.slug.clone().unwrap_or_else(|| slugify_team_name(&team.name));
+        if let Some(local_team) = local.teams.iter().find(|t| team_matches(t, &team.name, &slug)) {
+            if local_team.slug.is_none() {
+                team.slug = None;
+                team.name = local_team.name.clone();
+            }
+            if local_team.description.is_none() { team.description = None; }
+            if local_team.privacy.is_none() { team.privacy = None; }
+            if local_team.parent.is_none() { team.parent = None; }
+            if local_team.notification_setting.is_none() { team.notification_setting = None; }
         }
     }
 
-    async fn get(&self, url: &str) -> AppResult<reqwest::Response> {
-        // --- Add Enhanced Logging ---
-        debug!("Attempting to build GET request for URL: '{}'", url);
-        if url.trim().is_empty() {
-             error!("URL passed to get() is empty!");
-             return Err(AppError::GitHubApi("Internal error: Attempted GET with empty URL".to_string()));
+    // Point team references at the same spelling on both sides
+    let canonical_ref = |reference: &str| {
+        github
+            .teams
+            .iter()
+            .find(|t| team_ref_matches(reference, &t.name, t.slug.as_deref().unwrap_or(&slugify_team_name(&t.name))))
+            .map(|t| t.slug.clone().unwrap_or_else(|| t.name.clone()))
+    };
+    let local_refs: Vec<Option<String>> = local.assignments.iter().map(|a| canonical_ref(&a.team)).collect();
+    for (assignment, canonical) in local.assignments.iter_mut().zip(local_refs) {
+        if let Some(canonical) = canonical { assignment.team = canonical; }
+    }
+    let local_parents: Vec<Option<String>> = local.teams.iter().map(|t| t.parent.as_deref().and_then(canonical_ref)).collect();
+    for (team, canonical) in local.teams.iter_mut().zip(local_parents) {
+        if canonical.is_some() { team.parent = canonical; }
+    }
+}
+
+/// What sync should do with a repository once its rename/transfer lifecycle has been reconciled.
+#[derive(Debug, PartialEq)]
+enum RepoLifecycle {
+    /// Keep syncing the repository, addressing it by this name on GitHub.
+    Continue(String),
+    /// The repository has left (or, in dry-run mode, would leave) the organization.
+    Transferred,
+}
+
+/// Resolve the name a configured repo currently has on GitHub, following `previous_names` if it has not
+/// been renamed yet. Returns `None` if neither the name nor any previous name exists in the org.
+fn resolve_current_repo_name(repo: &Repo, exists: impl Fn(&str) -> bool) -> Option<&str> {
+    if exists(&repo.name) {
+        return Some(&repo.name);
+    }
+    repo.previous_names
+        .iter()
+        .find(|name| exists(name.as_str()))
+        .map(String::as_str)
+}
+
+/// Rewrite both sides of a diff so renames and transfers declared in the local config show up as such
+/// instead of as one repo being deleted and another created. Returns human-readable notes for each
+/// pending lifecycle change.
+fn normalize_repo_lifecycle_for_diff(local: &mut Config, github: &mut Config) -> Vec<String> {
+    let mut notes = Vec::new();
+    let existing: HashSet<String> = github.repos.iter().map(|r| r.name.clone()).collect();
+
+    let mut transferred = HashSet::new();
+    for repo in &local.repos {
+        let current_name = resolve_current_repo_name(repo, |name| existing.contains(name));
+        match (repo.transfer_to.as_deref(), current_name) {
+            (Some(target), Some(current)) if target != local.org => {
+                notes.push(format!("Repo '{}' would be transferred to '{}'", current, target));
+                transferred.insert(current.to_string());
+                transferred.insert(repo.name.clone());
+            }
+            (Some(target), None) if target != local.org => {
+                transferred.insert(repo.name.clone());
+            }
+            (_, Some(current)) if current != repo.name => {
+                notes.push(format!("Repo '{}' would be renamed to '{}'", current, repo.name));
+                if let Some(github_repo) = github.repos.iter_mut().find(|r| r.name == current) {
+                    github_repo.name = repo.name.clone();
+                }
+            }
+            _ => {}
         }
-        // Check token validity superficially (e.g., not empty)
-        if self.token.trim().is_empty() {
-            error!("GitHub token is empty!");
-            // Although this usually causes 401 later, an empty Bearer token might fail earlier.
-            return Err(AppError::GitHubApi("GitHub token is empty".to_string()));
-        } else {
-             // Log length as a non-sensitive indicator
-             debug!("Token length: {}", self.token.len());
+    }
+
+    local.repos.retain(|r| !transferred.contains(&r.name));
+    github.repos.retain(|r| !transferred.contains(&r.name));
+    for repo in local.repos.iter_mut().chain(github.repos.iter_mut()) {
+        // `previous_names`, `transfer_to` and `rename_default_branch_from` are instructions rather
+        // than state, and an explicit `archived: false` is what GitHub reports for every unarchived repo.
+        repo.previous_names.clear();
+        repo.transfer_to = None;
+        repo.rename_default_branch_from = None;
+        if repo.archived == Some(false) {
+            repo.archived = None;
         }
-        // --- End Enhanced Logging ---
+    }
+    notes
+}
 
-        // Try building the request step-by-step to isolate the failure
-        let client_ref = &self.client; // Borrow client
-        let builder_result = client_ref.get(url); // Create builder
+#[derive(Debug, Clone, Deserialize)]
+struct TeamResponse {
+    id: i64,
+    name: String,
+    slug: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    privacy: Option<TeamPrivacy>,
+    #[serde(default)]
+    notification_setting: Option<TeamNotificationSetting>,
+    #[serde(default)]
+    parent: Option<ParentTeamResponse>,
+}
 
-        // --- Log after potential URL parsing failure ---
-        debug!("reqwest::Client::get succeeded for URL: '{}'", url);
-        // ---
+#[derive(Debug, Clone, Deserialize)]
+struct ParentTeamResponse {
+    name: String,
+    slug: String,
+}
 
-        let auth_header_value = format!("Bearer {}", self.token);
-        // Validate auth header value doesn't contain obviously invalid chars like newline
-        if auth_header_value.contains('\n') || auth_header_value.contains('\r') {
-             error!("Authorization header value contains invalid characters (newline/CR)");
-             return Err(AppError::GitHubApi("Invalid characters in token for Authorization header".to_string()));
-        }
+#[derive(Debug, Deserialize)]
+struct MembershipResponse {
+    role: String,
+}
 
-        // Chain the rest - if it fails here, it's likely header related
-        let request_builder = builder_result
-            .header(reqwest::header::AUTHORIZATION, auth_header_value) // Use constant for clarity
-            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
-            .header(reqwest::header::USER_AGENT, "gh-config");
+#[derive(Debug, Deserialize)]
+struct OrgInvitationResponse {
+    id: i64,
+    login: Option<String>,
+    email: Option<String>,
+    role: String,
+}
 
-        // --- Log before send ---
-        debug!("Request builder fully configured, attempting send...");
-        // ---
+#[derive(Debug, Deserialize)]
+struct UserResponse {
+    id: i64,
+    login: String,
+}
 
-        let response = request_builder.send().await?;
+/// Map an organization invitation role to the membership role used in the config.
+fn org_role_from_invitation(role: &str) -> &str {
+    match role {
+        "direct_member" => "member",
+        other => other,
+    }
+}
 
-        // --- Original status check logic ---
-        let status = response.status();
-        debug!("GET {} returned status: {}", url, status);
-        if status.is_success() {
-            Ok(response)
+/// Map a membership role from the config to the role accepted by the invitations API.
+fn invitation_role(role: &str) -> &str {
+    match role {
+        "member" => "direct_member",
+        other => other,
+    }
+}
+
+/// Whether an invitation is for the given user, matching by login or email (case-insensitively).
+fn invitation_matches(invitation: &OrgInvitationResponse, user: &User) -> bool {
+    let eq = |a: &Option<String>, b: &str| a.as_deref().is_some_and(|a| !b.is_empty() && a.eq_ignore_ascii_case(b));
+    eq(&invitation.login, &user.login) || user.email.as_deref().is_some_and(|email| eq(&invitation.email, email))
+}
+
+/// A single change needed to bring the organization's membership in line with the config.
+#[derive(Debug, PartialEq)]
+enum MembershipChange {
+    /// Change the role of a member or of a pending invitation.
+    SetRole(String, String),
+    /// Invite a user who is neither a member nor invited yet.
+    Invite(User),
+    /// Withdraw an invitation for someone who isn't in the config.
+    CancelInvitation(i64, String),
+    /// Remove a member who isn't in the config.
+    Remove(String),
+}
+
+/// Plan the changes to the organization's membership. `members` is keyed by lowercase login.
+/// Invitations and members that aren't in the config are only cancelled or removed when `prune`
+/// is set, and the `protected` login (the authenticated user) is never removed.
+fn plan_membership_changes(
+    users: &[User],
+    members: &BTreeMap<String, User>,
+    invitations: &[OrgInvitationResponse],
+    prune: bool,
+    protected: Option<&str>,
+) -> Vec<MembershipChange> {
+    let mut changes = Vec::new();
+    for user in users {
+        if let Some(member) = members.get(&user.login.to_lowercase()) {
+            if member.role != user.role {
+                changes.push(MembershipChange::SetRole(member.login.clone(), user.role.clone()));
+            }
+        } else if let Some(invitation) = invitations.iter().find(|i| invitation_matches(i, user)) {
+            // Only invitations addressed to a login can have their role changed.
+            if org_role_from_invitation(&invitation.role) != user.role && !user.login.is_empty() {
+                changes.push(MembershipChange::SetRole(user.login.clone(), user.role.clone()));
+            }
         } else {
-            let text = response.text().await?;
-            error!("GET {} failed with status {}: {}", url, status, text);
-            Err(AppError::GitHubApi(text))
+            changes.push(MembershipChange::Invite(user.clone()));
         }
     }
-
-    /// Fetch all settings for a repo as a HashMap<String, serde_yaml::Value>
-    async fn get_repo_settings(&self, repo_name: &str) -> AppResult<RepoSettings> {
-        let full_url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name);
-        let response = self.get(&full_url).await?;
-        let text = response.text().await?;
-        if text.is_empty() {
-            error!("Empty response body from GET {}", full_url);
-            return Err(AppError::GitHubApi("Empty response body".to_string()));
+    if !prune {
+        return changes;
+    }
+    for invitation in invitations {
+        if !users.iter().any(|u| invitation_matches(invitation, u)) {
+            let label = invitation.login.clone().or_else(|| invitation.email.clone()).unwrap_or_default();
+            changes.push(MembershipChange::CancelInvitation(invitation.id, label));
         }
-        debug!("Raw response for {}: {}", full_url, text);
-        let repo_json: serde_json::Value = serde_json::from_str(&text)
-            .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
-        // Convert to serde_yaml::Value for consistency with config
-        let repo_yaml: serde_yaml::Value = serde_yaml::to_value(repo_json)
-            .map_err(|e| AppError::GitHubApi(format!("Failed to convert repo JSON to YAML: {}", e)))?;
-        // Flatten to a HashMap<String, Value>
-        let mut settings = RepoSettings::new();
-        if let serde_yaml::Value::Mapping(map) = repo_yaml {
-            for (k, v) in map {
-                if let serde_yaml::Value::String(key) = k {
-                    settings.insert(key, v);
-                }
-            }
+    }
+    let wanted: HashSet<String> = users.iter().map(|u| u.login.to_lowercase()).collect();
+    for (key, member) in members {
+        let is_protected = protected.is_some_and(|p| p.eq_ignore_ascii_case(key));
+        if !wanted.contains(key) && !is_protected {
+            changes.push(MembershipChange::Remove(member.login.clone()));
         }
-        Ok(settings)
     }
+    changes
+}
 
-    #[allow(dead_code)]
-    async fn get_repo_visibility(&self, repo_name: &str) -> AppResult<String> {
-        let full_url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name);
-        let response = self.get(&full_url).await?;
-        let text = response.text().await?;
-        if text.is_empty() {
-            error!("Empty response body from GET {}", full_url);
-            return Err(AppError::GitHubApi("Empty response body".to_string()));
+/// One way a user has access to the organization, as found by `offboard`.
+#[derive(Debug, PartialEq)]
+enum AccessGrant {
+    /// Organization membership (or a pending invitation) with the given role.
+    OrgMember { role: String, pending: bool },
+    /// Membership of a team, as member or maintainer.
+    Team { slug: String, role: &'static str },
+    /// Direct collaborator access (or a pending invitation) to a repository.
+    Collaborator { repo: String, permission: Permission },
+    /// Required reviewer of a deployment environment.
+    EnvironmentReviewer { repo: String, environment: String },
+    /// Outside collaborator status.
+    OutsideCollaborator,
+}
+
+impl std::fmt::Display for AccessGrant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessGrant::OrgMember { role, pending: false } => write!(f, "org membership ({})", role),
+            AccessGrant::OrgMember { role, pending: true } => write!(f, "pending org invitation ({})", role),
+            AccessGrant::Team { slug, role } => write!(f, "team {} ({})", slug, role),
+            AccessGrant::Collaborator { repo, permission } => write!(f, "collaborator on {} ({})", repo, permission),
+            AccessGrant::EnvironmentReviewer { repo, environment } => write!(f, "reviewer of environment {} of {}", environment, repo),
+            AccessGrant::OutsideCollaborator => write!(f, "outside collaborator"),
         }
-        let repo: RepoResponse = serde_json::from_str(&text)
-            .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
-        Ok(if repo.private { "private" } else { "public" }.to_string())
     }
+}
 
-    async fn get_team(&self, team_name: &str) -> AppResult<Option<TeamResponse>> {
-        let full_url = format!("{}/orgs/{}/teams/{}", GITHUB_API_BASE_URL, self.org, team_name);
-        match self.get(&full_url).await {
-            Ok(response) => {
-                let text = response.text().await?;
-                if text.is_empty() {
-                    error!("Empty response body from GET {}", full_url);
-                    return Err(AppError::GitHubApi("Empty response body".to_string()));
-                }
-                let team: TeamResponse = serde_json::from_str(&text)
-                    .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
-                if team.name == team_name {
-                    Ok(Some(team))
-                } else {
-                    Ok(None)
-                }
-            }
-            Err(AppError::GitHubApi(e)) if e.contains("404") => Ok(None),
-            Err(e) => Err(e),
+/// Find every way `login` has access in an org config generated from GitHub. Repo grants come
+/// first and org membership last, so revoking in order never leaves dangling access.
+fn find_user_access(config: &Config, login: &str) -> Vec<AccessGrant> {
+    let is_login = |l: &str| l.eq_ignore_ascii_case(login);
+    let mut grants = Vec::new();
+    for repo in &config.repos {
+        for environment in repo.environments.iter().flatten().filter(|e| e.reviewers.users.iter().any(|u| is_login(u))) {
+            grants.push(AccessGrant::EnvironmentReviewer { repo: repo.name.clone(), environment: environment.name.clone() });
+        }
+        for collaborator in repo.collaborators.iter().flatten().filter(|c| is_login(&c.login)) {
+            grants.push(AccessGrant::Collaborator { repo: repo.name.clone(), permission: collaborator.permission.clone() });
         }
     }
-
-    async fn get_user_membership(&self, login: &str) -> AppResult<Option<String>> {
-        let full_url = format!("{}/orgs/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, login);
-        match self.get(&full_url).await {
-            Ok(response) => {
-                let text = response.text().await?;
-                if text.is_empty() {
-                    error!("Empty response body from GET {}", full_url);
-                    return Err(AppError::GitHubApi("Empty response body".to_string()));
-                }
-                let membership: MembershipResponse = serde_json::from_str(&text)
-                    .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
-                Ok(Some(membership.role))
-            }
-            Err(AppError::GitHubApi(e)) if e.contains("404") => Ok(None),
-            Err(e) => Err(e),
+    for team in &config.teams {
+        let slug = team.slug.clone().unwrap_or_else(|| slugify_team_name(&team.name));
+        if team.maintainers.iter().flatten().any(|m| is_login(m)) {
+            grants.push(AccessGrant::Team { slug, role: TEAM_ROLE_MAINTAINER });
+        } else if team.members.iter().any(|m| is_login(m)) {
+            grants.push(AccessGrant::Team { slug, role: TEAM_ROLE_MEMBER });
         }
     }
-
-    async fn get_team_repos(&self, team_name: &str) -> AppResult<Vec<TeamRepoResponse>> {
-        let full_url = format!("{}/orgs/{}/teams/{}/repos?per_page=100", GITHUB_API_BASE_URL, self.org, team_name);
-        let response = self.get(&full_url).await?;
-        let text = response.text().await?;
-        let repos: Vec<TeamRepoResponse> = serde_json::from_str(&text)
-            .map_err(|e| AppError::GitHubApi(format!("Failed to parse team repos: {}", e)))?;
-        Ok(repos)
+    if config.outside_collaborators.iter().flatten().any(|l| is_login(l)) {
+        grants.push(AccessGrant::OutsideCollaborator);
     }
-
-    ///
-    /// Retrieve all webhooks configured for a given repository.
-    ///
-    /// # Arguments
-    /// * `repo_name` - The name of the repository.
-    ///
-    /// # Returns
-    /// * `Ok(Vec<WebhookResponse>)` containing the list of webhooks if successful.
-    /// * `Err(AppError)` if the API call or parsing fails.
-    ///
-    pub async fn get_webhooks(&self, repo_name: &str) -> AppResult<Vec<WebhookResponse>> {
-        let full_url = format!("{}/repos/{}/{}/hooks", GITHUB_API_BASE_URL, self.org, repo_name);
-        let response = self.get(&full_url).await?;
-        let text = response.text().await?;
-        let webhooks: Vec<WebhookResponse> = serde_json::from_str(&text)
-            .map_err(|e| AppError::GitHubApi(format!("Failed to parse webhooks: {}", e)))?;
-        Ok(webhooks)
+    if let Some(user) = config.users.iter().find(|u| is_login(&u.login)) {
+        grants.push(AccessGrant::OrgMember { role: user.role.clone(), pending: user.state == Some(MembershipState::Pending) });
     }
+    grants
+}
 
-    async fn create_webhook(&self, repo_name: &str, webhook: &WebhookConfig) -> AppResult<()> {
-        let url = format!("{}/repos/{}/{}/hooks", GITHUB_API_BASE_URL, self.org, repo_name);
-    
-        let body = json!({
-            "name": "web", // Standard name for webhooks
-            "active": true,
-            "events": webhook.events, // Use the events from the parameter
-            "config": {
-                "url": webhook.url, // Use the URL from the parameter
-                "content_type": webhook.content_type, // Use the content_type from the parameter
-                "insecure_ssl": "0" // Standard setting
-            }
-        });
-    
-        debug!("Webhook create payload: {}", serde_json::to_string(&body)?);
-        info!("Creating webhook for {}/{}", self.org, repo_name);
-        self.send_post(&url, body).await?;
-        Ok(())
+/// Take pending invitations out of the user lists of a diff, so they aren't reported as drift
+/// until they're accepted. Returns a note for each invitation that matches a configured user.
+fn normalize_memberships_for_diff(local: &mut Config, github: &mut Config) -> Vec<String> {
+    let mut notes = Vec::new();
+    for user in local.users.iter_mut().chain(github.users.iter_mut()) {
+        user.teams.clear();
     }
-
-    async fn update_webhook(&self, repo_name: &str, hook_id: i64, webhook: &WebhookConfig) -> AppResult<()> {
-        let url = format!("{}/repos/{}/{}/hooks/{}", GITHUB_API_BASE_URL, self.org, repo_name, hook_id);
-    
-        let body = json!({
-            // Note: Do not include "name" or "active" when updating
-            // according to some GitHub API docs, only config/events/add_events/remove_events
-            // Let's stick to config and events for simplicity here. Re-add 'active' if needed.
-            // "active": true, // Usually controlled separately if needed
-            "events": webhook.events, // Use the events from the parameter
-            "config": {
-                "url": webhook.url, // Use the URL from the parameter
-                "content_type": webhook.content_type, // Use the content_type from the parameter
-                "insecure_ssl": "0" // Standard setting
-            }
-            // "add_events": [], // Optional: Specific events to add without replacing all
-            // "remove_events": [] // Optional: Specific events to remove
-        });
-    
-        debug!("Webhook update payload: {}", serde_json::to_string(&body)?);
-        info!("Updating webhook {} for {}/{}", hook_id, self.org, repo_name); // Log hook_id
-        self.send_patch(&url, body).await?;
-        Ok(())
+    for user in &mut local.users {
+        user.state = None;
     }
-
-    async fn manage_webhooks(&self, repo_name: &str, webhook: &WebhookConfig, dry_run: bool) -> AppResult<()> {
-        let current_hooks = self.get_webhooks(repo_name).await?;
-        let existing = current_hooks.iter().find(|h| h.config.url == webhook.url);
-
-        if dry_run {
-            match existing {
-                Some(hook) if hook.events != webhook.events || hook.config.content_type != webhook.content_type => {
-                    info!(
-                        "[Dry Run] Would update webhook for {}/{}: events {:?} -> {:?}, content_type {} -> {}",
-                        self.org, repo_name, hook.events, webhook.events, hook.config.content_type, webhook.content_type
-                    );
-                }
-                Some(_) => debug!("[Dry Run] Webhook for {}/{} already matches desired config", self.org, repo_name),
-                None => info!(
-                    "[Dry Run] Would create webhook for {}/{} with config: {:?}",
-                    self.org, repo_name, webhook
-                ),
-            }
-        } else {
-            match existing {
-                Some(hook) if hook.events != webhook.events || hook.config.content_type != webhook.content_type => {
-                    self.update_webhook(repo_name, hook.id.unwrap(), webhook).await?;
+    let mut matched = Vec::new();
+    github.users.retain(|pending| {
+        if pending.state != Some(MembershipState::Pending) {
+            return true;
+        }
+        let matches = |u: &User| {
+            (!pending.login.is_empty() && u.login.eq_ignore_ascii_case(&pending.login))
+                || (pending.email.is_some() && u.email.as_deref().map(str::to_lowercase) == pending.email.as_deref().map(str::to_lowercase))
+        };
+        match local.users.iter().position(matches) {
+            Some(index) => {
+                let configured = &local.users[index];
+                if configured.role == pending.role {
+                    notes.push(format!("{}: invitation pending ({})", pending.label(), pending.role));
+                } else {
+                    notes.push(format!("{}: invitation pending ({}, config says {})", pending.label(), pending.role, configured.role));
                 }
-                Some(_) => debug!("Webhook for {}/{} already up to date", self.org, repo_name),
-                None => self.create_webhook(repo_name, webhook).await?,
+                matched.push(index);
+                false
             }
+            None => true,
         }
-        Ok(())
-    }
-
-    ///
-    /// Update repository settings on GitHub to match the desired configuration.
-    ///
-    /// This method compares the current repository settings with the desired settings and applies only the
-    /// necessary changes using the appropriate GitHub API endpoints. The mapping table determines which
-    /// settings correspond to which API endpoints and JSON fields.
-    ///
-    /// # Arguments
-    /// * `repo` - The repository whose settings should be updated.
-    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
-    ///
-    /// # Returns
-    /// * `Ok(())` if all updates succeed or are skipped in dry-run mode.
-    /// * `Err(AppError)` if any API call fails.
-    ///
-    /// # Behavior
-    /// - Only settings that differ from the current state are updated.
-    /// - Supports PATCH, PUT, and POST methods as defined in the mapping.
-    /// - Logs actions in dry-run mode instead of performing them.
-    /// - Also manages webhooks if defined in the repo configuration.
-    ///
-    pub async fn update_repo_settings(&self, repo: &Repo, dry_run: bool) -> AppResult<()> {
-        let current = self.get_repo_settings(&repo.name).await?;
-        let desired = &repo.settings;
-        let mapping = get_github_api_mapping();
+    });
+    local.users = std::mem::take(&mut local.users)
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !matched.contains(index))
+        .map(|(_, user)| user)
+        .collect();
+    notes
+}
 
-        // --- Optimization suggestion (Apply after fixing URL): ---
-        // Instead of sending one PATCH per setting, collect all PATCHes for the same endpoint.
-        // Create a HashMap<String, serde_json::Map<String, serde_json::Value>> where the key is the endpoint URL.
-        let mut pending_updates: HashMap<String, (String, serde_json::Map<String, serde_json::Value>)> = HashMap::new();
-        // The tuple stores (HTTP Method, Body Map)
+#[derive(Debug, Deserialize)]
+struct TeamRepoResponse {
+    name: String,
+    #[serde(default)]
+    role_name: Option<String>,
+    permissions: PermissionDetails,
+}
 
-        for (k, v_desired) in desired.iter() {
-            if let Some(field_map) = mapping.get(k.as_str()) {
-                let v_current = current.get(k);
-                if v_current != Some(v_desired) {
-                    // Construct the *full* URL for this *specific* setting's mapped endpoint
-                     let relative_path = field_map
-                        .endpoint
-                        .replace("{org}", &self.org)
-                        .replace("{owner}", &self.org)
-                        .replace("{repo}", &repo.name); // Add other replacements if needed (e.g., {team_slug})
+#[derive(Debug, Deserialize)]
+struct PermissionDetails {
+    pull: bool,
+    #[serde(default)]
+    triage: bool,
+    push: bool,
+    #[serde(default)]
+    maintain: bool,
+    admin: bool,
+}
 
-                    // ****** THIS IS THE KEY FIX ******
-                    let full_url = format!("{}{}", GITHUB_API_BASE_URL, relative_path);
-                    // ****** END KEY FIX ******
+#[derive(Debug, Deserialize)]
+struct CustomRepositoryRolesResponse {
+    custom_roles: Vec<CustomRepositoryRole>,
+}
 
-                    // Optimization: Group updates by full_url and method
-                    let (_, body_map) = pending_updates
-                         .entry(full_url.clone()) // Group by the calculated full URL
-                         .or_insert_with(|| (field_map.method.to_string(), serde_json::Map::new())); // Store method and init body map
+#[derive(Debug, Deserialize)]
+struct CustomRepositoryRole {
+    name: String,
+    #[serde(default)]
+    base_role: Option<String>,
+}
 
-                    // Add the current setting change to the body map for this URL
-                     body_map.insert(
-                        field_map.json_path.to_string(), // Use the key expected by the API
-                        serde_json::to_value(v_desired).unwrap_or(serde_json::Value::Null),
-                    );
-                }
-            } else {
-                debug!("No API mapping for repo setting '{}', skipping.", k);
+#[derive(Debug, Deserialize)]
+struct OrgResponse {
+    #[serde(default)]
+    default_repository_permission: Option<String>,
+}
+
+/// Describe every assignment whose permission is neither a built-in role nor one of the org's
+/// custom repository roles.
+fn invalid_assignment_permissions(assignments: &[Assignment], custom_roles: &HashSet<String>) -> Vec<String> {
+    assignments
+        .iter()
+        .filter_map(|a| match &a.permission {
+            Permission::Custom(name) if !custom_roles.contains(name) => Some(format!(
+                "team '{}' on repo '{}' has unknown permission '{}'",
+                a.team, a.repo, name
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Describe every repo collaborator whose permission is neither a built-in role nor one of the
+/// org's custom repository roles.
+fn invalid_collaborator_permissions(repos: &[Repo], custom_roles: &HashSet<String>) -> Vec<String> {
+    repos
+        .iter()
+        .flat_map(|repo| repo.collaborators.iter().flatten().map(move |c| (repo, c)))
+        .filter_map(|(repo, c)| match &c.permission {
+            Permission::Custom(name) if !custom_roles.contains(name) => Some(format!(
+                "collaborator '{}' on repo '{}' has unknown permission '{}'",
+                c.login, repo.name, name
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct CollaboratorResponse {
+    login: String,
+    #[serde(default)]
+    role_name: Option<String>,
+    permissions: PermissionDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct InvitationResponse {
+    id: i64,
+    invitee: Option<LoginResponse>,
+    permissions: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginResponse {
+    login: String,
+}
+
+/// A single change needed to bring a repo's direct collaborators in line with the config.
+#[derive(Debug, PartialEq)]
+enum CollaboratorChange {
+    /// Add the collaborator (inviting them if needed) or change their permission.
+    Grant(String, Permission),
+    /// Change the permission offered by a pending invitation.
+    UpdateInvitation(i64, String, Permission),
+    /// Remove an active collaborator.
+    Remove(String),
+    /// Withdraw a pending invitation.
+    CancelInvitation(i64, String),
+}
+
+/// Plan the changes to a repo's direct collaborators. `active` and `pending` are keyed by lowercase
+/// login, since GitHub logins are case-insensitive.
+fn plan_collaborator_changes(
+    desired: &[Collaborator],
+    active: &BTreeMap<String, Collaborator>,
+    pending: &BTreeMap<String, (i64, Collaborator)>,
+    prune: bool,
+) -> Vec<CollaboratorChange> {
+    let mut changes = Vec::new();
+    let wanted: HashSet<String> = desired.iter().map(|c| c.login.to_lowercase()).collect();
+    for collaborator in desired {
+        let key = collaborator.login.to_lowercase();
+        if let Some(current) = active.get(&key) {
+            if current.permission != collaborator.permission {
+                changes.push(CollaboratorChange::Grant(current.login.clone(), collaborator.permission.clone()));
             }
+        } else if let Some((id, invited)) = pending.get(&key) {
+            if invited.permission != collaborator.permission {
+                changes.push(CollaboratorChange::UpdateInvitation(*id, invited.login.clone(), collaborator.permission.clone()));
+            }
+        } else {
+            changes.push(CollaboratorChange::Grant(collaborator.login.clone(), collaborator.permission.clone()));
+        }
+    }
+    if !prune {
+        return changes;
+    }
+    for (key, current) in active {
+        if !wanted.contains(key) {
+            changes.push(CollaboratorChange::Remove(current.login.clone()));
+        }
+    }
+    for (key, (id, invited)) in pending {
+        if !wanted.contains(key) {
+            changes.push(CollaboratorChange::CancelInvitation(*id, invited.login.clone()));
         }
+    }
+    changes
+}
 
+/// Media type that makes the team repository endpoint return the repository (including the team's
+/// `role_name`) instead of an empty 204 response.
+const REPOSITORY_MEDIA_TYPE: &str = "application/vnd.github.v3.repository+json";
 
-        // --- Apply Grouped Updates ---
-        for (full_url, (method, body_map)) in pending_updates {
-             if body_map.is_empty() { continue; } // Skip if no changes ended up for this group
+/// Determine the permission a team has on a repo. Prefers `role_name`, which also covers custom
+/// repository roles, and falls back to the highest permission flag for older GitHub Enterprise Server.
+fn permission_from_response(repo: &TeamRepoResponse) -> Option<Permission> {
+    permission_from_role(repo.role_name.as_deref(), &repo.permissions)
+}
 
-             let body = serde_json::Value::Object(body_map);
+/// Determine a permission from a `role_name` if present, else from the permission flags.
+fn permission_from_role(role_name: Option<&str>, perms: &PermissionDetails) -> Option<Permission> {
+    if let Some(role_name) = role_name.filter(|r| !r.is_empty()) {
+        return Some(Permission::from(role_name));
+    }
+    if perms.admin {
+        Some(Permission::Admin)
+    } else if perms.maintain {
+        Some(Permission::Maintain)
+    } else if perms.push {
+        Some(Permission::Write)
+    } else if perms.triage {
+        Some(Permission::Triage)
+    } else if perms.pull {
+        Some(Permission::Read)
+    } else {
+        None
+    }
+}
 
-             if dry_run {
-                 info!(
-                     "[Dry Run] Would {} {} with body: {:?}",
-                     method, full_url, body // Log the full URL
-                 );
-             } else {
-                 debug!("{} {} with body: {:?}", method, full_url, body); // Log the full URL
-                 match method.as_str() { // Use the stored method string
-                     "PATCH" => self.send_patch(&full_url, body).await?, // Pass the FULL URL
-                     "PUT" => self.send_put(&full_url, Some(body)).await?, // Pass the FULL URL
-                     "POST" => self.send_post(&full_url, body).await?, // Pass the FULL URL
-                     _ => error!("Unsupported HTTP method: {}", method),
-                 }
-                 info!("Applied relevant settings changes for repo {} via {}", repo.name, full_url); // Add success log
-             }
-        }
+/// A secret or variable as listed by GitHub. Secrets have no value.
+#[derive(Debug, Deserialize)]
+struct ActionsItemResponse {
+    name: String,
+    #[serde(default)]
+    value: Option<String>,
+    #[serde(default)]
+    visibility: Option<ActionsVisibility>,
+}
 
+#[derive(Debug, Deserialize)]
+struct ActionsPublicKey {
+    key_id: String,
+    key: String,
+}
 
-        // If a webhook is defined in the repo config, manage it as well.
-        // Ensure manage_webhooks also uses full URLs if it calls send_* methods directly.
-         if let Some(webhook) = repo.webhook.as_ref() {
-             self.manage_webhooks(&repo.name, webhook, dry_run).await?;
-         }
+#[derive(Debug, Deserialize)]
+struct RepositoryNameResponse {
+    name: String,
+}
 
-        Ok(())
+/// Where Actions secrets and variables are stored: the organization, a repository or an environment.
+struct ActionsScope {
+    /// Base URL; secrets and variables live under `{url}/secrets` and `{url}/variables`.
+    url: String,
+    /// Names the scope in log messages and state file keys, e.g. `my-org/my-repo`.
+    label: String,
+    /// Organization secrets and variables have a visibility and selected repositories.
+    org_level: bool,
+}
+
+/// A secret or variable reduced to what can be compared with GitHub. Secrets have no value, since
+/// GitHub never returns it.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct ActionsItem {
+    name: String,
+    value: Option<String>,
+    visibility: Option<ActionsVisibility>,
+    selected_repositories: Vec<String>,
+}
+
+impl ActionsItem {
+    /// Build the item sync should apply. Org-level items default to `private`, and selected
+    /// repositories only count when the visibility is `selected`.
+    fn desired(name: &str, value: Option<&str>, visibility: Option<ActionsVisibility>, selected: &[String], org_level: bool) -> Self {
+        let visibility = org_level.then(|| visibility.unwrap_or(ActionsVisibility::Private));
+        let mut selected_repositories = if visibility == Some(ActionsVisibility::Selected) { selected.to_vec() } else { Vec::new() };
+        selected_repositories.sort_by_key(|r| r.to_lowercase());
+        ActionsItem { name: name.to_string(), value: value.map(str::to_string), visibility, selected_repositories }
     }
 
-        // Add a helper for DELETE
-    async fn send_delete(&self, url: &str) -> AppResult<()> {
-        debug!("Attempting to build DELETE request for URL: '{}'", url);
-        // Add token/URL checks if desired
-        let response = self
-            .client
-            .delete(url)
-            .header(reqwest::header::AUTHORIZATION, format!("Bearer {}", self.token))
-            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
-            .header(reqwest::header::USER_AGENT, "gh-config")
-            .send()
-            .await?;
+    fn from_secret(secret: &ActionsSecret, org_level: bool) -> Self {
+        Self::desired(&secret.name, None, secret.visibility, &secret.selected_repositories, org_level)
+    }
 
-        let status = response.status();
-        debug!("DELETE {} returned status: {}", url, status);
-        // DELETE for membership returns 204 No Content on success
-        if status.is_success() || status == reqwest::StatusCode::NO_CONTENT {
-            Ok(())
-        } else {
-            let text = response.text().await?;
-            error!("DELETE {} failed with status {}: {}", url, status, text);
-            Err(AppError::GitHubApi(text))
-        }
+    fn from_variable(variable: &ActionsVariable, org_level: bool) -> Self {
+        Self::desired(&variable.name, Some(&variable.value), variable.visibility, &variable.selected_repositories, org_level)
     }
 
-    // Add a helper to get team members
-    async fn get_team_members(&self, team_slug: &str) -> AppResult<HashSet<String>> {
-        let url = format!("https://api.github.com/orgs/{}/teams/{}/members?per_page=100", self.org, team_slug);
-        let response = self.get(&url).await?;
-        let members_json: Vec<serde_json::Value> = response.json().await.map_err(AppError::Http)?;
-        let members = members_json.iter()
-            .filter_map(|m| m["login"].as_str().map(String::from))
-            .collect::<HashSet<String>>();
-        Ok(members)
+    /// GitHub stores names in upper case, so names and repositories are compared case-insensitively.
+    fn matches(&self, other: &ActionsItem) -> bool {
+        let lower = |repos: &[String]| repos.iter().map(|r| r.to_lowercase()).collect::<Vec<_>>();
+        self.name.eq_ignore_ascii_case(&other.name)
+            && self.value == other.value
+            && self.visibility == other.visibility
+            && lower(&self.selected_repositories) == lower(&other.selected_repositories)
     }
+}
 
-    ///
-    /// Create a team in the GitHub organization and add members.
-    ///
-    /// If the team already exists, ensures all specified members are present.
-    ///
-    /// # Arguments
-    /// * `team` - The team to create or update.
-    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
-    ///
-    /// # Returns
-    /// * `Ok(())` if the team is created/updated successfully or in dry-run mode.
-    /// * `Err(AppError)` if any API call fails.
-    ///
-    pub async fn create_team(&self, team: &Team, dry_run: bool) -> AppResult<()> {
-        let url_create = format!("https://api.github.com/orgs/{}/teams", self.org); // For POST
-        let team_slug = &team.name; // Assuming name is slug for now
-    
-        let existing_team = self.get_team(team_slug).await?;
-    
-        if existing_team.is_none() {
-            // --- Create Team ---
-            if dry_run {
-                info!("[Dry Run] Would create team: {}", team_slug);
-                for member in &team.members {
-                   info!("[Dry Run] Would add {} to team {}", member, team_slug);
-                }
-            } else {
-                info!("Creating team: {}", team_slug);
-                let body = json!({"name": team.name, "privacy": "closed"}); // Use team.name for creation
-                self.send_post(&url_create, body).await?;
-                for member in &team.members {
-                    let member_url = format!("https://api.github.com/orgs/{}/teams/{}/memberships/{}", self.org, team_slug, member);
-                    // Use PUT for initial add too, it works as add/update
-                    match self.send_put(&member_url, Some(json!({"role": "member"}))).await { // Specify role maybe? GitHub default is member
-                        Ok(()) => info!("Added {} to new team {}", member, team_slug),
-                        Err(e) => error!("Failed to add {} to new team {}: {}", member, team_slug, e),
-                    }
-                }
+/// A single change to the secrets or variables of a scope. Indexes refer to the desired items.
+#[derive(Debug, PartialEq)]
+enum ActionsItemChange {
+    Create(usize),
+    Update(usize),
+    Delete(String),
+}
+
+/// Plan the changes to the secrets or variables of a scope. Items are matched by name; items on
+/// GitHub that aren't desired are deleted only when `prune` is set. Names in `stale` are updated
+/// even if everything GitHub returns matches, so their secret value is sent again.
+fn plan_actions_item_changes(desired: &[ActionsItem], current: &[ActionsItem], stale: &HashSet<&str>, prune: bool) -> Vec<ActionsItemChange> {
+    let mut changes = Vec::new();
+    for (index, item) in desired.iter().enumerate() {
+        match current.iter().find(|c| c.name.eq_ignore_ascii_case(&item.name)) {
+            Some(existing) if existing.matches(item) && !stale.contains(item.name.as_str()) => {}
+            Some(_) => changes.push(ActionsItemChange::Update(index)),
+            None => changes.push(ActionsItemChange::Create(index)),
+        }
+    }
+    if prune {
+        for existing in current {
+            if !desired.iter().any(|d| d.name.eq_ignore_ascii_case(&existing.name)) {
+                changes.push(ActionsItemChange::Delete(existing.name.clone()));
             }
-        } else {
-             // --- Update Existing Team Members ---
-             info!("Team {} already exists, syncing members", team_slug);
-    
-             let config_members: HashSet<String> = team.members.iter().cloned().collect();
-             let github_members = self.get_team_members(team_slug).await?;
-    
-             let members_to_add = config_members.difference(&github_members);
-             let members_to_remove = github_members.difference(&config_members);
-    
-             for member in members_to_add {
-                 let member_url = format!("https://api.github.com/orgs/{}/teams/{}/memberships/{}", self.org, team_slug, member);
-                if dry_run {
-                    info!("[Dry Run] Would add {} to team {}", member, team_slug);
-                } else {
-                    match self.send_put(&member_url, Some(json!({"role": "member"}))).await {
-                         Ok(()) => info!("Added {} to team {}", member, team_slug),
-                         Err(e) => error!("Failed to add {} to team {}: {}", member, team_slug, e),
-                     }
-                }
-             }
-    
-             for member in members_to_remove {
-                  let member_url = format!("https://api.github.com/orgs/{}/teams/{}/memberships/{}", self.org, team_slug, member);
-                 if dry_run {
-                     info!("[Dry Run] Would remove {} from team {}", member, team_slug);
-                 } else {
-                     match self.send_delete(&member_url).await {
-                         Ok(()) => info!("Removed {} from team {}", member, team_slug),
-                         Err(e) => error!("Failed to remove {} from team {}: {}", member, team_slug, e),
-                     }
-                 }
-             }
-    
-              if config_members == github_members {
-                   debug!("Team {} members already match config.", team_slug);
-              }
         }
-        Ok(())
     }
+    changes
+}
 
-    ///
-    /// Add a user to the GitHub organization or update their role.
-    ///
-    /// If the user is already a member, updates their role if necessary.
-    ///
-    /// # Arguments
-    /// * `user` - The user to add or update.
-    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
-    ///
-    /// # Returns
-    /// * `Ok(())` if the user is added/updated successfully or in dry-run mode.
-    /// * `Err(AppError)` if any API call fails.
-    ///
-    pub async fn add_user_to_org(&self, user: &User, dry_run: bool) -> AppResult<()> {
-        if dry_run {
-            let current_role = self.get_user_membership(&user.login).await?;
-            match current_role {
-                Some(role) if role != user.role => {
-                    info!(
-                        "[Dry Run] Would update {} role: {} -> {}",
-                        user.login, role, user.role
-                    );
-                }
-                None => {
-                    info!("[Dry Run] Would add {} with role {}", user.login, user.role);
-                }
-                _ => debug!("[Dry Run] No changes needed for user {}", user.login),
-            }
-            Ok(())
-        } else {
-            let full_url = format!(
-                "{}/orgs/{}/memberships/{}",
-                GITHUB_API_BASE_URL, self.org, user.login
-            );
-            let body = json!({
-                "role": user.role
-            });
-            info!("Adding {} to org with role {}", user.login, user.role);
-            self.send_put(&full_url, Some(body)).await?;
-            Ok(())
+/// Encrypt a secret value for the Actions secrets API: a libsodium sealed box for the scope's
+/// base64-encoded public key, itself base64 encoded.
+fn encrypt_secret(public_key: &str, value: &str) -> AppResult<String> {
+    use base64::Engine;
+    let engine = base64::engine::general_purpose::STANDARD;
+    let key: [u8; crypto_box::KEY_SIZE] = engine
+        .decode(public_key)
+        .ok()
+        .and_then(|key| key.try_into().ok())
+        .ok_or_else(|| AppError::GitHubApi("Invalid Actions public key returned by GitHub".to_string()))?;
+    let sealed = crypto_box::PublicKey::from(key)
+        .seal(&mut crypto_box::aead::OsRng, value.as_bytes())
+        .map_err(|_| AppError::GitHubApi("Failed to encrypt secret".to_string()))?;
+    Ok(engine.encode(sealed))
+}
+
+/// Render the visibility and selected repositories of an org secret or variable as YAML.
+fn actions_visibility_yaml(visibility: Option<ActionsVisibility>, selected: &[String], indent: &str) -> String {
+    let mut yaml = String::new();
+    if let Some(visibility) = visibility {
+        yaml.push_str(&format!("{}  visibility: {}\n", indent, visibility));
+    }
+    if !selected.is_empty() {
+        yaml.push_str(&format!("{}  selected_repositories:\n", indent));
+        for repo in selected {
+            yaml.push_str(&format!("{}  - {}\n", indent, repo));
         }
     }
+    yaml
+}
 
-    ///
-    /// Assign a team to a repository with a specific permission level.
-    ///
-    /// If the team already has a different permission, updates it.
-    ///
-    /// # Arguments
-    /// * `assignment` - The assignment specifying team, repo, and permission.
-    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
-    ///
-    /// # Returns
-    /// * `Ok(())` if the assignment is applied successfully or in dry-run mode.
-    /// * `Err(AppError)` if any API call fails.
-    ///
-    pub async fn assign_team_to_repo(&self, assignment: &Assignment, dry_run: bool) -> AppResult<()> {
-        if dry_run {
-            let current_perm = self
-                .get_team_repo_permission(&assignment.team, &assignment.repo)
-                .await?;
-            match current_perm {
-                Some(perm) if perm != assignment.permission => {
-                    info!(
-                        "[Dry Run] Would update {}/{} permission for team {}: {} -> {}",
-                        self.org, assignment.repo, assignment.team, perm, assignment.permission
-                    );
-                }
-                None => {
-                    info!(
-                        "[Dry Run] Would assign team {} to {}/{} with permission {}",
-                        assignment.team, self.org, assignment.repo, assignment.permission
-                    );
-                }
-                _ => debug!(
-                    "[Dry Run] No changes needed for team {} on {}/{}",
-                    assignment.team, self.org, assignment.repo
-                ),
-            }
-            Ok(())
-        } else {
-            let full_url = format!(
-                "{}/orgs/{}/teams/{}/repos/{}/{}",
-                GITHUB_API_BASE_URL, self.org, assignment.team, self.org, assignment.repo
-            );
-            let body = json!({
-                "permission": assignment.permission
-            });
-            info!(
-                "Assigning team {} to repo {} with permission {}",
-                assignment.team, assignment.repo, assignment.permission
-            );
-            self.send_put(&full_url, Some(body)).await?;
-            Ok(())
+/// Render Actions secrets as a `secrets:` block, with list items at `indent`.
+fn actions_secrets_yaml(secrets: &[ActionsSecret], indent: &str) -> String {
+    let mut yaml = format!("{}secrets:\n", indent);
+    for secret in secrets {
+        let value = match &secret.value {
+            SecretRef::Env(name) => format!("env: {}", yaml_scalar(name)),
+            SecretRef::File(path) => format!("file: {}", yaml_scalar(path)),
+        };
+        yaml.push_str(&format!("{}- name: {}\n{}  value:\n{}    {}\n", indent, secret.name, indent, indent, value));
+        yaml.push_str(&actions_visibility_yaml(secret.visibility, &secret.selected_repositories, indent));
+    }
+    yaml
+}
+
+/// Render Actions variables as a `variables:` block, with list items at `indent`.
+fn actions_variables_yaml(variables: &[ActionsVariable], indent: &str) -> String {
+    let mut yaml = format!("{}variables:\n", indent);
+    for variable in variables {
+        yaml.push_str(&format!("{}- name: {}\n{}  value: {}\n", indent, variable.name, indent, yaml_scalar(&variable.value)));
+        yaml.push_str(&actions_visibility_yaml(variable.visibility, &variable.selected_repositories, indent));
+    }
+    yaml
+}
+
+/// Compare one scope's secrets the way sync applies them. GitHub never returns secret values, so
+/// they are taken from the local config, and names take the local spelling.
+fn normalize_secrets_for_diff(local: &mut Option<Vec<ActionsSecret>>, github: &mut Option<Vec<ActionsSecret>>, org_level: bool) {
+    let Some(local) = local else {
+        *github = None;
+        return;
+    };
+    let github = github.get_or_insert_with(Vec::new);
+    for secret in github.iter_mut() {
+        if let Some(configured) = local.iter().find(|l| l.name.eq_ignore_ascii_case(&secret.name)) {
+            secret.name = configured.name.clone();
+            secret.value = configured.value.clone();
         }
     }
+    for secret in local.iter_mut() {
+        let item = ActionsItem::from_secret(secret, org_level);
+        secret.visibility = item.visibility;
+        secret.selected_repositories = item.selected_repositories;
+    }
+    local.sort_by(|a, b| a.name.cmp(&b.name));
+    github.sort_by(|a, b| a.name.cmp(&b.name));
+}
 
-    async fn get_team_repo_permission(&self, team: &str, repo: &str) -> AppResult<Option<String>> {
-        let full_url = format!(
-            "{}/orgs/{}/teams/{}/repos/{}/{}",
-            GITHUB_API_BASE_URL, self.org, team, self.org, repo
-        );
-        match self.get(&full_url).await {
-            Ok(response) => {
-                let text = response.text().await?;
-                if text.is_empty() {
-                    debug!("Empty response body from GET {}, assuming permission exists but not detailed", full_url);
-                    return Ok(Some("push".to_string()));
-                }
-                let perms: TeamRepoResponse = serde_json::from_str(&text)
-                    .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
-                let permission = if perms.permissions.admin {
-                    "admin"
-                } else if perms.permissions.push {
-                    "push"
-                } else if perms.permissions.pull {
-                    "pull"
-                } else {
-                    "none"
-                };
-                Ok(Some(permission.to_string()))
-            }
-            Err(AppError::GitHubApi(e)) if e.contains("404") => Ok(None),
-            Err(e) => Err(e),
+/// Compare one scope's variables the way sync applies them, with names in the local spelling.
+fn normalize_variables_for_diff(local: &mut Option<Vec<ActionsVariable>>, github: &mut Option<Vec<ActionsVariable>>, org_level: bool) {
+    let Some(local) = local else {
+        *github = None;
+        return;
+    };
+    let github = github.get_or_insert_with(Vec::new);
+    for variable in github.iter_mut() {
+        if let Some(configured) = local.iter().find(|l| l.name.eq_ignore_ascii_case(&variable.name)) {
+            variable.name = configured.name.clone();
         }
     }
+    for variable in local.iter_mut() {
+        let item = ActionsItem::from_variable(variable, org_level);
+        variable.visibility = item.visibility;
+        variable.selected_repositories = item.selected_repositories;
+    }
+    local.sort_by(|a, b| a.name.cmp(&b.name));
+    github.sort_by(|a, b| a.name.cmp(&b.name));
+}
 
-    pub async fn generate_config_and_write(&self, config_path: &str, dry_run: bool) -> AppResult<()> {
-        info!("Generating config from GitHub org: {}", self.org);
-        let config = self.generate_unfiltered_config_from_org().await?;  // Make sure to call an unfiltered version
+/// Normalize org and repo Actions secrets and variables for diffing. Lists that aren't in the
+/// local config aren't managed, so they are dropped from the GitHub side.
+fn normalize_actions_for_diff(local: &mut Config, github: &mut Config) {
+    normalize_secrets_for_diff(&mut local.secrets, &mut github.secrets, true);
+    normalize_variables_for_diff(&mut local.variables, &mut github.variables, true);
+    for github_repo in &mut github.repos {
+        if let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) {
+            normalize_secrets_for_diff(&mut local_repo.secrets, &mut github_repo.secrets, false);
+            normalize_variables_for_diff(&mut local_repo.variables, &mut github_repo.variables, false);
+        }
+    }
+}
 
-        // ... rest of the YAML writing logic ...
-        // (Ensure this part uses the full, unfiltered config)
+#[derive(Debug, Deserialize)]
+struct DeployKeyResponse {
+    id: u64,
+    title: String,
+    key: String,
+    read_only: bool,
+}
 
-         let mut yaml_content = String::new();
-         yaml_content.push_str(&format!("org: {}\n\n", config.org));
+/// A single change to a repo's deploy keys.
+#[derive(Debug, PartialEq)]
+enum DeployKeyChange<'a> {
+    Add(&'a DeployKey),
+    /// The key changed, so the new key is added before the existing one is deleted.
+    Replace(u64, &'a DeployKey),
+    /// Only the access changed. The same key can't be added twice, so the existing one is deleted
+    /// first and added again.
+    ChangeAccess(u64, &'a DeployKey),
+    Delete(u64, String),
+}
 
-         // Add assignments (sorted)
-         let mut assignments = config.assignments;
-         assignments.sort_by(|a, b| a.team.cmp(&b.team).then(a.repo.cmp(&b.repo)));
-         if !assignments.is_empty() {
-              yaml_content.push_str("assignments:\n");
-              for assignment in &assignments {
-                  yaml_content.push_str(&format!(
-                      "- repo: {}\n  team: {}\n  permission: {}\n",
-                      assignment.repo, assignment.team, assignment.permission
-                  ));
-              }
-              yaml_content.push_str("\n");
-         } else {
-             yaml_content.push_str("assignments: []\n\n");
-         }
+///
+/// Plan the changes to a repo's deploy keys. `desired` keys must have their public key resolved into
+/// `key`. Keys are matched by title; keys whose title isn't listed are deleted only when `prune` is set.
+///
+fn plan_deploy_key_changes<'a>(desired: &'a [DeployKey], current: &[DeployKeyResponse], prune: bool) -> Vec<DeployKeyChange<'a>> {
+    let mut changes = Vec::new();
+    for deploy_key in desired {
+        match current.iter().find(|k| k.title == deploy_key.title) {
+            Some(existing) if Some(&existing.key) == deploy_key.key.as_ref() && existing.read_only == deploy_key.read_only => {}
+            Some(existing) if Some(&existing.key) == deploy_key.key.as_ref() => changes.push(DeployKeyChange::ChangeAccess(existing.id, deploy_key)),
+            Some(existing) => changes.push(DeployKeyChange::Replace(existing.id, deploy_key)),
+            None => changes.push(DeployKeyChange::Add(deploy_key)),
+        }
+    }
+    if prune {
+        for existing in current.iter().filter(|k| !desired.iter().any(|d| d.title == k.title)) {
+            changes.push(DeployKeyChange::Delete(existing.id, existing.title.clone()));
+        }
+    }
+    changes
+}
 
-         // Add default webhook if present
-         if let Some(default_webhook) = &config.default_webhook {
-             yaml_content.push_str("default_webhook:\n");
-             yaml_content.push_str(&format!("  url: {}\n", default_webhook.url));
-             yaml_content.push_str(&format!("  content_type: {}\n", default_webhook.content_type));
-             yaml_content.push_str("  events:\n");
-             let mut events = default_webhook.events.clone();
-             events.sort();
-             for event in &events {
-                 yaml_content.push_str(&format!("  - {}\n", event));
-             }
-             yaml_content.push_str("\n");
-         }
+/// Check that the public key of every configured deploy key can be read.
+fn check_deploy_keys(config: &Config) -> AppResult<()> {
+    for repo in &config.repos {
+        resolve_deploy_keys(repo.deploy_keys.as_deref().unwrap_or_default())?;
+    }
+    Ok(())
+}
 
-         // Add repos (sorted)
-         let mut repos = config.repos;
-         repos.sort_by(|a, b| a.name.cmp(&b.name));
-         if !repos.is_empty() {
-             yaml_content.push_str("repos:\n");
-             for repo in &repos {
-                 yaml_content.push_str(&format!("- name: {}\n", repo.name));
+/// Deploy keys with their public key read into `key`, as GitHub returns them.
+fn resolve_deploy_keys(deploy_keys: &[DeployKey]) -> AppResult<Vec<DeployKey>> {
+    deploy_keys
+        .iter()
+        .map(|deploy_key| Ok(DeployKey { key: Some(deploy_key.public_key()?), key_file: None, ..deploy_key.clone() }))
+        .collect()
+}
 
-                 // Only write settings actually fetched/relevant (potentially limited set here)
-                 if !repo.settings.is_empty() {
-                       yaml_content.push_str("  settings:\n");
-                       // Example: Write only specific known settings for cleaner output
-                       let keys_to_write = ["allow_merge_commit", "allow_squash_merge", "allow_rebase_merge"];
-                       let mut setting_keys: Vec<_> = repo.settings.keys().collect();
-                       setting_keys.sort(); // Sort keys within settings
-                       for key in setting_keys {
-                           if keys_to_write.contains(&key.as_str()) {
-                             if let Some(value) = repo.settings.get(key) {
-                                let val_str = serde_yaml::to_string(value).unwrap_or_default().trim().to_string();
-                                // Basic indentation and handling for simple values
-                                yaml_content.push_str(&format!("    {}: {}\n", key, val_str));
-                              }
-                           }
-                       }
-                 } else {
-                      // Still ensure settings key exists if empty
-                      yaml_content.push_str("  settings: {}\n");
-                 }
+/// Normalize deploy keys for diffing, reading local keys from their files. Keys that would be
+/// replaced are also returned as notes, since GitHub can't change a key in place.
+fn normalize_deploy_keys_for_diff(local: &mut Config, github: &mut Config) -> Vec<String> {
+    let mut notes = Vec::new();
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        let Some(local_keys) = &mut local_repo.deploy_keys else {
+            github_repo.deploy_keys = None;
+            continue;
+        };
+        match resolve_deploy_keys(local_keys) {
+            Ok(resolved) => *local_keys = resolved,
+            Err(e) => warn!("Can't read deploy keys of repo {}: {}", local_repo.name, e),
+        }
+        let github_keys = github_repo.deploy_keys.get_or_insert_with(Vec::new);
+        for local_key in local_keys.iter() {
+            if let Some(github_key) = github_keys.iter().find(|k| k.title == local_key.title) {
+                if github_key != local_key {
+                    notes.push(format!("Deploy key '{}' of repo '{}' would be deleted and added again", local_key.title, local_repo.name));
+                }
+            }
+        }
+        local_keys.sort_by(|a, b| a.title.cmp(&b.title));
+        github_keys.sort_by(|a, b| a.title.cmp(&b.title));
+    }
+    notes
+}
 
+#[derive(Debug, Deserialize)]
+struct LabelResponse {
+    name: String,
+    color: String,
+    description: Option<String>,
+}
 
-                 if let Some(visibility) = &repo.visibility {
-                     yaml_content.push_str(&format!("  visibility: {}\n", visibility));
-                 }
-                 // Write webhook only if different from default (if default exists)
-                  if let Some(webhook) = &repo.webhook {
-                     if config.default_webhook.as_ref() != Some(webhook) {
-                        yaml_content.push_str("  webhook:\n");
-                        yaml_content.push_str(&format!("    url: {}\n", webhook.url));
-                        yaml_content.push_str(&format!("    content_type: {}\n", webhook.content_type));
-                        yaml_content.push_str("    events:\n");
-                        let mut events = webhook.events.clone();
-                        events.sort();
-                        for event in &events {
-                         yaml_content.push_str(&format!("    - {}\n", event));
-                         }
-                     }
-                  } else if config.default_webhook.is_none() {
-                     // If no default, explicitly state no webhook? Or omit? Omit for cleaner.
-                     // yaml_content.push_str("  webhook: null\n");
-                  }
-                // Add branch protections if needed
-                if !repo.branch_protections.is_empty() {
-                    // Serialize properly
-                }
-             }
-             yaml_content.push_str("\n");
-         }
+#[derive(Debug, Deserialize)]
+struct AutolinkResponse {
+    id: u64,
+    key_prefix: String,
+    url_template: String,
+    is_alphanumeric: bool,
+}
 
-         // Add teams (sorted)
-         let mut teams = config.teams;
-         teams.sort_by(|a, b| a.name.cmp(&b.name));
-         if !teams.is_empty() {
-             yaml_content.push_str("teams:\n");
-             for team in &teams {
-                 yaml_content.push_str(&format!("- name: {}\n", team.name));
-                 yaml_content.push_str("  members:\n");
-                 let mut members = team.members.clone();
-                 members.sort();
-                 for member in &members {
-                     yaml_content.push_str(&format!("  - {}\n", member));
-                 }
-             }
-             yaml_content.push_str("\n");
-         }
+#[derive(Debug, Deserialize)]
+struct MilestoneResponse {
+    number: u64,
+    title: String,
+    description: Option<String>,
+    due_on: Option<String>,
+    state: MilestoneState,
+}
 
-         // Add users (sorted)
-         let mut users = config.users;
-         users.sort_by(|a, b| a.login.cmp(&b.login));
-         if !users.is_empty() {
-             yaml_content.push_str("users:\n");
-             for user in &users {
-                 yaml_content.push_str(&format!("- login: {}\n  role: {}\n", user.login, user.role));
-             }
-             yaml_content.push_str("\n");
-         }
+/// A list of repo resources that sync manages: labels, autolinks and milestones.
+trait RepoResource: Clone + PartialEq {
+    /// Name of the resource in log messages.
+    const KIND: &'static str;
+    /// The `--prune` scope that allows deleting unlisted resources of this kind.
+    const PRUNE_SCOPE: PruneScope;
+    /// Path of the collection under the repo.
+    const PATH: &'static str;
+    /// Query string for listing the collection.
+    const LIST_QUERY: &'static str;
+    /// Whether existing resources can be updated. Otherwise they are deleted and created again.
+    const UPDATABLE: bool = true;
+    type Response: serde::de::DeserializeOwned;
+
+    /// The resource and the ID it is addressed by in the API.
+    fn from_response(response: Self::Response) -> (String, Self);
+    /// The key the resource is matched by, case-insensitively.
+    fn key(&self) -> &str;
+    /// A former key, for resources that can be renamed.
+    fn previous_key(&self) -> Option<&str> {
+        None
+    }
+    /// The resource as GitHub would return it once synced over `current`. Fields left unset aren't
+    /// managed, so they keep their current values.
+    fn normalized(&self, current: Option<&Self>) -> Self;
+    fn create_body(&self) -> serde_json::Value;
+    fn update_body(&self) -> serde_json::Value {
+        self.create_body()
+    }
+}
 
-         if dry_run {
-             println!("Dry run: Would write the following config to {}:\n{}", config_path, yaml_content);
-         } else {
-             println!("Writing generated config to {}", config_path);
-             let mut file = File::create(config_path).map_err(AppError::Io)?;
-             file.write_all(yaml_content.as_bytes()).map_err(AppError::Io)?;
-             println!("Config generation completed successfully.");
-         }
-        Ok(())
+impl RepoResource for Label {
+    const KIND: &'static str = "label";
+    const PRUNE_SCOPE: PruneScope = PruneScope::Labels;
+    const PATH: &'static str = "labels";
+    const LIST_QUERY: &'static str = "?per_page=100";
+    type Response = LabelResponse;
+
+    fn from_response(response: LabelResponse) -> (String, Self) {
+        let label = Label { name: response.name, color: response.color, description: response.description.filter(|d| !d.is_empty()), previous_name: None };
+        (label.name.clone(), label)
     }
 
-    async fn generate_unfiltered_config_from_org(&self) -> AppResult<Config> {
-        // This is essentially the original logic of generate_config_from_org
-         let mut repos = Vec::new();
-        let repo_url = format!("https://api.github.com/orgs/{}/repos?per_page=100", self.org);
-        let repo_response = self.get(&repo_url).await?;
-        let repo_json: Vec<serde_json::Value> = repo_response.json().await.map_err(AppError::Http)?;
+    fn key(&self) -> &str {
+        &self.name
+    }
 
-        for repo in repo_json {
-            let name = repo["name"].as_str().ok_or_else(|| AppError::GitHubApi("Missing repo name".to_string()))?.to_string();
-            // Fetch *limited* settings relevant for generating a *manageable* config
-            let settings = match self.get_repo_settings(&name).await {
-                Ok(full_settings) => {
-                    let mut manageable_settings = RepoSettings::new();
-                    // Only include settings we typically manage
-                    let managed_keys = ["allow_merge_commit", "allow_squash_merge", "allow_rebase_merge"];
-                    for key in managed_keys {
-                         if let Some(value) = full_settings.get(key) {
-                             manageable_settings.insert(key.to_string(), value.clone());
-                         }
-                    }
-                     manageable_settings
-                }
-                Err(e) => {
-                   error!("Failed to fetch settings for repo {}: {}", name, e);
-                    RepoSettings::new() // Return empty settings on error
-                }
-            };
+    fn previous_key(&self) -> Option<&str> {
+        self.previous_name.as_deref()
+    }
 
-            let visibility = if repo["private"].as_bool().unwrap_or(false) { Some("private".to_string()) } else { Some("public".to_string()) };
-            let webhooks = self.get_webhooks(&name).await.unwrap_or_default(); // Handle potential error
-            let webhook = webhooks.first().map(|wh| WebhookConfig {
-                url: wh.config.url.clone(),
-                content_type: wh.config.content_type.clone(),
-                events: wh.events.clone(),
-            });
+    fn normalized(&self, current: Option<&Self>) -> Self {
+        Label {
+            name: self.name.clone(),
+            color: self.color.trim_start_matches('#').to_lowercase(),
+            description: match &self.description {
+                Some(description) => Some(description.clone()).filter(|d| !d.is_empty()),
+                None => current.and_then(|c| c.description.clone()),
+            },
+            previous_name: None,
+        }
+    }
 
-            repos.push(Repo {
-                name,
-                settings,
-                visibility,
-                webhook,
-                branch_protections: vec![], // Add logic to fetch these if needed
-                extra: std::collections::HashMap::new(),
-            });
+    fn create_body(&self) -> serde_json::Value {
+        let mut body = json!({ "name": self.name, "color": self.color.trim_start_matches('#') });
+        if let Some(description) = &self.description {
+            body["description"] = json!(description);
         }
+        body
+    }
 
-        // Fetch teams, users, assignments as before (full state needed for generation)
-        let mut teams = Vec::new();
-        let team_url = format!("https://api.github.com/orgs/{}/teams?per_page=100", self.org);
-        let team_response = self.get(&team_url).await?;
-        let team_json: Vec<serde_json::Value> = team_response.json().await.map_err(AppError::Http)?;
+    fn update_body(&self) -> serde_json::Value {
+        let mut body = self.create_body();
+        if let Some(name) = body.as_object_mut().and_then(|b| b.remove("name")) {
+            body["new_name"] = name;
+        }
+        body
+    }
+}
 
-        for team in team_json {
-            // Fetch full team data including members
-             let name = team["slug"].as_str().ok_or_else(|| AppError::GitHubApi("Missing team slug".to_string()))?.to_string();
-            let members_url = format!("https://api.github.com/orgs/{}/teams/{}/members?per_page=100", self.org, name);
-            let members_response = self.get(&members_url).await?;
-            let members_json: Vec<serde_json::Value> = members_response.json().await.map_err(AppError::Http)?;
-            let mut members = members_json.iter()
-                .filter_map(|m| m["login"].as_str().map(String::from))
-                .collect::<Vec<String>>();
-            members.sort();
+impl RepoResource for Autolink {
+    const KIND: &'static str = "autolink";
+    const PRUNE_SCOPE: PruneScope = PruneScope::Autolinks;
+    const PATH: &'static str = "autolinks";
+    const LIST_QUERY: &'static str = "";
+    const UPDATABLE: bool = false;
+    type Response = AutolinkResponse;
+
+    fn from_response(response: AutolinkResponse) -> (String, Self) {
+        let autolink = Autolink { key_prefix: response.key_prefix, url_template: response.url_template, is_alphanumeric: response.is_alphanumeric };
+        (response.id.to_string(), autolink)
+    }
+
+    fn key(&self) -> &str {
+        &self.key_prefix
+    }
+
+    fn normalized(&self, _current: Option<&Self>) -> Self {
+        self.clone()
+    }
+
+    fn create_body(&self) -> serde_json::Value {
+        json!({ "key_prefix": self.key_prefix, "url_template": self.url_template, "is_alphanumeric": self.is_alphanumeric })
+    }
+}
+
+impl RepoResource for Milestone {
+    const KIND: &'static str = "milestone";
+    const PRUNE_SCOPE: PruneScope = PruneScope::Milestones;
+    const PATH: &'static str = "milestones";
+    const LIST_QUERY: &'static str = "?state=all&per_page=100";
+    type Response = MilestoneResponse;
+
+    fn from_response(response: MilestoneResponse) -> (String, Self) {
+        let milestone = Milestone {
+            title: response.title,
+            description: response.description.filter(|d| !d.is_empty()),
+            due_on: response.due_on.map(|d| milestone_due_date(&d).to_string()),
+            state: Some(response.state),
+            previous_title: None,
+        };
+        (response.number.to_string(), milestone)
+    }
+
+    fn key(&self) -> &str {
+        &self.title
+    }
+
+    fn previous_key(&self) -> Option<&str> {
+        self.previous_title.as_deref()
+    }
+
+    fn normalized(&self, current: Option<&Self>) -> Self {
+        let or_current = |own: Option<String>, current: Option<Option<String>>| match own {
+            Some(value) => Some(value).filter(|v| !v.is_empty()),
+            None => current.flatten(),
+        };
+        Milestone {
+            title: self.title.clone(),
+            description: or_current(self.description.clone(), current.map(|c| c.description.clone())),
+            due_on: or_current(self.due_on.as_deref().map(|d| milestone_due_date(d).to_string()), current.map(|c| c.due_on.clone())),
+            state: self.state.or(current.and_then(|c| c.state)).or(Some(MilestoneState::Open)),
+            previous_title: None,
+        }
+    }
 
-            teams.push(Team { name, members });
+    fn create_body(&self) -> serde_json::Value {
+        let mut body = json!({ "title": self.title });
+        if let Some(description) = &self.description {
+            body["description"] = json!(description);
+        }
+        if let Some(due_on) = &self.due_on {
+            body["due_on"] = json!(format!("{}T00:00:00Z", milestone_due_date(due_on)));
+        }
+        if let Some(state) = self.state {
+            body["state"] = json!(state);
         }
+        body
+    }
+}
+
+/// The date part of a milestone due date. GitHub returns a timestamp, but only the date matters.
+fn milestone_due_date(due_on: &str) -> &str {
+    due_on.split('T').next().unwrap_or(due_on)
+}
 
-        let mut users = Vec::new();
-        let members_url = format!("https://api.github.com/orgs/{}/members?per_page=100", self.org);
-        let members_response = self.get(&members_url).await?;
-        let members_json: Vec<serde_json::Value> = members_response.json().await.map_err(AppError::Http)?;
+/// A single change to one of a repo's resource lists. Existing resources are identified by their
+/// API ID, i.e. the label's current name, the autolink ID or the milestone number.
+#[derive(Debug, PartialEq)]
+enum ResourceChange<'a, T> {
+    Create(&'a T),
+    Update(String, &'a T),
+    Delete(String, String),
+}
 
-        for member in members_json {
-            let login = member["login"].as_str().ok_or_else(|| AppError::GitHubApi("Missing member login".to_string()))?.to_string();
-            let role_response = self.get_user_membership(&login).await?;
-            let role = role_response.unwrap_or("member".to_string()); // Default to member if fetch fails? Or error?
-            users.push(User { login, role });
+///
+/// Plan the changes to one of a repo's resource lists. Resources are matched by key, then by their
+/// previous key, so a renamed label keeps its issues. Resources that aren't listed are only deleted
+/// when `prune` is set. `current` holds each existing resource with its API ID.
+///
+fn plan_resource_changes<'a, T: RepoResource>(desired: &'a [T], current: &[(String, T)], prune: bool) -> Vec<ResourceChange<'a, T>> {
+    let find = |key: &str| current.iter().position(|(_, c)| c.key().eq_ignore_ascii_case(key));
+    let mut targets: Vec<Option<usize>> = desired.iter().map(|d| find(d.key())).collect();
+    let mut matched: HashSet<usize> = targets.iter().flatten().copied().collect();
+    for (target, resource) in targets.iter_mut().zip(desired) {
+        if target.is_none() {
+            *target = resource.previous_key().and_then(find).filter(|i| !matched.contains(i));
+            matched.extend(*target);
         }
+    }
 
-        let mut assignments = Vec::new();
-        for team in &teams {
-            let team_repos = self.get_team_repos(&team.name).await?;
-            for repo in team_repos {
-                 let permission = if repo.permissions.admin {
-                    "admin"
-                } else if repo.permissions.push {
-                    "push" // Use push/pull
-                } else if repo.permissions.pull {
-                    "pull"
-                } else {
-                    "none"
-                };
-                if permission != "none" {
-                    assignments.push(Assignment {
-                        repo: repo.name.clone(),
-                        team: team.name.clone(),
-                        permission: permission.to_string(),
-                    });
-                }
+    let mut changes = Vec::new();
+    for (target, resource) in targets.into_iter().zip(desired) {
+        let Some(index) = target else {
+            changes.push(ResourceChange::Create(resource));
+            continue;
+        };
+        let (id, existing) = &current[index];
+        if resource.normalized(Some(existing)) == *existing {
+            continue;
+        }
+        if T::UPDATABLE {
+            changes.push(ResourceChange::Update(id.clone(), resource));
+        } else {
+            changes.push(ResourceChange::Delete(id.clone(), existing.key().to_string()));
+            changes.push(ResourceChange::Create(resource));
+        }
+    }
+    if prune {
+        for (index, (id, existing)) in current.iter().enumerate() {
+            if !matched.contains(&index) {
+                changes.push(ResourceChange::Delete(id.clone(), existing.key().to_string()));
             }
         }
+    }
+    changes
+}
+
+/// Normalize one of a repo's resource lists for diffing. Without a list of its own the repo gets
+/// `default`. Either way only the listed resources are compared, since sync only deletes the others
+/// with `--prune`, so diff reports missing and changed resources only.
+fn normalize_resource_list_for_diff<T: RepoResource>(local: &mut Option<Vec<T>>, github: &mut Option<Vec<T>>, default: Option<&[T]>) {
+    let Some(desired) = local.take().or_else(|| default.map(<[T]>::to_vec)) else {
+        *github = None;
+        return;
+    };
+    let mut current = github.take().unwrap_or_default();
+    let matches = |resource: &T, existing: &T| {
+        existing.key().eq_ignore_ascii_case(resource.key()) || resource.previous_key().is_some_and(|k| existing.key().eq_ignore_ascii_case(k))
+    };
+    current.retain(|existing| desired.iter().any(|d| matches(d, existing)));
+    let mut desired: Vec<T> = desired.iter().map(|d| d.normalized(current.iter().find(|c| matches(d, c)))).collect();
+    desired.sort_by_key(|r| r.key().to_lowercase());
+    current.sort_by_key(|r| r.key().to_lowercase());
+    *local = Some(desired);
+    *github = Some(current);
+}
+
+/// Normalize repo labels, autolinks and milestones for diffing, applying the org defaults to repos
+/// without their own lists.
+fn normalize_repo_resources_for_diff(local: &mut Config, github: &mut Config) {
+    let default_labels = local.default_labels.take();
+    let default_autolinks = local.default_autolinks.take();
+    let default_milestones = local.default_milestones.take();
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        normalize_resource_list_for_diff(&mut local_repo.labels, &mut github_repo.labels, default_labels.as_deref());
+        normalize_resource_list_for_diff(&mut local_repo.autolinks, &mut github_repo.autolinks, default_autolinks.as_deref());
+        normalize_resource_list_for_diff(&mut local_repo.milestones, &mut github_repo.milestones, default_milestones.as_deref());
+    }
+}
+
+/// Percent-encode a value for use as a single URL path segment, e.g. a label name.
+fn encode_path_segment(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomPropertyResponse {
+    property_name: String,
+    value_type: CustomPropertyType,
+    #[serde(default)]
+    required: bool,
+    default_value: Option<serde_json::Value>,
+    description: Option<String>,
+    allowed_values: Option<Vec<String>>,
+    values_editable_by: Option<PropertyEditors>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoPropertiesResponse {
+    repository_name: String,
+    properties: Vec<PropertyValueResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PropertyValueResponse {
+    property_name: String,
+    value: serde_json::Value,
+}
+
+/// GitHub accepts at most this many repositories per bulk custom property update.
+const PROPERTY_VALUES_BATCH_SIZE: usize = 30;
+
+/// Custom property values are strings, or lists of strings for `multi_select` properties, so
+/// YAML booleans and numbers are sent as strings.
+fn property_value_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Bool(b) => json!(b.to_string()),
+        Value::Number(n) => json!(n.to_string()),
+        Value::Sequence(items) => serde_json::Value::Array(items.iter().map(property_value_json).collect()),
+        other => serde_json::to_value(other).unwrap_or(serde_json::Value::Null),
+    }
+}
+
+/// A property value in the form GitHub returns it, for comparison.
+fn property_value_yaml(value: &Value) -> Value {
+    serde_yaml::to_value(property_value_json(value)).unwrap_or(Value::Null)
+}
+
+fn custom_property_from_response(response: CustomPropertyResponse) -> CustomProperty {
+    CustomProperty {
+        name: response.property_name,
+        value_type: response.value_type,
+        required: response.required,
+        default_value: response.default_value.filter(|v| !v.is_null()).and_then(|v| serde_yaml::to_value(v).ok()),
+        description: response.description.filter(|d| !d.is_empty()),
+        allowed_values: response.allowed_values.unwrap_or_default(),
+        values_editable_by: response.values_editable_by,
+    }
+}
+
+/// Bring a custom property into the form GitHub returns it in, with unset fields at their defaults.
+fn normalize_custom_property(property: &mut CustomProperty) {
+    property.default_value = property.default_value.as_ref().map(property_value_yaml);
+    if property.values_editable_by == Some(PropertyEditors::OrgActors) {
+        property.values_editable_by = None;
+    }
+}
+
+fn custom_property_body(property: &CustomProperty) -> serde_json::Value {
+    json!({
+        "value_type": property.value_type,
+        "required": property.required,
+        "default_value": property.default_value.as_ref().map(property_value_json),
+        "description": property.description,
+        "allowed_values": Some(&property.allowed_values).filter(|v| !v.is_empty()),
+        "values_editable_by": property.values_editable_by.unwrap_or(PropertyEditors::OrgActors),
+    })
+}
+
+/// Check the custom property schema, and when it is managed, that repos only set properties it
+/// defines.
+fn check_custom_properties(config: &Config) -> AppResult<()> {
+    let Some(schema) = &config.custom_properties else {
+        return Ok(());
+    };
+    if let Some(property) = schema.iter().find(|p| p.required && p.default_value.is_none()) {
+        return Err(AppError::Validation(format!("Custom property {} is required but has no default_value", property.name)));
+    }
+    let unknown: Vec<String> = config
+        .repos
+        .iter()
+        .flat_map(|repo| repo.properties.iter().flatten().filter(|(k, _)| !schema.iter().any(|p| &p.name == *k)).map(move |(k, _)| format!("{}: {}", repo.name, k)))
+        .collect();
+    if !unknown.is_empty() {
+        return Err(AppError::Validation(format!("Unknown custom properties: {}", unknown.join(", "))));
+    }
+    Ok(())
+}
+
+///
+/// Plan bulk updates of repository custom property values.
+///
+/// Only the listed properties whose value differs are sent. Repositories with identical changes
+/// share a request, of up to `PROPERTY_VALUES_BATCH_SIZE` repositories each. `current` is keyed by
+/// lower-cased repository name.
+///
+fn plan_property_value_updates(
+    desired: &[(&str, &BTreeMap<String, Value>)],
+    current: &HashMap<String, BTreeMap<String, serde_json::Value>>,
+) -> Vec<serde_json::Value> {
+    let mut groups: BTreeMap<String, (serde_json::Value, Vec<String>)> = BTreeMap::new();
+    for (repo_name, properties) in desired {
+        let current_values = current.get(&repo_name.to_lowercase());
+        let changes: Vec<serde_json::Value> = properties
+            .iter()
+            .filter_map(|(name, value)| {
+                let value = property_value_json(value);
+                let current_value = current_values.and_then(|c| c.get(name)).unwrap_or(&serde_json::Value::Null);
+                (*current_value != value).then(|| json!({ "property_name": name, "value": value }))
+            })
+            .collect();
+        if changes.is_empty() {
+            continue;
+        }
+        let changes = serde_json::Value::Array(changes);
+        groups.entry(changes.to_string()).or_insert_with(|| (changes, Vec::new())).1.push(repo_name.to_string());
+    }
+    let mut requests = Vec::new();
+    for (properties, repo_names) in groups.into_values() {
+        for chunk in repo_names.chunks(PROPERTY_VALUES_BATCH_SIZE) {
+            requests.push(json!({ "repository_names": chunk, "properties": properties }));
+        }
+    }
+    requests
+}
+
+/// Normalize the custom property schema and repo property values for diffing. Properties that the
+/// local config doesn't list aren't managed, so they are dropped from the GitHub side.
+fn normalize_custom_properties_for_diff(local: &mut Config, github: &mut Config) {
+    match &mut local.custom_properties {
+        Some(local_properties) => {
+            let github_properties = github.custom_properties.get_or_insert_with(Vec::new);
+            for property in local_properties.iter_mut().chain(github_properties.iter_mut()) {
+                normalize_custom_property(property);
+            }
+            local_properties.sort_by(|a, b| a.name.cmp(&b.name));
+            github_properties.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        None => github.custom_properties = None,
+    }
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        let Some(local_values) = &mut local_repo.properties else {
+            github_repo.properties = None;
+            continue;
+        };
+        let github_values = github_repo.properties.get_or_insert_with(BTreeMap::new);
+        github_values.retain(|name, _| local_values.contains_key(name));
+        for values in [&mut *local_values, github_values] {
+            values.retain(|_, value| !value.is_null());
+            for value in values.values_mut() {
+                *value = property_value_yaml(value);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeSecurityConfigurationResponse {
+    id: i64,
+    name: String,
+    /// `organization` for the org's own configurations, `global` for GitHub's.
+    target_type: String,
+    description: Option<String>,
+    #[serde(flatten)]
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeSecurityDefaultResponse {
+    default_for_new_repos: DefaultForNewRepos,
+    configuration: CodeSecurityConfigurationIdResponse,
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeSecurityConfigurationIdResponse {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfigurationRepositoryResponse {
+    status: String,
+    repository: RepositoryNameResponse,
+}
+
+/// Whether a repository's attachment status means the configuration applies, or is being applied, to it.
+fn configuration_is_attached(status: &str) -> bool {
+    matches!(status, "attached" | "attaching" | "enforced" | "updating")
+}
+
+/// Settings of code security configurations that aren't in the API mapping, as `config: key`.
+fn invalid_code_security_settings(configurations: &[CodeSecurityConfiguration]) -> Vec<String> {
+    let mapping = get_code_security_configuration_mapping();
+    configurations
+        .iter()
+        .flat_map(|c| c.settings.keys().filter(|k| !mapping.contains_key(k.as_str())).map(move |k| format!("{}: {}", c.name, k)))
+        .collect()
+}
+
+/// Check that the settings of every code security configuration are in the API mapping.
+fn check_code_security_configurations(config: &Config) -> AppResult<()> {
+    let invalid = invalid_code_security_settings(config.code_security_configurations.as_deref().unwrap_or_default());
+    if !invalid.is_empty() {
+        return Err(AppError::Validation(format!("Unknown code security configuration settings: {}", invalid.join(", "))));
+    }
+    Ok(())
+}
+
+/// The fields of a code security configuration that differ from GitHub, as a request body.
+fn code_security_changes(desired: &CodeSecurityConfiguration, current: &CodeSecurityConfigurationResponse) -> serde_json::Map<String, serde_json::Value> {
+    let mut changes = serde_json::Map::new();
+    if let Some(description) = desired.description.as_ref().filter(|d| current.description.as_ref() != Some(*d)) {
+        changes.insert("description".to_string(), json!(description));
+    }
+    for (key, value) in &desired.settings {
+        let value = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+        if current.fields.get(key) != Some(&value) {
+            changes.insert(key.clone(), value);
+        }
+    }
+    changes
+}
+
+fn attach_scope_matches(scope: AttachScope, visibility: Visibility) -> bool {
+    match scope {
+        AttachScope::All => true,
+        AttachScope::Public => visibility == Visibility::Public,
+        AttachScope::PrivateOrInternal => visibility != Visibility::Public,
+    }
+}
+
+/// The repositories a configuration should be attached to: those listed by name plus those
+/// selected by `attach_to`. `None` if attachment isn't managed.
+fn expected_attachments(desired: &CodeSecurityConfiguration, repos: &[(String, Visibility)]) -> Option<Vec<String>> {
+    if desired.repositories.is_none() && desired.attach_to.is_none() {
+        return None;
+    }
+    let mut expected = desired.repositories.clone().unwrap_or_default();
+    if let Some(scope) = desired.attach_to {
+        for (name, visibility) in repos {
+            if attach_scope_matches(scope, *visibility) && !expected.iter().any(|e| e.eq_ignore_ascii_case(name)) {
+                expected.push(name.clone());
+            }
+        }
+    }
+    expected.sort_by_key(|r| r.to_lowercase());
+    Some(expected)
+}
+
+/// Plan attachment changes: the repositories to attach and, with `prune`, those to detach.
+fn plan_attachment_changes(expected: &[String], attached: &[String], prune: bool) -> (Vec<String>, Vec<String>) {
+    let attach = expected.iter().filter(|e| !attached.iter().any(|a| a.eq_ignore_ascii_case(e))).cloned().collect();
+    let detach = if prune {
+        attached.iter().filter(|a| !expected.iter().any(|e| e.eq_ignore_ascii_case(a))).cloned().collect()
+    } else {
+        Vec::new()
+    };
+    (attach, detach)
+}
+
+/// Build the config for a code security configuration from GitHub, keeping only mapped settings.
+fn code_security_configuration_from_response(
+    response: CodeSecurityConfigurationResponse,
+    default_for_new_repos: Option<DefaultForNewRepos>,
+    mut repositories: Vec<String>,
+) -> CodeSecurityConfiguration {
+    let mapping = get_code_security_configuration_mapping();
+    let settings = response
+        .fields
+        .into_iter()
+        .filter(|(key, value)| !value.is_null() && mapping.contains_key(key.as_str()))
+        .filter_map(|(key, value)| Some((key, serde_yaml::to_value(value).ok()?)))
+        .collect();
+    repositories.sort_by_key(|r| r.to_lowercase());
+    CodeSecurityConfiguration {
+        name: response.name,
+        description: response.description.filter(|d| !d.is_empty()),
+        settings,
+        default_for_new_repos,
+        attach_to: None,
+        repositories: Some(repositories),
+    }
+}
+
+/// Normalize code security configurations for diffing. Settings, defaults and attachments the local
+/// config doesn't manage are dropped from the GitHub side, and `attach_to` is expanded to the
+/// repositories it selects. Repos attached beyond those are dropped too, since sync only detaches
+/// them with `--prune code-security`, so diff reports missing attachments only.
+fn normalize_code_security_for_diff(local: &mut Config, github: &mut Config) {
+    let Some(local_configurations) = &mut local.code_security_configurations else {
+        github.code_security_configurations = None;
+        return;
+    };
+    let repos: Vec<(String, Visibility)> = github.repos.iter().map(|r| (r.name.clone(), r.visibility.unwrap_or(Visibility::Public))).collect();
+    for configuration in local_configurations.iter_mut() {
+        configuration.repositories = expected_attachments(configuration, &repos);
+        configuration.attach_to = None;
+    }
+    let github_configurations = github.code_security_configurations.get_or_insert_with(Vec::new);
+    for github_configuration in github_configurations.iter_mut() {
+        let Some(local_configuration) = local_configurations.iter().find(|c| c.name == github_configuration.name) else {
+            continue;
+        };
+        github_configuration.settings.retain(|key, _| local_configuration.settings.contains_key(key));
+        if local_configuration.description.is_none() {
+            github_configuration.description = None;
+        }
+        if local_configuration.default_for_new_repos.is_none() {
+            github_configuration.default_for_new_repos = None;
+        }
+        match (&local_configuration.repositories, &mut github_configuration.repositories) {
+            (None, repositories) => *repositories = None,
+            (Some(expected), Some(attached)) => attached.retain(|a| expected.iter().any(|e| e.eq_ignore_ascii_case(a))),
+            (Some(_), None) => {}
+        }
+    }
+    local_configurations.sort_by(|a, b| a.name.cmp(&b.name));
+    github_configurations.sort_by(|a, b| a.name.cmp(&b.name));
+}
+
+/// A request that changes a repository's security features.
+#[derive(Debug, PartialEq)]
+enum SecurityChange {
+    /// `PATCH` the repo's `security_and_analysis` object.
+    AnalysisFeatures(serde_json::Value),
+    /// Enable (`PUT`) or disable (`DELETE`) a feature with its own endpoint below the repo.
+    Toggle { path: &'static str, enable: bool },
+    /// Configure or unconfigure code scanning default setup.
+    CodeScanningDefaultSetup(bool),
+}
+
+fn desired_change(desired: Option<bool>, current: Option<bool>) -> Option<bool> {
+    desired.filter(|d| current != Some(*d))
+}
+
+///
+/// Plan the requests that bring a repo's security features in line with the config. Unset fields
+/// are left alone. Dependabot alerts are enabled before, and disabled after, security updates,
+/// which depend on them.
+///
+fn plan_security_changes(desired: &RepoSecurity, current: &RepoSecurity) -> Vec<SecurityChange> {
+    let mut changes = Vec::new();
+    let status = |enabled: bool| json!({ "status": if enabled { "enabled" } else { "disabled" } });
+    let mut analysis = serde_json::Map::new();
+    for (key, desired, current) in [
+        ("secret_scanning", desired.secret_scanning, current.secret_scanning),
+        ("secret_scanning_push_protection", desired.secret_scanning_push_protection, current.secret_scanning_push_protection),
+        ("secret_scanning_non_provider_patterns", desired.secret_scanning_non_provider_patterns, current.secret_scanning_non_provider_patterns),
+    ] {
+        if let Some(enabled) = desired_change(desired, current) {
+            analysis.insert(key.to_string(), status(enabled));
+        }
+    }
+    if !analysis.is_empty() {
+        changes.push(SecurityChange::AnalysisFeatures(serde_json::Value::Object(analysis)));
+    }
+    let alerts = desired_change(desired.dependabot_alerts, current.dependabot_alerts);
+    if alerts == Some(true) {
+        changes.push(SecurityChange::Toggle { path: "/vulnerability-alerts", enable: true });
+    }
+    if let Some(enable) = desired_change(desired.dependabot_security_updates, current.dependabot_security_updates) {
+        changes.push(SecurityChange::Toggle { path: "/automated-security-fixes", enable });
+    }
+    if alerts == Some(false) {
+        changes.push(SecurityChange::Toggle { path: "/vulnerability-alerts", enable: false });
+    }
+    if let Some(enable) = desired_change(desired.private_vulnerability_reporting, current.private_vulnerability_reporting) {
+        changes.push(SecurityChange::Toggle { path: "/private-vulnerability-reporting", enable });
+    }
+    if let Some(configured) = desired_change(desired.code_scanning_default_setup, current.code_scanning_default_setup) {
+        changes.push(SecurityChange::CodeScanningDefaultSetup(configured));
+    }
+    changes
+}
+
+/// Read the secret scanning features from a repo's `security_and_analysis` object.
+fn security_from_repo_json(repo: &serde_json::Value) -> RepoSecurity {
+    let status = |key: &str| repo["security_and_analysis"][key]["status"].as_str().map(|s| s == "enabled");
+    RepoSecurity {
+        secret_scanning: status("secret_scanning"),
+        secret_scanning_push_protection: status("secret_scanning_push_protection"),
+        secret_scanning_non_provider_patterns: status("secret_scanning_non_provider_patterns"),
+        ..Default::default()
+    }
+}
+
+/// Normalize repo security features for diffing. `default_security` is applied to the local repos,
+/// and features the local config doesn't manage are dropped from the GitHub side.
+fn normalize_security_for_diff(local: &mut Config, github: &mut Config) {
+    let default = local.default_security.take();
+    for local_repo in &mut local.repos {
+        local_repo.security = local_repo.effective_security(default.as_ref());
+    }
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        let (Some(local_security), Some(github_security)) = (&local_repo.security, &mut github_repo.security) else {
+            if local_repo.security.is_none() {
+                github_repo.security = None;
+            }
+            continue;
+        };
+        let managed = |local: Option<bool>, github: &mut Option<bool>| {
+            if local.is_none() {
+                *github = None;
+            }
+        };
+        managed(local_security.secret_scanning, &mut github_security.secret_scanning);
+        managed(local_security.secret_scanning_push_protection, &mut github_security.secret_scanning_push_protection);
+        managed(local_security.secret_scanning_non_provider_patterns, &mut github_security.secret_scanning_non_provider_patterns);
+        managed(local_security.dependabot_alerts, &mut github_security.dependabot_alerts);
+        managed(local_security.dependabot_security_updates, &mut github_security.dependabot_security_updates);
+        managed(local_security.private_vulnerability_reporting, &mut github_security.private_vulnerability_reporting);
+        managed(local_security.code_scanning_default_setup, &mut github_security.code_scanning_default_setup);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EnabledResponse {
+    enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeScanningDefaultSetupResponse {
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActionsPermissionsResponse {
+    /// Set for repositories.
+    enabled: Option<bool>,
+    /// Set for the organization.
+    enabled_repositories: Option<EnabledRepositories>,
+    allowed_actions: Option<AllowedActions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowPermissionsResponse {
+    default_workflow_permissions: Option<WorkflowPermissions>,
+    can_approve_pull_request_reviews: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForkPrApprovalResponse {
+    approval_policy: ForkPrApproval,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActionsAccessResponse {
+    access_level: ActionsAccessLevel,
+}
+
+/// Reject `actions` blocks that GitHub would refuse or silently ignore.
+fn validate_actions_policy(label: &str, policy: &ActionsPolicy) -> AppResult<()> {
+    if policy.selected_actions.is_some() && policy.allowed_actions.is_some_and(|a| a != AllowedActions::Selected) {
+        return Err(AppError::Validation(format!("Actions of {}: selected_actions requires allowed_actions: selected", label)));
+    }
+    Ok(())
+}
+
+/// Check the org's and every repo's `actions` block.
+fn check_actions(config: &Config) -> AppResult<()> {
+    if let Some(actions) = &config.actions {
+        validate_actions_policy(&config.org, &actions.policy)?;
+        if !actions.selected_repositories.is_empty() && actions.enabled_repositories != Some(EnabledRepositories::Selected) {
+            return Err(AppError::Validation(format!(
+                "Actions of {}: selected_repositories requires enabled_repositories: selected",
+                config.org
+            )));
+        }
+    }
+    for repo in &config.repos {
+        if let Some(actions) = &repo.actions {
+            validate_actions_policy(&format!("{}/{}", config.org, repo.name), &actions.policy)?;
+        }
+    }
+    Ok(())
+}
+
+fn sorted_selected_actions(selected: &SelectedActions) -> SelectedActions {
+    let mut selected = selected.clone();
+    selected.patterns_allowed.sort();
+    selected
+}
+
+///
+/// Plan the requests that bring a repo's or the org's Actions policy in line with the config.
+///
+/// Returns `(path, body)` pairs, where the path is relative to the scope's `actions/permissions`
+/// endpoint. `enabled` is the field that switches Actions on for the scope (`enabled` for repos,
+/// `enabled_repositories` for the org) with its desired and current values; the permissions
+/// request always needs it. Fields left unset in the config keep their current values.
+///
+fn plan_actions_policy_requests(
+    enabled: (&str, Option<serde_json::Value>, serde_json::Value),
+    desired: &ActionsPolicy,
+    current: &ActionsPolicy,
+) -> Vec<(&'static str, serde_json::Value)> {
+    let mut requests = Vec::new();
+    let (enabled_key, desired_enabled, current_enabled) = enabled;
+    let enabled_changed = desired_enabled.as_ref().is_some_and(|e| *e != current_enabled);
+    let allowed_changed = desired.allowed_actions.is_some_and(|a| current.allowed_actions != Some(a));
+    if enabled_changed || allowed_changed {
+        let mut body = json!({ enabled_key: desired_enabled.unwrap_or(current_enabled) });
+        if let Some(allowed) = desired.allowed_actions.or(current.allowed_actions) {
+            body["allowed_actions"] = json!(allowed);
+        }
+        requests.push(("", body));
+    }
+    if let Some(selected) = &desired.selected_actions {
+        let selected = sorted_selected_actions(selected);
+        if current.selected_actions.as_ref().map(sorted_selected_actions) != Some(selected.clone()) {
+            requests.push(("/selected-actions", json!(selected)));
+        }
+    }
+    let permissions_changed = desired.default_workflow_permissions.is_some_and(|p| current.default_workflow_permissions != Some(p));
+    let approval_changed = desired.can_approve_pull_request_reviews.is_some_and(|a| current.can_approve_pull_request_reviews != Some(a));
+    if permissions_changed || approval_changed {
+        let mut body = json!({});
+        if let Some(permissions) = desired.default_workflow_permissions.or(current.default_workflow_permissions) {
+            body["default_workflow_permissions"] = json!(permissions);
+        }
+        if let Some(approve) = desired.can_approve_pull_request_reviews.or(current.can_approve_pull_request_reviews) {
+            body["can_approve_pull_request_reviews"] = json!(approve);
+        }
+        requests.push(("/workflow", body));
+    }
+    if let Some(approval) = desired.fork_pr_approval.filter(|a| current.fork_pr_approval != Some(*a)) {
+        requests.push(("/fork-pr-contributor-approval", json!({ "approval_policy": approval })));
+    }
+    requests
+}
+
+/// Full URL of a mapped repo endpoint, e.g. `/repos/{owner}/{repo}/pages`.
+fn repo_mapping_url(endpoint: &str, org: &str, repo_name: &str) -> String {
+    let relative_path = endpoint.replace("{org}", org).replace("{owner}", org).replace("{repo}", repo_name);
+    format!("{}{}", GITHUB_API_BASE_URL, relative_path)
+}
+
+/// URL to read a mapped repo setting's current value from. `None` if the mapping has no read
+/// endpoint, or its endpoint addresses a sub-resource such as `{branch}` that the repo alone
+/// doesn't resolve.
+fn repo_mapping_read_url(field_map: &ApiFieldMapping, org: &str, repo_name: &str) -> Option<String> {
+    let url = repo_mapping_url(field_map.read_endpoint?, org, repo_name);
+    (!url.contains('{')).then_some(url)
+}
+
+/// Mapped endpoints that repo `settings` can't target, with the config block that manages them.
+const BLOCK_ENDPOINTS: &[(&str, &str)] = &[("/actions/permissions", "actions"), ("/labels/{name}", "labels")];
+
+/// Look up a dot-separated path, e.g. `source.branch`, in a JSON response.
+fn json_path_lookup<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.').try_fold(value, |value, key| value.get(key))
+}
+
+/// Render a value as a nested block under `key`, indented by `indent`.
+fn nested_yaml(key: &str, value: &impl Serialize, indent: &str) -> String {
+    let mut yaml = format!("{}{}:\n", indent, key);
+    for line in serde_yaml::to_string(value).unwrap_or_default().lines() {
+        yaml.push_str(&format!("{}  {}\n", indent, line));
+    }
+    yaml
+}
+
+/// Drop the parts of a GitHub Actions policy that the local config doesn't manage, and sort
+/// allowed patterns on both sides.
+fn normalize_actions_policy_for_diff(local: &mut ActionsPolicy, github: &mut ActionsPolicy) {
+    if local.allowed_actions.is_none() {
+        github.allowed_actions = None;
+    }
+    if local.default_workflow_permissions.is_none() {
+        github.default_workflow_permissions = None;
+    }
+    if local.can_approve_pull_request_reviews.is_none() {
+        github.can_approve_pull_request_reviews = None;
+    }
+    if local.fork_pr_approval.is_none() {
+        github.fork_pr_approval = None;
+    }
+    match &mut local.selected_actions {
+        Some(selected) => selected.patterns_allowed.sort(),
+        None => github.selected_actions = None,
+    }
+    if let Some(selected) = &mut github.selected_actions {
+        selected.patterns_allowed.sort();
+    }
+}
+
+/// Normalize the org and repo `actions` blocks for diffing. Blocks and fields that aren't in the
+/// local config aren't managed, so they are dropped from the GitHub side.
+fn normalize_actions_permissions_for_diff(local: &mut Config, github: &mut Config) {
+    match (&mut local.actions, &mut github.actions) {
+        (Some(local_actions), Some(github_actions)) => {
+            if local_actions.enabled_repositories.is_none() {
+                github_actions.enabled_repositories = None;
+            }
+            if local_actions.selected_repositories.is_empty() {
+                github_actions.selected_repositories.clear();
+            }
+            local_actions.selected_repositories.sort_by_key(|r| r.to_lowercase());
+            github_actions.selected_repositories.sort_by_key(|r| r.to_lowercase());
+            normalize_actions_policy_for_diff(&mut local_actions.policy, &mut github_actions.policy);
+        }
+        (None, github_actions) => *github_actions = None,
+        _ => {}
+    }
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        match (&mut local_repo.actions, &mut github_repo.actions) {
+            (Some(local_actions), Some(github_actions)) => {
+                if local_actions.enabled.is_none() {
+                    github_actions.enabled = None;
+                }
+                if local_actions.access_level.is_none() {
+                    github_actions.access_level = None;
+                }
+                normalize_actions_policy_for_diff(&mut local_actions.policy, &mut github_actions.policy);
+            }
+            (None, github_actions) => *github_actions = None,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EnvironmentResponse {
+    name: String,
+    #[serde(default)]
+    protection_rules: Vec<ProtectionRuleResponse>,
+    #[serde(default)]
+    deployment_branch_policy: Option<DeploymentBranchPolicyResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProtectionRuleResponse {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    wait_timer: Option<u32>,
+    #[serde(default)]
+    prevent_self_review: Option<bool>,
+    #[serde(default)]
+    reviewers: Vec<EnvironmentReviewerResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnvironmentReviewerResponse {
+    #[serde(rename = "type")]
+    kind: String,
+    reviewer: ReviewerResponse,
+}
+
+/// A reviewing user (with a login) or team (with a slug).
+#[derive(Debug, Deserialize)]
+struct ReviewerResponse {
+    #[serde(default)]
+    login: Option<String>,
+    #[serde(default)]
+    slug: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeploymentBranchPolicyResponse {
+    protected_branches: bool,
+    custom_branch_policies: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchPolicyResponse {
+    id: i64,
+    name: String,
+}
+
+/// Convert an environment from the API. `custom_branches` are the names of its deployment branch
+/// policies, which are listed separately.
+fn environment_from_response(response: &EnvironmentResponse, custom_branches: Vec<String>) -> Environment {
+    let mut environment = Environment { name: response.name.clone(), ..Default::default() };
+    for rule in &response.protection_rules {
+        match rule.kind.as_str() {
+            "wait_timer" => environment.wait_timer = rule.wait_timer.unwrap_or_default(),
+            "required_reviewers" => {
+                environment.prevent_self_review = rule.prevent_self_review.unwrap_or_default();
+                for reviewer in &rule.reviewers {
+                    match (reviewer.kind.as_str(), &reviewer.reviewer.login, &reviewer.reviewer.slug) {
+                        ("User", Some(login), _) => environment.reviewers.users.push(login.clone()),
+                        ("Team", _, Some(slug)) => environment.reviewers.teams.push(slug.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    environment.deployment_branch_policy = response.deployment_branch_policy.as_ref().map(|policy| DeploymentBranchPolicy {
+        protected_branches: policy.protected_branches,
+        custom_branches: if policy.custom_branch_policies { custom_branches } else { Vec::new() },
+    });
+    environment
+}
+
+/// The protection rules of an environment in a form that can be compared: reviewers and branch
+/// patterns sorted, logins and team references lower-cased, and secrets and variables dropped.
+fn environment_protection(environment: &Environment) -> Environment {
+    let sorted = |items: &[String], lower: bool| {
+        let mut items: Vec<String> = items.iter().map(|i| if lower { i.to_lowercase() } else { i.clone() }).collect();
+        items.sort();
+        items
+    };
+    Environment {
+        name: environment.name.clone(),
+        wait_timer: environment.wait_timer,
+        reviewers: EnvironmentReviewers {
+            users: sorted(&environment.reviewers.users, true),
+            teams: sorted(&environment.reviewers.teams, true),
+        },
+        prevent_self_review: environment.prevent_self_review,
+        deployment_branch_policy: environment.deployment_branch_policy.as_ref().map(|policy| DeploymentBranchPolicy {
+            protected_branches: policy.protected_branches,
+            custom_branches: sorted(&policy.custom_branches, false),
+        }),
+        secrets: None,
+        variables: None,
+    }
+}
+
+/// Check the environments of every repo in the config.
+fn check_environments(config: &Config) -> AppResult<()> {
+    for repo in &config.repos {
+        for environment in repo.environments.iter().flatten() {
+            validate_environment(&repo.name, environment)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check an environment's protection rules before anything is sent to GitHub.
+fn validate_environment(repo_name: &str, environment: &Environment) -> AppResult<()> {
+    if let Some(policy) = &environment.deployment_branch_policy {
+        if policy.protected_branches != policy.custom_branches.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Environment '{}' of {}: deployment_branch_policy needs exactly one of protected_branches or custom_branches",
+                environment.name, repo_name
+            )));
+        }
+    }
+    if environment.reviewers.users.len() + environment.reviewers.teams.len() > 6 {
+        return Err(AppError::Validation(format!("Environment '{}' of {}: at most 6 reviewers are allowed", environment.name, repo_name)));
+    }
+    Ok(())
+}
+
+/// Plan changes to an environment's custom deployment branch policies: the patterns to create and
+/// the policies (ID and pattern) to delete.
+fn plan_branch_policy_changes<'a>(desired: &'a [String], current: &[BranchPolicyResponse]) -> (Vec<&'a str>, Vec<(i64, String)>) {
+    let create = desired.iter().filter(|p| !current.iter().any(|c| &c.name == *p)).map(String::as_str).collect();
+    let delete = current.iter().filter(|c| !desired.contains(&c.name)).map(|c| (c.id, c.name.clone())).collect();
+    (create, delete)
+}
+
+/// Render a repository's environments as an `environments:` block, with list items at `indent`.
+fn environments_yaml(environments: &[Environment], indent: &str) -> String {
+    let mut yaml = format!("{}environments:\n", indent);
+    let list = |yaml: &mut String, key: &str, items: &[String], indent: &str| {
+        if !items.is_empty() {
+            yaml.push_str(&format!("{}{}:\n", indent, key));
+            for item in items {
+                yaml.push_str(&format!("{}- {}\n", indent, yaml_scalar(item)));
+            }
+        }
+    };
+    for environment in environments {
+        yaml.push_str(&format!("{}- name: {}\n", indent, yaml_scalar(&environment.name)));
+        if environment.wait_timer > 0 {
+            yaml.push_str(&format!("{}  wait_timer: {}\n", indent, environment.wait_timer));
+        }
+        if !environment.reviewers.is_empty() {
+            yaml.push_str(&format!("{}  reviewers:\n", indent));
+            let nested = format!("{}    ", indent);
+            list(&mut yaml, "users", &environment.reviewers.users, &nested);
+            list(&mut yaml, "teams", &environment.reviewers.teams, &nested);
+        }
+        if environment.prevent_self_review {
+            yaml.push_str(&format!("{}  prevent_self_review: true\n", indent));
+        }
+        if let Some(policy) = &environment.deployment_branch_policy {
+            yaml.push_str(&format!("{}  deployment_branch_policy:\n", indent));
+            if policy.protected_branches {
+                yaml.push_str(&format!("{}    protected_branches: true\n", indent));
+            }
+            list(&mut yaml, "custom_branches", &policy.custom_branches, &format!("{}    ", indent));
+        }
+        let nested = format!("{}  ", indent);
+        if let Some(secrets) = &environment.secrets {
+            yaml.push_str(&actions_secrets_yaml(secrets, &nested));
+        }
+        if let Some(variables) = &environment.variables {
+            yaml.push_str(&actions_variables_yaml(variables, &nested));
+        }
+    }
+    yaml
+}
+
+/// Environments GitHub creates and manages itself, which are never deleted.
+const SYSTEM_ENVIRONMENTS: &[&str] = &["github-pages"];
+
+/// Names of the current environments that aren't listed in the config, leaving out the ones
+/// GitHub manages itself.
+fn unlisted_environments<'a>(current: &'a [Environment], desired: &[Environment]) -> Vec<&'a str> {
+    current
+        .iter()
+        .map(|e| e.name.as_str())
+        .filter(|name| !desired.iter().any(|d| d.name == *name) && !SYSTEM_ENVIRONMENTS.iter().any(|s| s.eq_ignore_ascii_case(name)))
+        .collect()
+}
+
+/// Normalize repository environments for diffing, the way sync compares them. Repos whose
+/// environments aren't in the local config aren't managed, so they are dropped from the GitHub
+/// side, as are unlisted environments sync would never delete. Team reviewers on GitHub take the
+/// local spelling (display name or slug) when they refer to the same team. `prune_environments` is
+/// an instruction rather than state, so it is cleared.
+fn normalize_environments_for_diff(local: &mut Config, github: &mut Config) {
+    let team_names: HashMap<String, String> = github.teams.iter().filter_map(|t| Some((t.slug.clone()?, t.name.clone()))).collect();
+    for github_repo in &mut github.repos {
+        let Some(local_repo) = local.repos.iter_mut().find(|r| r.name == github_repo.name) else {
+            continue;
+        };
+        let Some(local_environments) = &mut local_repo.environments else {
+            github_repo.environments = None;
+            continue;
+        };
+        let github_environments = github_repo.environments.get_or_insert_with(Vec::new);
+        let prunable: Vec<String> = if local_repo.prune_environments {
+            unlisted_environments(github_environments, local_environments).into_iter().map(str::to_string).collect()
+        } else {
+            Vec::new()
+        };
+        github_environments.retain(|e| local_environments.iter().any(|l| l.name == e.name) || prunable.contains(&e.name));
+        for github_environment in github_environments.iter_mut() {
+            let Some(local_environment) = local_environments.iter_mut().find(|e| e.name == github_environment.name) else {
+                continue;
+            };
+            for slug in &mut github_environment.reviewers.teams {
+                let name = team_names.get(slug.as_str()).cloned().unwrap_or_else(|| slug.clone());
+                if let Some(reference) = local_environment.reviewers.teams.iter().find(|r| team_ref_matches(r, &name, slug)) {
+                    *slug = reference.clone();
+                }
+            }
+            normalize_secrets_for_diff(&mut local_environment.secrets, &mut github_environment.secrets, false);
+            normalize_variables_for_diff(&mut local_environment.variables, &mut github_environment.variables, false);
+        }
+        for environment in local_environments.iter_mut().chain(github_environments.iter_mut()) {
+            let protection = environment_protection(environment);
+            environment.reviewers.users.sort_by_key(|u| u.to_lowercase());
+            environment.reviewers.teams.sort_by_key(|t| t.to_lowercase());
+            environment.deployment_branch_policy = protection.deployment_branch_policy;
+        }
+        local_environments.sort_by(|a, b| a.name.cmp(&b.name));
+        github_environments.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    for repo in &mut local.repos {
+        repo.prune_environments = false;
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WebhookResponse {
+    id: Option<i64>,
+    url: String,
+    config: WebhookConfigResponse,
+    events: Vec<String>,
+    #[serde(default)]
+    active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WebhookConfigResponse {
+    url: String,
+    #[serde(default)]
+    content_type: String,
+    /// `"0"` or `"1"`; GitHub has returned it both as a string and as a number.
+    #[serde(default)]
+    insecure_ssl: Option<serde_json::Value>,
+}
+
+///
+/// Client for interacting with the GitHub API for organization management.
+///
+/// The `GitHubClient` provides methods to manage repositories, teams, users, webhooks,
+/// and configuration synchronization for a GitHub organization. It handles authentication,
+/// API requests, and high-level orchestration of organization state.
+///
+pub struct GitHubClient {
+    /// Reqwest HTTP client for making API requests.
+    client: Client,
+    /// GitHub personal access token for authentication.
+    token: String,
+    /// Name of the GitHub organization to operate on.
+    pub org: String,
+    /// Hashes of write-only values last applied, if a state file is in use.
+    state: Option<Mutex<StateFile>>,
+    /// Org repository IDs keyed by lowercase name, listed once and reused for repository selections.
+    repo_ids: Mutex<Option<HashMap<String, i64>>>,
+    /// The org's teams, listed once and reused to resolve team references.
+    teams: Mutex<Option<Vec<TeamResponse>>>,
+}
+
+impl GitHubClient {
+    /// Create a new GitHubClient for the given organization and token.
+    ///
+    /// # Arguments
+    /// * `token` - GitHub personal access token.
+    /// * `org` - Name of the GitHub organization.
+    pub fn new(token: &str, org: &str) -> Self {
+        GitHubClient {
+            client: Client::new(),
+            token: token.to_string(),
+            org: org.to_string(),
+            state: None,
+            repo_ids: Mutex::new(None),
+            teams: Mutex::new(None),
+        }
+    }
+
+    /// Track write-only values such as webhook secrets in `state`, so diff and sync can tell
+    /// when they need to be sent again.
+    pub fn with_state(mut self, state: StateFile) -> Self {
+        self.state = Some(Mutex::new(state));
+        self
+    }
+
+    async fn send_patch(&self, url: &str, body: serde_json::Value) -> AppResult<()> {
+        // --- Add Enhanced Logging ---
+        debug!("Attempting to build PATCH request for URL: '{}'", url);
+        if url.trim().is_empty() {
+            error!("URL passed to send_patch() is empty!");
+            return Err(AppError::GitHubApi("Internal error: Attempted PATCH with empty URL".to_string()));
+        }
+        if self.token.trim().is_empty() {
+            error!("GitHub token is empty!");
+            return Err(AppError::GitHubApi("GitHub token is empty".to_string()));
+        } else {
+            debug!("Token length: {}", self.token.len());
+        }
+        debug!("PATCH body: {:?}", body); // Log the body being sent
+        // --- End Enhanced Logging ---
+
+        // Try building step-by-step again
+        let client_ref = &self.client;
+        let builder_result = client_ref.patch(url); // Initial builder
+
+        // --- Log after initial build ---
+        debug!("reqwest::Client::patch succeeded for URL: '{}'", url); // Check if this logs
+        // ---
+
+        let auth_header_value = format!("Bearer {}", self.token);
+        if auth_header_value.contains('\n') || auth_header_value.contains('\r') {
+             error!("Authorization header value contains invalid characters (newline/CR)");
+             return Err(AppError::GitHubApi("Invalid characters in token for Authorization header".to_string()));
+        }
+
+        // Chain headers
+        let request_builder_headers = builder_result
+            .header(reqwest::header::AUTHORIZATION, auth_header_value)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .header(reqwest::header::USER_AGENT, "gh-config");
+
+        // --- Log after headers added ---
+        debug!("Request builder headers configured."); // Check if this logs
+        // ---
+
+        // Add JSON body
+        let request_builder_final = request_builder_headers.json(&body);
+
+        // --- Log after body added ---
+        debug!("Request builder body configured (using .json())."); // Check if this logs
+        // ----
+
+        // Send the request
+        debug!("Attempting final send..."); // Check if this logs
+        let response_result = request_builder_final.send().await; // Store result before unwrapping
+
+        match response_result {
+             Ok(response) => {
+                 // --- Original status check logic ---
+                 debug!("Request send successful."); // Log success before status check
+                 let status = response.status();
+                 debug!("PATCH {} returned status: {}", url, status);
+                 if status.is_success() {
+                     Ok(())
+                 } else {
+                     let text = response.text().await?;
+                     error!("PATCH {} failed with status {}: {}", url, status, text);
+                     Err(AppError::GitHubApi(text))
+                 }
+             }
+             Err(e) => {
+                 error!("Request builder send() failed: {}", e);
+                 // Check if the error source is the builder error we saw
+                 if e.is_builder() {
+                     error!("Confirmed: Failure is a builder error during send().");
+                 } else if e.is_connect() {
+                     error!("Confirmed: Failure is a connection error during send().");
+                 } else if e.is_timeout() {
+                     error!("Confirmed: Failure is a timeout error during send().");
+                 } // Add other checks from reqwest::Error if needed
+                 Err(AppError::from(e)) // Propagate the reqwest error
+             }
+         }
+
+    }
+
+    async fn send_post(&self, url: &str, body: serde_json::Value) -> AppResult<()> {
+        // --- Add Similar Enhanced Logging ---
+        debug!("Attempting to build POST request for URL: '{}'", url);
+        if url.trim().is_empty() { /* ... */ }
+        if self.token.trim().is_empty() { /* ... */ } else { debug!("Token length: {}", self.token.len()); }
+        debug!("POST body: {:?}", body);
+
+        let client_ref = &self.client;
+        let builder_result = client_ref.post(url); // Initial builder
+        debug!("reqwest::Client::post succeeded for URL: '{}'", url);
+
+        let auth_header_value = format!("Bearer {}", self.token);
+        if auth_header_value.contains('\n') || auth_header_value.contains('\r') { /* ... */ }
+
+        let request_builder_headers = builder_result
+            .header(reqwest::header::AUTHORIZATION, auth_header_value)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .header(reqwest::header::USER_AGENT, "gh-config");
+        debug!("Request builder headers configured.");
+
+        let request_builder_final = request_builder_headers.json(&body);
+        debug!("Request builder body configured (using .json()).");
+
+        debug!("Attempting final send...");
+        let response_result = request_builder_final.send().await; // Store result
+
+        match response_result {
+            Ok(response) => {
+                debug!("Request send successful.");
+                let status = response.status();
+                debug!("POST {} returned status: {}", url, status);
+                if status.is_success() {
+                    Ok(())
+                } else {
+                    let text = response.text().await?;
+                    error!("POST {} failed with status {}: {}", url, status, text);
+                    Err(AppError::GitHubApi(text))
+                }
+            }
+            Err(e) => {
+                error!("Request builder send() failed: {}", e);
+                if e.is_builder() { error!("Confirmed: Failure is a builder error during send()."); }
+                // ... other error checks ...
+                Err(AppError::from(e))
+            }
+        }
+    }
+
+    async fn send_put(&self, url: &str, body: Option<serde_json::Value>) -> AppResult<()> {
+        let mut request = self
+            .client
+            .put(url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "gh-config");
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request.send().await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let text = response.text().await?;
+            error!("PUT {} failed with status {}: {}", url, status, text);
+            Err(AppError::GitHubApi(text))
+        }
+    }
+
+    async fn get(&self, url: &str) -> AppResult<reqwest::Response> {
+        self.get_with_accept(url, "application/vnd.github+json").await
+    }
+
+    async fn get_with_accept(&self, url: &str, accept: &str) -> AppResult<reqwest::Response> {
+        let response = self.send_get(url, accept).await?;
+
+        // --- Original status check logic ---
+        let status = response.status();
+        debug!("GET {} returned status: {}", url, status);
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let text = response.text().await?;
+            error!("GET {} failed with status {}: {}", url, status, text);
+            Err(AppError::GitHubApi(text))
+        }
+    }
+
+    /// GET a resource that may not exist. A 404 is `Ok(None)` and isn't logged as an error.
+    async fn get_optional(&self, url: &str) -> AppResult<Option<reqwest::Response>> {
+        let response = self.send_get(url, "application/vnd.github+json").await?;
+        let status = response.status();
+        debug!("GET {} returned status: {}", url, status);
+        if status == reqwest::StatusCode::NOT_FOUND {
+            Ok(None)
+        } else if status.is_success() {
+            Ok(Some(response))
+        } else {
+            let text = response.text().await?;
+            error!("GET {} failed with status {}: {}", url, status, text);
+            Err(AppError::GitHubApi(text))
+        }
+    }
+
+    /// Build and send an authenticated GET request, without checking the response status.
+    async fn send_get(&self, url: &str, accept: &str) -> AppResult<reqwest::Response> {
+        // --- Add Enhanced Logging ---
+        debug!("Attempting to build GET request for URL: '{}'", url);
+        if url.trim().is_empty() {
+             error!("URL passed to get() is empty!");
+             return Err(AppError::GitHubApi("Internal error: Attempted GET with empty URL".to_string()));
+        }
+        // Check token validity superficially (e.g., not empty)
+        if self.token.trim().is_empty() {
+            error!("GitHub token is empty!");
+            // Although this usually causes 401 later, an empty Bearer token might fail earlier.
+            return Err(AppError::GitHubApi("GitHub token is empty".to_string()));
+        } else {
+             // Log length as a non-sensitive indicator
+             debug!("Token length: {}", self.token.len());
+        }
+        // --- End Enhanced Logging ---
+
+        // Try building the request step-by-step to isolate the failure
+        let client_ref = &self.client; // Borrow client
+        let builder_result = client_ref.get(url); // Create builder
+
+        // --- Log after potential URL parsing failure ---
+        debug!("reqwest::Client::get succeeded for URL: '{}'", url);
+        // ---
+
+        let auth_header_value = format!("Bearer {}", self.token);
+        // Validate auth header value doesn't contain obviously invalid chars like newline
+        if auth_header_value.contains('\n') || auth_header_value.contains('\r') {
+             error!("Authorization header value contains invalid characters (newline/CR)");
+             return Err(AppError::GitHubApi("Invalid characters in token for Authorization header".to_string()));
+        }
+
+        // Chain the rest - if it fails here, it's likely header related
+        let request_builder = builder_result
+            .header(reqwest::header::AUTHORIZATION, auth_header_value) // Use constant for clarity
+            .header(reqwest::header::ACCEPT, accept)
+            .header(reqwest::header::USER_AGENT, "gh-config");
+
+        // --- Log before send ---
+        debug!("Request builder fully configured, attempting send...");
+        // ---
+
+        Ok(request_builder.send().await?)
+    }
+
+    /// GET a list endpoint and follow its `Link` headers, collecting the items of every page.
+    async fn get_all<T: serde::de::DeserializeOwned>(&self, url: &str) -> AppResult<Vec<T>> {
+        let mut items = Vec::new();
+        let mut next = Some(url.to_string());
+        while let Some(page_url) = next {
+            let response = self.get(&page_url).await?;
+            next = response_next_page_url(&response);
+            let page: Vec<T> = response.json().await.map_err(AppError::Http)?;
+            items.extend(page);
+        }
+        Ok(items)
+    }
+
+    /// Like `get_all`, for list endpoints that wrap each page's items in an object such as
+    /// `{"total_count": 2, "secrets": [...]}`: collects the `field` list of every page.
+    async fn get_all_in<T: serde::de::DeserializeOwned>(&self, url: &str, field: &str) -> AppResult<Vec<T>> {
+        let first = self.get(url).await?;
+        self.collect_pages_in(first, field).await
+    }
+
+    /// Collect the `field` list of a wrapped list response and of the pages after it.
+    async fn collect_pages_in<T: serde::de::DeserializeOwned>(&self, first: reqwest::Response, field: &str) -> AppResult<Vec<T>> {
+        let mut items = Vec::new();
+        let mut response = first;
+        loop {
+            let page_url = response.url().to_string();
+            let next = response_next_page_url(&response);
+            let mut page: serde_json::Value = response.json().await.map_err(AppError::Http)?;
+            let list = page
+                .get_mut(field)
+                .map(serde_json::Value::take)
+                .ok_or_else(|| AppError::GitHubApi(format!("Response from {} has no '{}' list", page_url, field)))?;
+            items.extend(serde_json::from_value::<Vec<T>>(list)?);
+            match next {
+                Some(next_url) => response = self.get(&next_url).await?,
+                None => return Ok(items),
+            }
+        }
+    }
+
+    /// Fetch all settings for a repo as a HashMap<String, serde_yaml::Value>
+    async fn get_repo_settings(&self, repo_name: &str) -> AppResult<RepoSettings> {
+        let full_url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name);
+        let response = self.get(&full_url).await?;
+        let text = response.text().await?;
+        if text.is_empty() {
+            error!("Empty response body from GET {}", full_url);
+            return Err(AppError::GitHubApi("Empty response body".to_string()));
+        }
+        debug!("Raw response for {}: {}", full_url, text);
+        let repo_json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
+        // Convert to serde_yaml::Value for consistency with config
+        let repo_yaml: serde_yaml::Value = serde_yaml::to_value(repo_json)
+            .map_err(|e| AppError::GitHubApi(format!("Failed to convert repo JSON to YAML: {}", e)))?;
+        // Flatten to a HashMap<String, Value>
+        let mut settings = RepoSettings::new();
+        if let serde_yaml::Value::Mapping(map) = repo_yaml {
+            for (k, v) in map {
+                if let serde_yaml::Value::String(key) = k {
+                    settings.insert(key, v);
+                }
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Get the organization's settings as returned by `GET /orgs/{org}`.
+    async fn get_org_settings(&self) -> AppResult<OrgSettings> {
+        let full_url = format!("{}/orgs/{}", GITHUB_API_BASE_URL, self.org);
+        let response = self.get(&full_url).await?;
+        let org_json: serde_json::Value = response.json().await.map_err(AppError::Http)?;
+        let org_yaml: serde_yaml::Value = serde_yaml::to_value(org_json)
+            .map_err(|e| AppError::GitHubApi(format!("Failed to convert org JSON to YAML: {}", e)))?;
+        let mut settings = OrgSettings::new();
+        if let serde_yaml::Value::Mapping(map) = org_yaml {
+            for (k, v) in map {
+                if let serde_yaml::Value::String(key) = k {
+                    settings.insert(key, v);
+                }
+            }
+        }
+        Ok(settings)
+    }
+
+    ///
+    /// Update organization settings to match the config.
+    ///
+    /// Settings are applied through the org settings mapping, one request per endpoint. Read-only
+    /// settings such as `two_factor_requirement_enabled` are only reported when they drift.
+    ///
+    /// # Arguments
+    /// * `desired` - The org settings from the config.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all settings are updated or in dry-run mode.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    pub async fn update_org_settings(&self, desired: &OrgSettings, dry_run: bool) -> AppResult<()> {
+        if desired.is_empty() {
+            return Ok(());
+        }
+        let current = self.get_org_settings().await?;
+        let (updates, read_only_drift) = org_settings_updates(desired, &current);
+
+        for key in read_only_drift {
+            warn!(
+                "Org setting '{}' is {:?} on GitHub but {:?} in config; it can't be changed through the API.",
+                key,
+                current.get(key),
+                desired.get(key)
+            );
+        }
+
+        for ((endpoint, method), body_map) in updates {
+            let full_url = format!("{}{}", GITHUB_API_BASE_URL, endpoint.replace("{org}", &self.org));
+            let body = serde_json::Value::Object(body_map);
+            if dry_run {
+                info!("[Dry Run] Would {} {} with body: {:?}", method, full_url, body);
+                continue;
+            }
+            debug!("{} {} with body: {:?}", method, full_url, body);
+            match method {
+                "PATCH" => self.send_patch(&full_url, body).await?,
+                "PUT" => self.send_put(&full_url, Some(body)).await?,
+                "POST" => self.send_post(&full_url, body).await?,
+                _ => error!("Unsupported HTTP method: {}", method),
+            }
+            info!("Applied org settings changes for {} via {}", self.org, full_url);
+        }
+        Ok(())
+    }
+
+    async fn get_repo_visibility(&self, repo_name: &str) -> AppResult<Visibility> {
+        let full_url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name);
+        let response = self.get(&full_url).await?;
+        let text = response.text().await?;
+        if text.is_empty() {
+            error!("Empty response body from GET {}", full_url);
+            return Err(AppError::GitHubApi("Empty response body".to_string()));
+        }
+        let repo: RepoResponse = serde_json::from_str(&text)
+            .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
+        Ok(visibility_from_api(repo.visibility.as_deref(), repo.private))
+    }
+
+    /// List the organization's teams. The listing is fetched once and reused until a team is
+    /// created or renamed.
+    async fn list_org_teams(&self) -> AppResult<Vec<TeamResponse>> {
+        if let Some(teams) = self.teams.lock().unwrap().as_ref() {
+            return Ok(teams.clone());
+        }
+        let team_url = format!("{}/orgs/{}/teams?per_page=100", GITHUB_API_BASE_URL, self.org);
+        let teams: Vec<TeamResponse> = self.get_all(&team_url).await?;
+        *self.teams.lock().unwrap() = Some(teams.clone());
+        Ok(teams)
+    }
+
+    /// Drop the cached team listing after a team was created or renamed.
+    fn forget_teams(&self) {
+        *self.teams.lock().unwrap() = None;
+    }
+
+    /// Look up a team by a config reference, which may be a slug or a display name, in the org's
+    /// team listing.
+    async fn find_team(&self, reference: &str) -> AppResult<Option<TeamResponse>> {
+        let teams = self.list_org_teams().await?;
+        Ok(teams.into_iter().find(|t| team_ref_matches(reference, &t.name, &t.slug)))
+    }
+
+    /// Find the GitHub team a configured team refers to: by its slug if it has one, falling back to
+    /// its display name (e.g. when the team was already renamed on GitHub). Teams without a slug are
+    /// resolved by name alone.
+    async fn find_configured_team(&self, team: &Team) -> AppResult<Option<TeamResponse>> {
+        let teams = self.list_org_teams().await?;
+        let by_slug = team.slug.as_ref().and_then(|slug| teams.iter().find(|t| t.slug.eq_ignore_ascii_case(slug)));
+        Ok(by_slug.or_else(|| teams.iter().find(|t| team_ref_matches(&team.name, &t.name, &t.slug))).cloned())
+    }
+
+    /// Resolve a team reference to the slug used in API URLs. Falls back to the derived slug for
+    /// teams that don't exist yet.
+    async fn resolve_team_slug(&self, reference: &str) -> AppResult<String> {
+        Ok(match self.find_team(reference).await? {
+            Some(team) => team.slug,
+            None => slugify_team_name(reference),
+        })
+    }
+
+    async fn get_user_membership(&self, login: &str) -> AppResult<Option<String>> {
+        let full_url = format!("{}/orgs/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, login);
+        match self.get(&full_url).await {
+            Ok(response) => {
+                let text = response.text().await?;
+                if text.is_empty() {
+                    error!("Empty response body from GET {}", full_url);
+                    return Err(AppError::GitHubApi("Empty response body".to_string()));
+                }
+                let membership: MembershipResponse = serde_json::from_str(&text)
+                    .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
+                Ok(Some(membership.role))
+            }
+            Err(AppError::GitHubApi(e)) if e.contains("404") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_team_repos(&self, team_name: &str) -> AppResult<Vec<TeamRepoResponse>> {
+        let full_url = format!("{}/orgs/{}/teams/{}/repos?per_page=100", GITHUB_API_BASE_URL, self.org, team_name);
+        self.get_all(&full_url).await
+    }
+
+    /// The repository permission of every team, as assignments referencing the team by slug.
+    async fn get_team_assignments(&self, teams: &[Team]) -> AppResult<Vec<Assignment>> {
+        let mut assignments = Vec::new();
+        for team in teams {
+            let team_slug = team.slug.as_deref().unwrap_or(&team.name);
+            for repo in self.get_team_repos(team_slug).await? {
+                if let Some(permission) = permission_from_response(&repo) {
+                    assignments.push(Assignment {
+                        repo: repo.name.clone(),
+                        team: team_slug.to_string(),
+                        permission,
+                    });
+                }
+            }
+        }
+        Ok(assignments)
+    }
+
+    ///
+    /// Retrieve all webhooks configured for a given repository.
+    ///
+    /// # Arguments
+    /// * `repo_name` - The name of the repository.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<WebhookResponse>)` containing the list of webhooks if successful.
+    /// * `Err(AppError)` if the API call or parsing fails.
+    ///
+    pub async fn get_webhooks(&self, repo_name: &str) -> AppResult<Vec<WebhookResponse>> {
+        self.get_hooks(&self.repo_hooks_url(repo_name)).await
+    }
+
+    fn repo_hooks_url(&self, repo_name: &str) -> String {
+        format!("{}/repos/{}/{}/hooks", GITHUB_API_BASE_URL, self.org, repo_name)
+    }
+
+    fn org_hooks_url(&self) -> String {
+        format!("{}/orgs/{}/hooks", GITHUB_API_BASE_URL, self.org)
+    }
+
+    /// List the webhooks of a hooks collection (a repo's or the organization's).
+    async fn get_hooks(&self, hooks_url: &str) -> AppResult<Vec<WebhookResponse>> {
+        self.get_all(&format!("{}?per_page=100", hooks_url)).await
+    }
+
+    async fn create_webhook(&self, hooks_url: &str, target: &str, webhook: &WebhookConfig) -> AppResult<()> {
+        let mut body = webhook_body(webhook)?;
+        body["name"] = json!("web"); // Standard name for webhooks
+        info!("Creating webhook {} for {}", webhook.url, target);
+        self.send_post(hooks_url, body).await?;
+        Ok(())
+    }
+
+    async fn update_webhook(&self, hooks_url: &str, target: &str, hook_id: i64, webhook: &WebhookConfig) -> AppResult<()> {
+        let url = format!("{}/{}", hooks_url, hook_id);
+        let body = webhook_body(webhook)?;
+        info!("Updating webhook {} ({}) for {}", hook_id, webhook.url, target); // Log hook_id
+        self.send_patch(&url, body).await?;
+        Ok(())
+    }
+
+    /// IDs of the write-only values that may differ from what GitHub has, according to the state
+    /// file. Each value is given as `(id, state key, value)`. Without a state file, nothing is stale,
+    /// so values are only sent when something GitHub returns differs.
+    fn stale_write_only<'a>(&self, values: impl IntoIterator<Item = (&'a str, String, Option<&'a SecretRef>)>) -> AppResult<HashSet<&'a str>> {
+        let Some(state) = &self.state else {
+            return Ok(HashSet::new());
+        };
+        let state = state.lock().unwrap();
+        let mut stale = HashSet::new();
+        for (id, key, value) in values {
+            if write_only_status(&state, &key, value)? != WriteOnlyStatus::Unchanged {
+                stale.insert(id);
+            }
+        }
+        Ok(stale)
+    }
+
+    /// Record a write-only value just applied in the state file, or forget it if there is none.
+    fn record_write_only(&self, key: &str, value: Option<&SecretRef>) -> AppResult<()> {
+        let Some(state) = &self.state else {
+            return Ok(());
+        };
+        let mut state = state.lock().unwrap();
+        match value {
+            Some(secret) => state.record(key, &secret.resolve()?)?,
+            None => {
+                state.remove(key);
+            }
+        }
+        state.save()
+    }
+
+    /// Record or forget the secret of a hook just created, updated or deleted.
+    fn record_webhook_secret(&self, target: &str, url: &str, secret: Option<&SecretRef>) -> AppResult<()> {
+        self.record_write_only(&webhook_secret_key(target, url), secret)
+    }
+    /// Describe write-only values in `config` that sync would send again, according to the state
+    /// file. Returns nothing when no state file is in use.
+    fn write_only_notes(&self, config: &Config) -> AppResult<Vec<String>> {
+        let Some(state) = &self.state else {
+            return Ok(Vec::new());
+        };
+        let state = state.lock().unwrap();
+        // (description, state key, value) of every write-only value sync manages
+        let mut values: Vec<(String, String, Option<&SecretRef>)> = Vec::new();
+        let hook_value = |target: &str, webhook: &WebhookConfig| {
+            (format!("Webhook secret for {} on {}", webhook.url, target), webhook_secret_key(target, &webhook.url))
+        };
+        let secret_value = |scope: &str, secret: &ActionsSecret| {
+            (format!("Actions secret {} on {}", secret.name, scope), actions_secret_key(scope, &secret.name))
+        };
+        for repo in &config.repos {
+            let target = format!("{}/{}", self.org, repo.name);
+            for webhook in repo_webhook_plan(repo, config.default_webhook.as_ref(), false).map(|(w, _)| w).unwrap_or_default() {
+                let (description, key) = hook_value(&target, webhook);
+                values.push((description, key, webhook.secret.as_ref()));
+            }
+            for secret in repo.secrets.iter().flatten() {
+                let (description, key) = secret_value(&target, secret);
+                values.push((description, key, Some(&secret.value)));
+            }
+            for environment in repo.environments.iter().flatten() {
+                let scope = format!("{}/{}", target, environment.name);
+                for secret in environment.secrets.iter().flatten() {
+                    let (description, key) = secret_value(&scope, secret);
+                    values.push((description, key, Some(&secret.value)));
+                }
+            }
+        }
+        for webhook in &config.org_webhooks {
+            let (description, key) = hook_value(&self.org, webhook);
+            values.push((description, key, webhook.secret.as_ref()));
+        }
+        for secret in config.secrets.iter().flatten() {
+            let (description, key) = secret_value(&self.org, secret);
+            values.push((description, key, Some(&secret.value)));
+        }
+
+        let mut notes = Vec::new();
+        for (description, key, value) in values {
+            match write_only_status(&state, &key, value)? {
+                WriteOnlyStatus::Unchanged => {}
+                WriteOnlyStatus::Changed => notes.push(format!("{} changed since the last sync", description)),
+                WriteOnlyStatus::Untracked => notes.push(format!("{} isn't tracked in the state file", description)),
+            }
+        }
+        Ok(notes)
+    }
+
+    /// Create, update and (if `delete_unlisted`) delete webhooks in a hooks collection so it
+    /// matches `desired`. `target` names the owner of the collection in log messages (e.g. `org/repo`).
+    async fn sync_webhooks(&self, hooks_url: &str, target: &str, desired: &[WebhookConfig], delete_unlisted: bool, dry_run: bool) -> AppResult<()> {
+        let current_hooks = self.get_hooks(hooks_url).await?;
+        let stale_secrets = self.stale_write_only(desired.iter().map(|w| (w.url.as_str(), webhook_secret_key(target, &w.url), w.secret.as_ref())))?;
+        let changes = plan_webhook_changes(desired, &current_hooks, delete_unlisted, &stale_secrets);
+        if changes.is_empty() {
+            debug!("Webhooks for {} already match desired config", target);
+        }
+
+        for change in changes {
+            match change {
+                WebhookChange::Create(webhook) if dry_run => {
+                    info!("[Dry Run] Would create webhook for {} with config: {:?}", target, webhook);
+                }
+                WebhookChange::Create(webhook) => {
+                    self.create_webhook(hooks_url, target, webhook).await?;
+                    self.record_webhook_secret(target, &webhook.url, webhook.secret.as_ref())?;
+                }
+                WebhookChange::Update(hook_id, webhook) if dry_run => {
+                    let current = current_hooks.iter().find(|h| h.id == Some(hook_id)).map(webhook_from_response);
+                    info!("[Dry Run] Would update webhook for {}: {:?} -> {:?}", target, current, webhook);
+                }
+                WebhookChange::Update(hook_id, webhook) => {
+                    self.update_webhook(hooks_url, target, hook_id, webhook).await?;
+                    self.record_webhook_secret(target, &webhook.url, webhook.secret.as_ref())?;
+                }
+                WebhookChange::Delete(hook_id, url) if dry_run => {
+                    info!("[Dry Run] Would delete webhook {} ({}) from {}", hook_id, url, target);
+                }
+                WebhookChange::Delete(hook_id, url) => {
+                    info!("Deleting webhook {} ({}) from {}", hook_id, url, target);
+                    self.send_delete(&format!("{}/{}", hooks_url, hook_id)).await?;
+                    self.record_webhook_secret(target, &url, None)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn org_actions_scope(&self) -> ActionsScope {
+        ActionsScope { url: format!("{}/orgs/{}/actions", GITHUB_API_BASE_URL, self.org), label: self.org.clone(), org_level: true }
+    }
+
+    fn repo_actions_scope(&self, repo_name: &str) -> ActionsScope {
+        ActionsScope {
+            url: format!("{}/repos/{}/{}/actions", GITHUB_API_BASE_URL, self.org, repo_name),
+            label: format!("{}/{}", self.org, repo_name),
+            org_level: false,
+        }
+    }
+
+    /// List the secrets (`kind` = "secrets") or variables (`kind` = "variables") of a scope,
+    /// including the selected repositories of org-level items.
+    async fn get_actions_items(&self, scope: &ActionsScope, kind: &str) -> AppResult<Vec<ActionsItem>> {
+        let listed: Vec<ActionsItemResponse> = self.get_all_in(&format!("{}/{}?per_page=100", scope.url, kind), kind).await?;
+        let mut items = Vec::new();
+        for item in listed {
+            let mut selected_repositories = Vec::new();
+            if scope.org_level && item.visibility == Some(ActionsVisibility::Selected) {
+                let url = format!("{}/{}/{}/repositories?per_page=100", scope.url, kind, item.name);
+                let selected: Vec<RepositoryNameResponse> = self.get_all_in(&url, "repositories").await?;
+                selected_repositories = selected.into_iter().map(|r| r.name).collect();
+            }
+            items.push(ActionsItem::desired(&item.name, item.value.as_deref(), item.visibility, &selected_repositories, scope.org_level));
+        }
+        Ok(items)
+    }
+
+    /// Add the visibility and selected repository IDs of an org-level item to a request body.
+    async fn add_actions_visibility(&self, item: &ActionsItem, body: &mut serde_json::Value) -> AppResult<()> {
+        let Some(visibility) = item.visibility else {
+            return Ok(());
+        };
+        body["visibility"] = json!(visibility.as_str());
+        if visibility == ActionsVisibility::Selected {
+            let ids = self.resolve_repo_ids(&item.selected_repositories, &item.name).await?;
+            body["selected_repository_ids"] = json!(ids);
+        }
+        Ok(())
+    }
+
+    /// Map repository names to IDs, listing the org's repositories only on first use.
+    async fn resolve_repo_ids(&self, names: &[String], selected_for: &str) -> AppResult<Vec<i64>> {
+        if self.repo_ids.lock().unwrap().is_none() {
+            let ids: HashMap<String, i64> = self
+                .list_org_repos()
+                .await?
+                .iter()
+                .filter_map(|r| Some((r["name"].as_str()?.to_lowercase(), r["id"].as_i64()?)))
+                .collect();
+            *self.repo_ids.lock().unwrap() = Some(ids);
+        }
+        let cache = self.repo_ids.lock().unwrap();
+        let ids = cache.as_ref().expect("repository IDs were just listed");
+        names
+            .iter()
+            .map(|name| {
+                ids.get(&name.to_lowercase()).copied().ok_or_else(|| {
+                    AppError::Validation(format!("Repository '{}' selected for {} does not exist in {}", name, selected_for, self.org))
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Create, update and (with `prune`) delete the Actions secrets of a scope to match the config.
+    ///
+    /// Values are encrypted with the scope's public key. GitHub never returns them, so existing
+    /// secrets are only sent again when the state file says the value changed, or when their
+    /// visibility or selected repositories differ.
+    ///
+    async fn sync_actions_secrets(&self, scope: &ActionsScope, desired: &[ActionsSecret], prune: bool, dry_run: bool) -> AppResult<()> {
+        let current = self.get_actions_items(scope, "secrets").await?;
+        let items: Vec<ActionsItem> = desired.iter().map(|s| ActionsItem::from_secret(s, scope.org_level)).collect();
+        let stale = self.stale_write_only(desired.iter().map(|s| (s.name.as_str(), actions_secret_key(&scope.label, &s.name), Some(&s.value))))?;
+        let mut public_key: Option<ActionsPublicKey> = None;
+
+        for change in plan_actions_item_changes(&items, &current, &stale, prune) {
+            match change {
+                ActionsItemChange::Create(index) | ActionsItemChange::Update(index) => {
+                    let secret = &desired[index];
+                    if dry_run {
+                        info!("[Dry Run] Would set Actions secret {} on {}", secret.name, scope.label);
+                        continue;
+                    }
+                    let key = match &public_key {
+                        Some(key) => key,
+                        None => public_key.insert(self.get(&format!("{}/secrets/public-key", scope.url)).await?.json().await?),
+                    };
+                    let mut body = json!({
+                        "encrypted_value": encrypt_secret(&key.key, &secret.value.resolve()?)?,
+                        "key_id": key.key_id,
+                    });
+                    self.add_actions_visibility(&items[index], &mut body).await?;
+                    info!("Setting Actions secret {} on {}", secret.name, scope.label);
+                    self.send_put(&format!("{}/secrets/{}", scope.url, secret.name), Some(body)).await?;
+                    self.record_write_only(&actions_secret_key(&scope.label, &secret.name), Some(&secret.value))?;
+                }
+                ActionsItemChange::Delete(name) if dry_run => {
+                    info!("[Dry Run] Would delete Actions secret {} from {}", name, scope.label);
+                }
+                ActionsItemChange::Delete(name) => {
+                    info!("Deleting Actions secret {} from {}", name, scope.label);
+                    self.send_delete(&format!("{}/secrets/{}", scope.url, name)).await?;
+                    self.record_write_only(&actions_secret_key(&scope.label, &name), None)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Create, update and (with `prune`) delete the Actions variables of a scope to match the config.
+    async fn sync_actions_variables(&self, scope: &ActionsScope, desired: &[ActionsVariable], prune: bool, dry_run: bool) -> AppResult<()> {
+        let current = self.get_actions_items(scope, "variables").await?;
+        let items: Vec<ActionsItem> = desired.iter().map(|v| ActionsItem::from_variable(v, scope.org_level)).collect();
+
+        for change in plan_actions_item_changes(&items, &current, &HashSet::new(), prune) {
+            match change {
+                ActionsItemChange::Create(index) | ActionsItemChange::Update(index) if dry_run => {
+                    info!("[Dry Run] Would set Actions variable {} on {} to {:?}", desired[index].name, scope.label, desired[index].value);
+                }
+                ActionsItemChange::Create(index) => {
+                    let variable = &desired[index];
+                    let mut body = json!({ "name": variable.name, "value": variable.value });
+                    self.add_actions_visibility(&items[index], &mut body).await?;
+                    info!("Creating Actions variable {} on {}", variable.name, scope.label);
+                    self.send_post(&format!("{}/variables", scope.url), body).await?;
+                }
+                ActionsItemChange::Update(index) => {
+                    let variable = &desired[index];
+                    let mut body = json!({ "name": variable.name, "value": variable.value });
+                    self.add_actions_visibility(&items[index], &mut body).await?;
+                    info!("Updating Actions variable {} on {}", variable.name, scope.label);
+                    self.send_patch(&format!("{}/variables/{}", scope.url, variable.name), body).await?;
+                }
+                ActionsItemChange::Delete(name) if dry_run => {
+                    info!("[Dry Run] Would delete Actions variable {} from {}", name, scope.label);
+                }
+                ActionsItemChange::Delete(name) => {
+                    info!("Deleting Actions variable {} from {}", name, scope.label);
+                    self.send_delete(&format!("{}/variables/{}", scope.url, name)).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetch the Actions secrets and variables of a scope for export. Secrets are written as a
+    /// reference to an environment variable of the same name, since GitHub never returns values.
+    /// Empty lists, and lists that can't be read, are `None`.
+    async fn export_actions(&self, scope: &ActionsScope) -> (Option<Vec<ActionsSecret>>, Option<Vec<ActionsVariable>>) {
+        let secrets = match self.get_actions_items(scope, "secrets").await {
+            Ok(items) => items
+                .into_iter()
+                .map(|item| ActionsSecret {
+                    value: SecretRef::Env(item.name.clone()),
+                    name: item.name,
+                    visibility: item.visibility,
+                    selected_repositories: item.selected_repositories,
+                })
+                .collect(),
+            Err(e) => {
+                error!("Failed to fetch Actions secrets for {}: {}", scope.label, e);
+                Vec::new()
+            }
+        };
+        let variables = match self.get_actions_items(scope, "variables").await {
+            Ok(items) => items
+                .into_iter()
+                .map(|item| ActionsVariable {
+                    name: item.name,
+                    value: item.value.unwrap_or_default(),
+                    visibility: item.visibility,
+                    selected_repositories: item.selected_repositories,
+                })
+                .collect(),
+            Err(e) => {
+                error!("Failed to fetch Actions variables for {}: {}", scope.label, e);
+                Vec::new()
+            }
+        };
+        (Some(secrets).filter(|s| !s.is_empty()), Some(variables).filter(|v| !v.is_empty()))
+    }
+
+    /// Sync the Actions secrets and variables of a scope. Lists that are `None` aren't managed.
+    async fn sync_actions(
+        &self,
+        scope: &ActionsScope,
+        secrets: Option<&[ActionsSecret]>,
+        variables: Option<&[ActionsVariable]>,
+        prune: &[PruneScope],
+        dry_run: bool,
+    ) -> AppResult<()> {
+        if let Some(secrets) = secrets {
+            self.sync_actions_secrets(scope, secrets, prune.contains(&PruneScope::Secrets), dry_run).await?;
+        }
+        if let Some(variables) = variables {
+            self.sync_actions_variables(scope, variables, prune.contains(&PruneScope::Variables), dry_run).await?;
+        }
+        Ok(())
+    }
+
+    fn custom_properties_url(&self) -> String {
+        format!("{}/orgs/{}/properties", GITHUB_API_BASE_URL, self.org)
+    }
+
+    /// The organization's custom property schema.
+    async fn get_custom_properties(&self) -> AppResult<Vec<CustomProperty>> {
+        let url = format!("{}/schema", self.custom_properties_url());
+        let properties: Vec<CustomPropertyResponse> = self.get(&url).await?.json().await?;
+        Ok(properties.into_iter().map(custom_property_from_response).collect())
+    }
+
+    /// Custom property values of every repository that has any, keyed by repository name.
+    async fn get_property_values(&self) -> AppResult<HashMap<String, BTreeMap<String, serde_json::Value>>> {
+        let url = format!("{}/values?per_page=100", self.custom_properties_url());
+        let repos: Vec<RepoPropertiesResponse> = self.get_all(&url).await?;
+        Ok(repos
+            .into_iter()
+            .map(|r| (r.repository_name, r.properties.into_iter().filter(|p| !p.value.is_null()).map(|p| (p.property_name, p.value)).collect()))
+            .collect())
+    }
+
+    /// Create and update the organization's custom properties and, with `prune`, delete those that
+    /// aren't listed.
+    pub async fn sync_custom_properties(&self, desired: &[CustomProperty], prune: bool, dry_run: bool) -> AppResult<()> {
+        let url = format!("{}/schema", self.custom_properties_url());
+        let mut current = self.get_custom_properties().await?;
+        for property in &mut current {
+            normalize_custom_property(property);
+        }
+        for property in desired {
+            let mut normalized = property.clone();
+            normalize_custom_property(&mut normalized);
+            if current.contains(&normalized) {
+                debug!("Custom property {} is up to date", property.name);
+                continue;
+            }
+            let body = custom_property_body(property);
+            if dry_run {
+                info!("[Dry Run] Would set custom property {} with body: {}", property.name, body);
+            } else {
+                info!("Setting custom property {}", property.name);
+                self.send_put(&format!("{}/{}", url, property.name), Some(body)).await?;
+            }
+        }
+        if prune {
+            for property in current.iter().filter(|c| !desired.iter().any(|d| d.name == c.name)) {
+                if dry_run {
+                    info!("[Dry Run] Would delete custom property {}", property.name);
+                } else {
+                    info!("Deleting custom property {}", property.name);
+                    self.send_delete(&format!("{}/{}", url, property.name)).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the custom property values of repositories through the bulk values endpoint.
+    pub async fn sync_property_values(&self, repos: &[Repo], dry_run: bool) -> AppResult<()> {
+        let desired: Vec<(&str, &BTreeMap<String, Value>)> = repos.iter().filter_map(|r| Some((r.name.as_str(), r.properties.as_ref()?))).collect();
+        if desired.is_empty() {
+            return Ok(());
+        }
+        let current = self.get_property_values().await?.into_iter().map(|(name, values)| (name.to_lowercase(), values)).collect();
+        for body in plan_property_value_updates(&desired, &current) {
+            if dry_run {
+                info!("[Dry Run] Would update custom property values with body: {}", body);
+            } else {
+                info!("Updating custom property values of {}", body["repository_names"]);
+                self.send_patch(&format!("{}/values", self.custom_properties_url()), body).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn code_security_url(&self) -> String {
+        format!("{}/orgs/{}/code-security/configurations", GITHUB_API_BASE_URL, self.org)
+    }
+
+    /// List the organization's own code security configurations (not GitHub's global ones).
+    async fn get_code_security_configurations(&self) -> AppResult<Vec<CodeSecurityConfigurationResponse>> {
+        let url = format!("{}?target_type=all&per_page=100", self.code_security_url());
+        let configurations: Vec<CodeSecurityConfigurationResponse> = self.get_all(&url).await?;
+        Ok(configurations.into_iter().filter(|c| c.target_type == "organization").collect())
+    }
+
+    /// The new repositories each configuration applies to by default, keyed by configuration ID.
+    async fn get_code_security_defaults(&self) -> AppResult<HashMap<i64, DefaultForNewRepos>> {
+        let url = format!("{}/defaults", self.code_security_url());
+        let defaults: Vec<CodeSecurityDefaultResponse> = self.get(&url).await?.json().await?;
+        Ok(defaults.into_iter().map(|d| (d.configuration.id, d.default_for_new_repos)).collect())
+    }
+
+    /// The repositories a code security configuration is attached to.
+    async fn get_configuration_repositories(&self, configuration_id: i64) -> AppResult<Vec<String>> {
+        let url = format!("{}/{}/repositories?per_page=100", self.code_security_url(), configuration_id);
+        let repositories: Vec<ConfigurationRepositoryResponse> = self.get_all(&url).await?;
+        Ok(repositories.into_iter().filter(|r| configuration_is_attached(&r.status)).map(|r| r.repository.name).collect())
+    }
+
+    /// Fetch the code security configurations for export, with their defaults and attached repositories.
+    async fn export_code_security_configurations(&self) -> AppResult<Vec<CodeSecurityConfiguration>> {
+        let defaults = self.get_code_security_defaults().await?;
+        let mut configurations = Vec::new();
+        for response in self.get_code_security_configurations().await? {
+            let repositories = self.get_configuration_repositories(response.id).await?;
+            let default_for_new_repos = defaults.get(&response.id).copied();
+            configurations.push(code_security_configuration_from_response(response, default_for_new_repos, repositories));
+        }
+        Ok(configurations)
+    }
+
+    ///
+    /// Create and update code security configurations by name, set their defaults for new
+    /// repositories, and attach them to repositories. With `prune`, repositories that shouldn't
+    /// have a configuration are detached from it, and the org's configurations that aren't listed
+    /// are deleted.
+    ///
+    pub async fn sync_code_security_configurations(&self, desired: &[CodeSecurityConfiguration], prune: bool, dry_run: bool) -> AppResult<()> {
+        let url = self.code_security_url();
+        let mut current = self.get_code_security_configurations().await?;
+        let defaults = self.get_code_security_defaults().await?;
+        let org_repos = self.list_org_repos().await?;
+        let repos: Vec<(String, Visibility)> = org_repos
+            .iter()
+            .filter_map(|r| Some((r["name"].as_str()?.to_string(), visibility_from_api(r["visibility"].as_str(), r["private"].as_bool().unwrap_or(false)))))
+            .collect();
+        let repo_ids = |names: &[String]| -> AppResult<Vec<i64>> {
+            names
+                .iter()
+                .map(|name| {
+                    org_repos
+                        .iter()
+                        .find(|r| r["name"].as_str().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+                        .and_then(|r| r["id"].as_i64())
+                        .ok_or_else(|| AppError::Validation(format!("Repository '{}' for a code security configuration does not exist in {}", name, self.org)))
+                })
+                .collect()
+        };
+
+        for configuration in desired {
+            let existing = current.iter().find(|c| c.name == configuration.name);
+            let id = match existing {
+                Some(existing) => {
+                    let changes = code_security_changes(configuration, existing);
+                    if changes.is_empty() {
+                        debug!("Code security configuration {} is up to date", configuration.name);
+                    } else if dry_run {
+                        info!("[Dry Run] Would update code security configuration {} with: {:?}", configuration.name, changes);
+                    } else {
+                        info!("Updating code security configuration {}", configuration.name);
+                        self.send_patch(&format!("{}/{}", url, existing.id), serde_json::Value::Object(changes)).await?;
+                    }
+                    Some(existing.id)
+                }
+                None if dry_run => {
+                    info!("[Dry Run] Would create code security configuration {}", configuration.name);
+                    None
+                }
+                None => {
+                    info!("Creating code security configuration {}", configuration.name);
+                    let mut body: serde_json::Map<String, serde_json::Value> =
+                        configuration.settings.iter().map(|(k, v)| (k.clone(), serde_json::to_value(v).unwrap_or(serde_json::Value::Null))).collect();
+                    body.insert("name".to_string(), json!(configuration.name));
+                    body.insert("description".to_string(), json!(configuration.description.as_deref().unwrap_or_default()));
+                    self.send_post(&url, serde_json::Value::Object(body)).await?;
+                    current = self.get_code_security_configurations().await?;
+                    let created = current.iter().find(|c| c.name == configuration.name).ok_or_else(|| {
+                        AppError::GitHubApi(format!("Code security configuration {} not found after creating it", configuration.name))
+                    })?;
+                    Some(created.id)
+                }
+            };
+
+            if let Some(default) = configuration.default_for_new_repos {
+                if id.and_then(|id| defaults.get(&id)) != Some(&default) {
+                    let body = json!({ "default_for_new_repos": default });
+                    match id {
+                        Some(id) if !dry_run => {
+                            info!("Setting code security configuration {} as default for new repos: {:?}", configuration.name, default);
+                            self.send_put(&format!("{}/{}/defaults", url, id), Some(body)).await?;
+                        }
+                        _ => info!("[Dry Run] Would set code security configuration {} as default for new repos with body: {}", configuration.name, body),
+                    }
+                }
+            }
+
+            let Some(expected) = expected_attachments(configuration, &repos) else {
+                continue;
+            };
+            let attached = match id {
+                Some(id) => self.get_configuration_repositories(id).await?,
+                None => Vec::new(),
+            };
+            let (attach, detach) = plan_attachment_changes(&expected, &attached, prune);
+            if !attach.is_empty() {
+                let ids = repo_ids(&attach)?;
+                match id {
+                    Some(id) if !dry_run => {
+                        info!("Attaching code security configuration {} to {}", configuration.name, attach.join(", "));
+                        self.send_post(&format!("{}/{}/attach", url, id), json!({ "scope": "selected", "selected_repository_ids": ids })).await?;
+                    }
+                    _ => info!("[Dry Run] Would attach code security configuration {} to {}", configuration.name, attach.join(", ")),
+                }
+            }
+            if !detach.is_empty() {
+                let ids = repo_ids(&detach)?;
+                if dry_run {
+                    info!("[Dry Run] Would detach code security configuration {} from {}", configuration.name, detach.join(", "));
+                } else {
+                    info!("Detaching code security configuration {} from {}", configuration.name, detach.join(", "));
+                    self.send_delete_with_body(&format!("{}/detach", url), Some(json!({ "selected_repository_ids": ids }))).await?;
+                }
+            }
+        }
+
+        if prune {
+            for configuration in current.iter().filter(|c| !desired.iter().any(|d| d.name == c.name)) {
+                if dry_run {
+                    info!("[Dry Run] Would delete code security configuration {}", configuration.name);
+                } else {
+                    info!("Deleting code security configuration {}", configuration.name);
+                    self.send_delete(&format!("{}/{}", url, configuration.id)).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn repo_url(&self, repo_name: &str) -> String {
+        format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name)
+    }
+
+    ///
+    /// Read a repository's security features from their dedicated endpoints.
+    ///
+    /// Features that can't be read, e.g. because the plan doesn't include them or the token lacks
+    /// admin access, are `None`. Dependabot alerts report a 404 when they are off.
+    ///
+    async fn get_repo_security(&self, repo_name: &str) -> AppResult<RepoSecurity> {
+        let url = self.repo_url(repo_name);
+        let repo_json: serde_json::Value = self.get(&url).await?.json().await?;
+        let mut security = security_from_repo_json(&repo_json);
+        security.dependabot_alerts = match self.get_optional(&format!("{}/vulnerability-alerts", url)).await {
+            Ok(found) => Some(found.is_some()),
+            Err(e) => {
+                debug!("Could not read Dependabot alerts of {}: {}", repo_name, e);
+                None
+            }
+        };
+        security.dependabot_security_updates = match self.get_optional(&format!("{}/automated-security-fixes", url)).await {
+            Ok(Some(response)) => Some(response.json::<EnabledResponse>().await?.enabled),
+            Ok(None) => Some(false),
+            Err(e) => {
+                debug!("Could not read Dependabot security updates of {}: {}", repo_name, e);
+                None
+            }
+        };
+        security.private_vulnerability_reporting = match self.get_optional(&format!("{}/private-vulnerability-reporting", url)).await {
+            Ok(Some(response)) => Some(response.json::<EnabledResponse>().await?.enabled),
+            Ok(None) => None,
+            Err(e) => {
+                debug!("Could not read private vulnerability reporting of {}: {}", repo_name, e);
+                None
+            }
+        };
+        security.code_scanning_default_setup = match self.get_optional(&format!("{}/code-scanning/default-setup", url)).await {
+            Ok(Some(response)) => Some(response.json::<CodeScanningDefaultSetupResponse>().await?.state == "configured"),
+            Ok(None) => None,
+            Err(e) => {
+                debug!("Could not read code scanning default setup of {}: {}", repo_name, e);
+                None
+            }
+        };
+        Ok(security)
+    }
+
+    /// Update a repository's security features to match the config. Unset fields are left alone.
+    pub async fn update_repo_security(&self, repo_name: &str, desired: &RepoSecurity, dry_run: bool) -> AppResult<()> {
+        let url = self.repo_url(repo_name);
+        let current = self.get_repo_security(repo_name).await?;
+        for change in plan_security_changes(desired, &current) {
+            match change {
+                SecurityChange::AnalysisFeatures(features) if dry_run => {
+                    info!("[Dry Run] Would update security and analysis of {}/{}: {}", self.org, repo_name, features);
+                }
+                SecurityChange::AnalysisFeatures(features) => {
+                    info!("Updating security and analysis of {}/{}: {}", self.org, repo_name, features);
+                    self.send_patch(&url, json!({ "security_and_analysis": features })).await?;
+                }
+                SecurityChange::Toggle { path, enable } => {
+                    let action = if enable { "enable" } else { "disable" };
+                    if dry_run {
+                        info!("[Dry Run] Would {} {} on {}/{}", action, path.trim_start_matches('/'), self.org, repo_name);
+                    } else if enable {
+                        info!("Enabling {} on {}/{}", path.trim_start_matches('/'), self.org, repo_name);
+                        self.send_put(&format!("{}{}", url, path), None).await?;
+                    } else {
+                        info!("Disabling {} on {}/{}", path.trim_start_matches('/'), self.org, repo_name);
+                        self.send_delete(&format!("{}{}", url, path)).await?;
+                    }
+                }
+                SecurityChange::CodeScanningDefaultSetup(configured) => {
+                    let state = if configured { "configured" } else { "not-configured" };
+                    if dry_run {
+                        info!("[Dry Run] Would set code scanning default setup of {}/{} to {}", self.org, repo_name, state);
+                    } else {
+                        info!("Setting code scanning default setup of {}/{} to {}", self.org, repo_name, state);
+                        self.send_patch(&format!("{}/code-scanning/default-setup", url), json!({ "state": state })).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn repo_actions_permissions_url(&self, repo_name: &str) -> String {
+        format!("{}/repos/{}/{}/actions/permissions", GITHUB_API_BASE_URL, self.org, repo_name)
+    }
+
+    fn org_actions_permissions_url(&self) -> String {
+        format!("{}/orgs/{}/actions/permissions", GITHUB_API_BASE_URL, self.org)
+    }
+
+    ///
+    /// Read the Actions policy below a scope's `actions/permissions` endpoint.
+    ///
+    /// Selected actions are only read when `allowed_actions` is `selected`. The fork pull request
+    /// approval policy is only read when `with_fork_approval` is set, since GitHub only has one
+    /// for public repositories.
+    ///
+    async fn get_actions_policy(&self, url: &str, allowed_actions: Option<AllowedActions>, with_fork_approval: bool) -> AppResult<ActionsPolicy> {
+        let selected_actions = match allowed_actions {
+            Some(AllowedActions::Selected) => Some(self.get(&format!("{}/selected-actions", url)).await?.json().await?),
+            _ => None,
+        };
+        let workflow: WorkflowPermissionsResponse = self.get(&format!("{}/workflow", url)).await?.json().await?;
+        let fork_pr_approval = if with_fork_approval {
+            let response: ForkPrApprovalResponse = self.get(&format!("{}/fork-pr-contributor-approval", url)).await?.json().await?;
+            Some(response.approval_policy)
+        } else {
+            None
+        };
+        Ok(ActionsPolicy {
+            allowed_actions,
+            selected_actions,
+            default_workflow_permissions: workflow.default_workflow_permissions,
+            can_approve_pull_request_reviews: workflow.can_approve_pull_request_reviews,
+            fork_pr_approval,
+        })
+    }
+
+    /// Read a repository's Actions settings. The access level only exists for private and internal
+    /// repositories, so it is only read with `with_access_level`.
+    async fn get_repo_actions(&self, repo_name: &str, with_access_level: bool, with_fork_approval: bool) -> AppResult<RepoActions> {
+        let url = self.repo_actions_permissions_url(repo_name);
+        let permissions: ActionsPermissionsResponse = self.get(&url).await?.json().await?;
+        let access_level = if with_access_level {
+            let response: ActionsAccessResponse = self.get(&format!("{}/access", url)).await?.json().await?;
+            Some(response.access_level)
+        } else {
+            None
+        };
+        Ok(RepoActions {
+            enabled: permissions.enabled,
+            access_level,
+            policy: self.get_actions_policy(&url, permissions.allowed_actions, with_fork_approval).await?,
+        })
+    }
+
+    /// Read the organization's Actions settings, including the repositories that can use Actions
+    /// when only selected ones can.
+    async fn get_org_actions(&self) -> AppResult<OrgActions> {
+        let url = self.org_actions_permissions_url();
+        let permissions: ActionsPermissionsResponse = self.get(&url).await?.json().await?;
+        let mut selected_repositories = Vec::new();
+        if permissions.enabled_repositories == Some(EnabledRepositories::Selected) {
+            let selected: Vec<RepositoryNameResponse> = self.get_all_in(&format!("{}/repositories?per_page=100", url), "repositories").await?;
+            selected_repositories = selected.into_iter().map(|r| r.name).collect();
+        }
+        Ok(OrgActions {
+            enabled_repositories: permissions.enabled_repositories,
+            selected_repositories,
+            policy: self.get_actions_policy(&url, permissions.allowed_actions, true).await?,
+        })
+    }
+
+    /// Send planned Actions permission requests, each relative to the scope's permissions `url`.
+    async fn put_actions_requests(&self, url: &str, label: &str, requests: Vec<(&str, serde_json::Value)>, dry_run: bool) -> AppResult<()> {
+        for (path, body) in requests {
+            let full_url = format!("{}{}", url, path);
+            if dry_run {
+                info!("[Dry Run] Would update Actions permissions of {} via PUT {} with body: {:?}", label, full_url, body);
+            } else {
+                info!("Updating Actions permissions of {} via {}", label, full_url);
+                self.send_put(&full_url, Some(body)).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Update a repository's Actions settings to match its `actions` block. Unset fields are left alone.
+    pub async fn update_repo_actions(&self, repo_name: &str, desired: &RepoActions, dry_run: bool) -> AppResult<()> {
+        let label = format!("{}/{}", self.org, repo_name);
+        let current = self.get_repo_actions(repo_name, desired.access_level.is_some(), desired.policy.fork_pr_approval.is_some()).await?;
+        let enabled = ("enabled", desired.enabled.map(|e| json!(e)), json!(current.enabled.unwrap_or(true)));
+        let mut requests = plan_actions_policy_requests(enabled, &desired.policy, &current.policy);
+        if let Some(access_level) = desired.access_level.filter(|a| current.access_level != Some(*a)) {
+            requests.push(("/access", json!({ "access_level": access_level })));
+        }
+        self.put_actions_requests(&self.repo_actions_permissions_url(repo_name), &label, requests, dry_run).await
+    }
+
+    ///
+    /// Update the organization's Actions settings to match the top-level `actions` block. Unset
+    /// fields are left alone. Selected repositories must exist.
+    ///
+    pub async fn update_org_actions(&self, desired: &OrgActions, dry_run: bool) -> AppResult<()> {
+        let current = self.get_org_actions().await?;
+        let enabled = (
+            "enabled_repositories",
+            desired.enabled_repositories.map(|e| json!(e)),
+            json!(current.enabled_repositories.unwrap_or(EnabledRepositories::All)),
+        );
+        let mut requests = plan_actions_policy_requests(enabled, &desired.policy, &current.policy);
+
+        let mut desired_repos: Vec<String> = desired.selected_repositories.iter().map(|r| r.to_lowercase()).collect();
+        let mut current_repos: Vec<String> = current.selected_repositories.iter().map(|r| r.to_lowercase()).collect();
+        desired_repos.sort();
+        current_repos.sort();
+        if desired.enabled_repositories == Some(EnabledRepositories::Selected) && desired_repos != current_repos {
+            let ids = self.resolve_repo_ids(&desired.selected_repositories, "Actions").await?;
+            requests.push(("/repositories", json!({ "selected_repository_ids": ids })));
+        }
+        self.put_actions_requests(&self.org_actions_permissions_url(), &self.org, requests, dry_run).await
+    }
+
+    fn environment_url(&self, repo_name: &str, environment: &str) -> String {
+        format!("{}/repos/{}/{}/environments/{}", GITHUB_API_BASE_URL, self.org, repo_name, encode_path_segment(environment))
+    }
+
+    fn environment_actions_scope(&self, repo_name: &str, environment: &str) -> ActionsScope {
+        ActionsScope {
+            url: self.environment_url(repo_name, environment),
+            label: format!("{}/{}/{}", self.org, repo_name, environment),
+            org_level: false,
+        }
+    }
+
+    async fn get_branch_policies(&self, repo_name: &str, environment: &str) -> AppResult<Vec<BranchPolicyResponse>> {
+        let url = format!("{}/deployment-branch-policies?per_page=100", self.environment_url(repo_name, environment));
+        self.get_all_in(&url, "branch_policies").await
+    }
+
+    /// List a repository's environments with their protection rules (without secrets or variables).
+    /// Repos whose plan doesn't offer environments have none.
+    async fn get_environments(&self, repo_name: &str) -> AppResult<Vec<Environment>> {
+        let url = format!("{}/repos/{}/{}/environments?per_page=100", GITHUB_API_BASE_URL, self.org, repo_name);
+        let Some(response) = self.get_optional(&url).await? else {
+            return Ok(Vec::new());
+        };
+        let listed: Vec<EnvironmentResponse> = self.collect_pages_in(response, "environments").await?;
+        let mut environments = Vec::new();
+        for environment in &listed {
+            let custom_branches = match &environment.deployment_branch_policy {
+                Some(policy) if policy.custom_branch_policies => {
+                    self.get_branch_policies(repo_name, &environment.name).await?.into_iter().map(|p| p.name).collect()
+                }
+                _ => Vec::new(),
+            };
+            environments.push(environment_from_response(environment, custom_branches));
+        }
+        Ok(environments)
+    }
+
+    /// Create or update an environment with the protection rules in `environment`, resolving
+    /// reviewers to user and team IDs.
+    async fn put_environment(&self, repo_name: &str, environment: &Environment) -> AppResult<()> {
+        let mut reviewers = Vec::new();
+        for login in &environment.reviewers.users {
+            let user: UserResponse = self.get(&format!("{}/users/{}", GITHUB_API_BASE_URL, login)).await?.json().await?;
+            reviewers.push(json!({ "type": "User", "id": user.id }));
+        }
+        for reference in &environment.reviewers.teams {
+            let team = self.find_team(reference).await?.ok_or_else(|| {
+                AppError::Validation(format!("Reviewer team '{}' of environment '{}' does not exist", reference, environment.name))
+            })?;
+            reviewers.push(json!({ "type": "Team", "id": team.id }));
+        }
+        let deployment_branch_policy = environment.deployment_branch_policy.as_ref().map(|policy| {
+            json!({ "protected_branches": policy.protected_branches, "custom_branch_policies": !policy.custom_branches.is_empty() })
+        });
+        let body = json!({
+            "wait_timer": environment.wait_timer,
+            "prevent_self_review": environment.prevent_self_review,
+            "reviewers": reviewers,
+            "deployment_branch_policy": deployment_branch_policy,
+        });
+        self.send_put(&self.environment_url(repo_name, &environment.name), Some(body)).await
+    }
+
+    /// Bring an environment's custom deployment branch patterns in line with the config.
+    /// `exists` says whether the environment existed before this sync.
+    async fn sync_branch_policies(&self, repo_name: &str, environment: &Environment, exists: bool, dry_run: bool) -> AppResult<()> {
+        let desired = environment.deployment_branch_policy.as_ref().map(|p| p.custom_branches.as_slice()).unwrap_or_default();
+        if desired.is_empty() {
+            // GitHub drops the patterns when custom branch policies are turned off
+            return Ok(());
+        }
+        let current = if dry_run && !exists {
+            Vec::new()
+        } else {
+            self.get_branch_policies(repo_name, &environment.name).await?
+        };
+        let url = format!("{}/deployment-branch-policies", self.environment_url(repo_name, &environment.name));
+        let (create, delete) = plan_branch_policy_changes(desired, &current);
+        let prefix = if dry_run { "[Dry Run] Would" } else { "Will" };
+        for pattern in create {
+            info!("{} allow branch pattern {} to deploy to {} of {}/{}", prefix, pattern, environment.name, self.org, repo_name);
+            if !dry_run {
+                self.send_post(&url, json!({ "name": pattern, "type": "branch" })).await?;
+            }
+        }
+        for (id, pattern) in delete {
+            info!("{} remove branch pattern {} from {} of {}/{}", prefix, pattern, environment.name, self.org, repo_name);
+            if !dry_run {
+                self.send_delete(&format!("{}/{}", url, id)).await?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Bring a repository's deployment environments in line with the config.
+    ///
+    /// Each listed environment gets exactly the protection rules, branch patterns, secrets and
+    /// variables in the config. Environments that aren't listed are deleted only when the repo
+    /// opts in with `prune_environments` and `prune` includes `environments`; environments GitHub
+    /// manages itself are never deleted.
+    ///
+    /// # Arguments
+    /// * `repo_name` - The name of the repository.
+    /// * `environments` - The environments the repository should have.
+    /// * `prune_environments` - Whether the repository opted in to deleting unlisted environments.
+    /// * `prune` - Scopes to delete unlisted resources in: `environments` for environments, and
+    ///   `secrets` and `variables` for those of the listed environments.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    pub async fn manage_environments(&self, repo_name: &str, environments: &[Environment], prune_environments: bool, prune: &[PruneScope], dry_run: bool) -> AppResult<()> {
+        let current = self.get_environments(repo_name).await?;
+
+        for environment in environments {
+            let existing = current.iter().find(|e| e.name == environment.name);
+            let mut desired = environment_protection(environment);
+            for team in &mut desired.reviewers.teams {
+                *team = self.resolve_team_slug(team).await?.to_lowercase();
+            }
+            desired.reviewers.teams.sort();
+            let up_to_date = existing.is_some_and(|e| {
+                let mut current = environment_protection(e);
+                // Custom branch patterns are managed through their own endpoints
+                if let (Some(current_policy), Some(desired_policy)) = (&mut current.deployment_branch_policy, &desired.deployment_branch_policy) {
+                    current_policy.custom_branches = desired_policy.custom_branches.clone();
+                }
+                current == desired
+            });
+
+            if !up_to_date {
+                let action = if existing.is_some() { "update" } else { "create" };
+                if dry_run {
+                    info!("[Dry Run] Would {} environment {} of {}/{}: {:?}", action, environment.name, self.org, repo_name, desired);
+                } else {
+                    info!("Will {} environment {} of {}/{}", action, environment.name, self.org, repo_name);
+                    self.put_environment(repo_name, environment).await?;
+                }
+            }
+            self.sync_branch_policies(repo_name, environment, existing.is_some(), dry_run).await?;
+
+            if dry_run && existing.is_none() {
+                info!("[Dry Run] Would set secrets and variables of the new environment {} of {}/{}", environment.name, self.org, repo_name);
+                continue;
+            }
+            let scope = self.environment_actions_scope(repo_name, &environment.name);
+            self.sync_actions(&scope, environment.secrets.as_deref(), environment.variables.as_deref(), prune, dry_run).await?;
+        }
+
+        if prune.contains(&PruneScope::Environments) && !prune_environments {
+            debug!("Environments of {}/{} are not pruned (set 'prune_environments: true' to opt in)", self.org, repo_name);
+        } else if prune.contains(&PruneScope::Environments) {
+            for name in unlisted_environments(&current, environments) {
+                if dry_run {
+                    info!("[Dry Run] Would delete environment {} of {}/{}", name, self.org, repo_name);
+                } else {
+                    info!("Deleting environment {} of {}/{}", name, self.org, repo_name);
+                    self.send_delete(&self.environment_url(repo_name, name)).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Bring a repository's webhooks in line with the config.
+    ///
+    /// # Arguments
+    /// * `repo_name` - The name of the repository.
+    /// * `webhooks` - The webhooks the repository should have, matched by URL.
+    /// * `delete_unlisted` - If true, hooks whose URL isn't in `webhooks` are deleted.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    pub async fn manage_webhooks(&self, repo_name: &str, webhooks: &[WebhookConfig], delete_unlisted: bool, dry_run: bool) -> AppResult<()> {
+        let target = format!("{}/{}", self.org, repo_name);
+        self.sync_webhooks(&self.repo_hooks_url(repo_name), &target, webhooks, delete_unlisted, dry_run).await
+    }
+
+    fn deploy_keys_url(&self, repo_name: &str) -> String {
+        format!("{}/repos/{}/{}/keys", GITHUB_API_BASE_URL, self.org, repo_name)
+    }
+
+    async fn get_deploy_keys(&self, repo_name: &str) -> AppResult<Vec<DeployKeyResponse>> {
+        self.get_all(&format!("{}?per_page=100", self.deploy_keys_url(repo_name))).await
+    }
+
+    async fn add_deploy_key(&self, repo_name: &str, deploy_key: &DeployKey) -> AppResult<()> {
+        let body = json!({ "title": deploy_key.title, "key": deploy_key.key, "read_only": deploy_key.read_only });
+        self.send_post(&self.deploy_keys_url(repo_name), body).await
+    }
+
+    ///
+    /// Bring a repository's deploy keys in line with the config.
+    ///
+    /// # Arguments
+    /// * `repo_name` - The name of the repository.
+    /// * `deploy_keys` - The keys the repository should have, matched by title.
+    /// * `prune` - If true, keys whose title isn't listed are deleted.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    pub async fn manage_deploy_keys(&self, repo_name: &str, deploy_keys: &[DeployKey], prune: bool, dry_run: bool) -> AppResult<()> {
+        let desired = resolve_deploy_keys(deploy_keys)?;
+        let current = self.get_deploy_keys(repo_name).await?;
+        for change in plan_deploy_key_changes(&desired, &current, prune) {
+            match change {
+                DeployKeyChange::Add(deploy_key) if dry_run => {
+                    info!("[Dry Run] Would add deploy key {} to {}/{}", deploy_key.title, self.org, repo_name);
+                }
+                DeployKeyChange::Add(deploy_key) => {
+                    info!("Adding deploy key {} to {}/{}", deploy_key.title, self.org, repo_name);
+                    self.add_deploy_key(repo_name, deploy_key).await?;
+                }
+                DeployKeyChange::Replace(_, deploy_key) if dry_run => {
+                    info!("[Dry Run] Would add the new deploy key {} to {}/{} and delete the old one", deploy_key.title, self.org, repo_name);
+                }
+                DeployKeyChange::Replace(id, deploy_key) => {
+                    info!("Replacing deploy key {} of {}/{}", deploy_key.title, self.org, repo_name);
+                    self.add_deploy_key(repo_name, deploy_key).await?;
+                    self.send_delete(&format!("{}/{}", self.deploy_keys_url(repo_name), id)).await?;
+                }
+                DeployKeyChange::ChangeAccess(_, deploy_key) if dry_run => {
+                    info!("[Dry Run] Would delete and add again deploy key {} of {}/{} with read_only: {}", deploy_key.title, self.org, repo_name, deploy_key.read_only);
+                }
+                DeployKeyChange::ChangeAccess(id, deploy_key) => {
+                    info!("Changing access of deploy key {} of {}/{} to read_only: {}", deploy_key.title, self.org, repo_name, deploy_key.read_only);
+                    self.send_delete(&format!("{}/{}", self.deploy_keys_url(repo_name), id)).await?;
+                    self.add_deploy_key(repo_name, deploy_key).await?;
+                }
+                DeployKeyChange::Delete(_, title) if dry_run => {
+                    info!("[Dry Run] Would delete deploy key {} from {}/{}", title, self.org, repo_name);
+                }
+                DeployKeyChange::Delete(id, title) => {
+                    info!("Deleting deploy key {} from {}/{}", title, self.org, repo_name);
+                    self.send_delete(&format!("{}/{}", self.deploy_keys_url(repo_name), id)).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Replace a deploy key with a freshly generated Ed25519 key pair.
+    ///
+    /// The private key is written to `private_key_path` and the public key next to it with a `.pub`
+    /// extension. If that public key is the configured key's `key_file`, both files are replaced;
+    /// otherwise existing files are not overwritten. The new key is added before keys with the same
+    /// title are deleted, so the repo never goes without one. Its access is taken from the config,
+    /// then from the existing key, and is read-only otherwise.
+    ///
+    /// # Arguments
+    /// * `config_path` - Path to the config file, which is checked for the key's entry.
+    /// * `repo_name` - The name of the repository.
+    /// * `title` - Title of the deploy key.
+    /// * `private_key_path` - Where to write the private key.
+    /// * `dry_run` - If true, no key is generated and nothing is changed.
+    ///
+    pub async fn rotate_deploy_key(&self, config_path: &str, repo_name: &str, title: &str, private_key_path: &str, dry_run: bool) -> AppResult<()> {
+        let config = Config::from_file_with_defaults(config_path, None)?;
+        let configured = config
+            .repos
+            .iter()
+            .find(|r| r.name == repo_name)
+            .and_then(|r| r.deploy_keys.as_ref()?.iter().find(|k| k.title == title));
+        let existing: Vec<DeployKeyResponse> = self.get_deploy_keys(repo_name).await?.into_iter().filter(|k| k.title == title).collect();
+        let read_only = configured.map(|k| k.read_only).or(existing.first().map(|k| k.read_only)).unwrap_or(true);
+        let private_key_path = std::path::Path::new(private_key_path);
+        let public_key_path = crate::ssh_key::public_key_path(private_key_path);
+        // The key pair the config already reads is replaced in place
+        let replace = configured
+            .and_then(|k| k.key_file.as_deref())
+            .is_some_and(|key_file| std::path::Path::new(key_file) == public_key_path);
+        if !replace {
+            crate::ssh_key::check_writable(private_key_path)?;
+        }
+
+        if dry_run {
+            info!(
+                "[Dry Run] Would write a new key pair to {}, add it to {}/{} as {} (read_only: {}) and delete {} existing key(s) with that title",
+                private_key_path.display(),
+                self.org,
+                repo_name,
+                title,
+                read_only,
+                existing.len()
+            );
+            return Ok(());
+        }
+
+        let pair = crate::ssh_key::generate(&format!("{}@{}/{}", title, self.org, repo_name))?;
+        crate::ssh_key::write(&pair, private_key_path, replace)?;
+        info!("Wrote private key to {} and public key to {}", private_key_path.display(), public_key_path.display());
+        let new_key = DeployKey { title: title.to_string(), key: Some(pair.public_key), key_file: None, read_only };
+        info!("Adding new deploy key {} to {}/{}", title, self.org, repo_name);
+        self.add_deploy_key(repo_name, &new_key).await?;
+        for old_key in &existing {
+            info!("Deleting old deploy key {} ({}) from {}/{}", title, old_key.id, self.org, repo_name);
+            self.send_delete(&format!("{}/{}", self.deploy_keys_url(repo_name), old_key.id)).await?;
+        }
+
+        let public_key_file = public_key_path.to_string_lossy();
+        match configured {
+            Some(_) if replace => {}
+            Some(_) => warn!("Update deploy key {} of repo {} in {} to `key_file: {}`, or the next sync will restore the old key", title, repo_name, config_path, public_key_file),
+            None => warn!("Deploy key {} of repo {} isn't in {}; add it with `key_file: {}` to manage it", title, repo_name, config_path, public_key_file),
+        }
+        Ok(())
+    }
+
+    fn repo_resources_url<T: RepoResource>(&self, repo_name: &str) -> String {
+        format!("{}/repos/{}/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name, T::PATH)
+    }
+
+    /// A repo's labels, autolinks or milestones, each with the ID it is addressed by.
+    async fn get_repo_resources<T: RepoResource>(&self, repo_name: &str) -> AppResult<Vec<(String, T)>> {
+        let url = format!("{}{}", self.repo_resources_url::<T>(repo_name), T::LIST_QUERY);
+        let responses: Vec<T::Response> = self.get_all(&url).await?;
+        Ok(responses.into_iter().map(T::from_response).collect())
+    }
+
+    ///
+    /// Bring a repository's labels, autolinks or milestones in line with the config.
+    ///
+    /// # Arguments
+    /// * `repo_name` - The name of the repository.
+    /// * `desired` - The resources the repository should have, matched by key.
+    /// * `prune` - If true, resources that aren't listed are deleted.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    async fn manage_repo_resources<T: RepoResource>(&self, repo_name: &str, desired: &[T], prune: bool, dry_run: bool) -> AppResult<()> {
+        let url = self.repo_resources_url::<T>(repo_name);
+        let current = self.get_repo_resources::<T>(repo_name).await?;
+        let changes = plan_resource_changes(desired, &current, prune);
+        if changes.is_empty() {
+            debug!("{}s of {}/{} already match desired config", T::KIND, self.org, repo_name);
+        }
+        for change in changes {
+            match change {
+                ResourceChange::Create(resource) if dry_run => {
+                    info!("[Dry Run] Would create {} {} in {}/{} with body: {}", T::KIND, resource.key(), self.org, repo_name, resource.create_body());
+                }
+                ResourceChange::Create(resource) => {
+                    info!("Creating {} {} in {}/{}", T::KIND, resource.key(), self.org, repo_name);
+                    self.send_post(&url, resource.create_body()).await?;
+                }
+                ResourceChange::Update(id, resource) if dry_run => {
+                    info!("[Dry Run] Would update {} {} in {}/{} with body: {}", T::KIND, id, self.org, repo_name, resource.update_body());
+                }
+                ResourceChange::Update(id, resource) => {
+                    info!("Updating {} {} in {}/{}", T::KIND, id, self.org, repo_name);
+                    self.send_patch(&format!("{}/{}", url, encode_path_segment(&id)), resource.update_body()).await?;
+                }
+                ResourceChange::Delete(_, key) if dry_run => {
+                    info!("[Dry Run] Would delete {} {} from {}/{}", T::KIND, key, self.org, repo_name);
+                }
+                ResourceChange::Delete(id, key) => {
+                    info!("Deleting {} {} from {}/{}", T::KIND, key, self.org, repo_name);
+                    self.send_delete(&format!("{}/{}", url, encode_path_segment(&id))).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sync a repo's labels, autolinks and milestones, falling back to the org defaults for repos
+    /// without their own lists.
+    pub async fn update_repo_resources(&self, repo: &Repo, config: &Config, prune: &[PruneScope], dry_run: bool) -> AppResult<()> {
+        let prunes = |scope: PruneScope| prune.contains(&scope);
+        if let Some((labels, prune)) = repo_list_plan(repo.labels.as_deref(), config.default_labels.as_deref(), prunes(Label::PRUNE_SCOPE)) {
+            self.manage_repo_resources(&repo.name, labels, prune, dry_run).await?;
+        }
+        if let Some((autolinks, prune)) = repo_list_plan(repo.autolinks.as_deref(), config.default_autolinks.as_deref(), prunes(Autolink::PRUNE_SCOPE)) {
+            self.manage_repo_resources(&repo.name, autolinks, prune, dry_run).await?;
+        }
+        if let Some((milestones, prune)) = repo_list_plan(repo.milestones.as_deref(), config.default_milestones.as_deref(), prunes(Milestone::PRUNE_SCOPE)) {
+            self.manage_repo_resources(&repo.name, milestones, prune, dry_run).await?;
+        }
+        Ok(())
+    }
+
+    /// Fetch a repo's labels, autolinks or milestones for export. Errors are logged and an empty
+    /// list counts as none.
+    async fn export_repo_resources<T: RepoResource>(&self, repo_name: &str) -> Option<Vec<T>> {
+        match self.get_repo_resources::<T>(repo_name).await {
+            Ok(resources) => Some(resources.into_iter().map(|(_, r)| r).collect::<Vec<T>>()).filter(|r| !r.is_empty()),
+            Err(e) => {
+                error!("Failed to fetch {}s for repo {}: {}", T::KIND, repo_name, e);
+                None
+            }
+        }
+    }
+
+    ///
+    /// Create or update the organization's webhooks to match the config.
+    ///
+    /// Hooks are matched by URL. Org hooks that aren't listed in the config are left untouched.
+    ///
+    /// # Arguments
+    /// * `webhooks` - The org webhooks from the config.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    pub async fn manage_org_webhooks(&self, webhooks: &[WebhookConfig], dry_run: bool) -> AppResult<()> {
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+        self.sync_webhooks(&self.org_hooks_url(), &self.org, webhooks, false, dry_run).await
+    }
+
+    ///
+    /// Update a repository's description, homepage, topics and default branch.
+    ///
+    /// If the desired default branch already exists, the default is switched to it. If it doesn't,
+    /// the current default branch is renamed to it when the repo opts in with
+    /// `rename_default_branch_from` naming that branch, which also retargets open pull requests and
+    /// branch protection rules. Without the opt-in this fails with a validation error.
+    ///
+    /// # Arguments
+    /// * `repo` - The repository as configured.
+    /// * `current` - The repository as GitHub returns it, e.g. from `update_repo_settings`.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    pub async fn update_repo_metadata(&self, repo: &Repo, current: &serde_json::Value, dry_run: bool) -> AppResult<()> {
+        if repo.description.is_none() && repo.homepage.is_none() && repo.topics.is_none() && repo.default_branch.is_none() {
+            return Ok(());
+        }
+        let repo_url = self.repo_url(&repo.name);
+        let plan = plan_repo_metadata(repo, current);
+
+        if !plan.patch.is_empty() {
+            let body = serde_json::Value::Object(plan.patch);
+            if dry_run {
+                info!("[Dry Run] Would update {}/{} with body: {}", self.org, repo.name, body);
+            } else {
+                info!("Updating description and homepage of {}/{}", self.org, repo.name);
+                self.send_patch(&repo_url, body).await?;
+            }
+        }
+        if let Some(topics) = plan.topics {
+            if dry_run {
+                info!("[Dry Run] Would set topics of {}/{} to {:?}", self.org, repo.name, topics);
+            } else {
+                info!("Setting topics of {}/{} to {:?}", self.org, repo.name, topics);
+                self.send_put(&format!("{}/topics", repo_url), Some(json!({ "names": topics }))).await?;
+            }
+        }
+        if let Some((current_branch, desired_branch)) = plan.default_branch {
+            let branches_url = format!("{}/branches", repo_url);
+            let exists = self.get_optional(&format!("{}/{}", branches_url, encode_path_segment(&desired_branch))).await?.is_some();
+            if exists {
+                if dry_run {
+                    info!("[Dry Run] Would switch default branch of {}/{} from {} to existing branch {}", self.org, repo.name, current_branch, desired_branch);
+                } else {
+                    info!("Switching default branch of {}/{} from {} to {}", self.org, repo.name, current_branch, desired_branch);
+                    self.send_patch(&repo_url, json!({ "default_branch": desired_branch })).await?;
+                }
+            } else {
+                check_default_branch_rename(repo, &current_branch, &desired_branch)?;
+                if dry_run {
+                    info!("[Dry Run] Would rename default branch {} of {}/{} to {}", current_branch, self.org, repo.name, desired_branch);
+                } else {
+                    info!("Renaming default branch {} of {}/{} to {}", current_branch, self.org, repo.name, desired_branch);
+                    self.send_post(&format!("{}/{}/rename", branches_url, encode_path_segment(&current_branch)), json!({ "new_name": desired_branch })).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Update repository settings on GitHub to match the desired configuration.
+    ///
+    /// This method compares the current repository settings with the desired settings and applies only the
+    /// necessary changes using the appropriate GitHub API endpoints. The mapping table determines which
+    /// settings correspond to which API endpoints and JSON fields.
+    ///
+    /// # Arguments
+    /// * `repo` - The repository whose settings should be updated.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(repo)` with the repository as read before the update, if all updates succeed or are
+    ///   skipped in dry-run mode.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    /// # Behavior
+    /// - Only settings that differ from the current state are updated.
+    /// - Supports PATCH, PUT, and POST methods as defined in the mapping.
+    /// - Logs actions in dry-run mode instead of performing them.
+    ///
+    pub async fn update_repo_settings(&self, repo: &Repo, dry_run: bool) -> AppResult<serde_json::Value> {
+        let desired = &repo.settings;
+        let mapping = get_github_api_mapping();
+        // Responses of the read endpoints, fetched once each. `None` if the endpoint returned 404, e.g.
+        // because the feature isn't set up yet, in which case the setting is sent.
+        let mut read_responses: HashMap<String, Option<serde_json::Value>> = HashMap::new();
+        // The repo itself is always read, which also reports a missing repo.
+        let repo_url = repo_mapping_url("/repos/{owner}/{repo}", &self.org, &repo.name);
+        let repo_json: serde_json::Value = self.get(&repo_url).await?.json().await?;
+        read_responses.insert(repo_url, Some(repo_json.clone()));
+
+        // --- Optimization suggestion (Apply after fixing URL): ---
+        // Instead of sending one PATCH per setting, collect all PATCHes for the same endpoint.
+        // Create a HashMap<String, serde_json::Map<String, serde_json::Value>> where the key is the endpoint URL.
+        let mut pending_updates: HashMap<String, (String, serde_json::Map<String, serde_json::Value>)> = HashMap::new();
+        // The tuple stores (HTTP Method, Body Map)
+
+        for (k, v_desired) in desired.iter() {
+            if VISIBILITY_SETTING_KEYS.contains(&k.as_str()) {
+                warn!("Ignoring '{}' in settings for repo {}; use the top-level 'visibility' field instead.", k, repo.name);
+                continue;
+            }
+            let typed = match k.as_str() {
+                "description" => repo.description.is_some(),
+                "homepage" => repo.homepage.is_some(),
+                "topics" => repo.topics.is_some(),
+                "default_branch" => repo.default_branch.is_some(),
+                _ => false,
+            };
+            if typed {
+                warn!("Ignoring '{}' in settings for repo {}; the top-level '{}' field takes precedence.", k, repo.name, k);
+                continue;
+            }
+            if let Some(field_map) = mapping.get(k.as_str()) {
+                if let Some((_, block)) = BLOCK_ENDPOINTS.iter().find(|(endpoint, _)| field_map.endpoint.contains(endpoint)) {
+                    warn!("Ignoring '{}' in settings for repo {}; use the '{}' block instead.", k, repo.name, block);
+                    continue;
+                }
+                let v_desired_json = serde_json::to_value(v_desired).unwrap_or(serde_json::Value::Null);
+                let v_current = match repo_mapping_read_url(field_map, &self.org, &repo.name) {
+                    Some(read_url) => {
+                        if !read_responses.contains_key(&read_url) {
+                            let response = match self.get_optional(&read_url).await? {
+                                Some(response) => Some(response.json().await?),
+                                None => {
+                                    debug!("{} not found; sending the settings it reads.", read_url);
+                                    None
+                                }
+                            };
+                            read_responses.insert(read_url.clone(), response);
+                        }
+                        read_responses[&read_url].as_ref().and_then(|body| json_path_lookup(body, field_map.read_json_path))
+                    }
+                    None => {
+                        debug!("Repo setting '{}' can't be read back; sending it.", k);
+                        None
+                    }
+                };
+                if v_current != Some(&v_desired_json) {
+                    // Construct the *full* URL for this *specific* setting's mapped endpoint
+                    let full_url = repo_mapping_url(field_map.endpoint, &self.org, &repo.name);
+
+                    // Optimization: Group updates by full_url and method
+                    let (_, body_map) = pending_updates
+                         .entry(full_url.clone()) // Group by the calculated full URL
+                         .or_insert_with(|| (field_map.method.to_string(), serde_json::Map::new())); // Store method and init body map
+
+                    // Add the current setting change to the body map for this URL
+                     body_map.insert(
+                        field_map.json_path.to_string(), // Use the key expected by the API
+                        v_desired_json,
+                    );
+                }
+            } else {
+                debug!("No API mapping for repo setting '{}', skipping.", k);
+            }
+        }
+
+
+        // --- Apply Grouped Updates ---
+        for (full_url, (method, body_map)) in pending_updates {
+             if body_map.is_empty() { continue; } // Skip if no changes ended up for this group
+
+             let body = serde_json::Value::Object(body_map);
+
+             if dry_run {
+                 info!(
+                     "[Dry Run] Would {} {} with body: {:?}",
+                     method, full_url, body // Log the full URL
+                 );
+             } else {
+                 debug!("{} {} with body: {:?}", method, full_url, body); // Log the full URL
+                 match method.as_str() { // Use the stored method string
+                     "PATCH" => self.send_patch(&full_url, body).await?, // Pass the FULL URL
+                     "PUT" => self.send_put(&full_url, Some(body)).await?, // Pass the FULL URL
+                     "POST" => self.send_post(&full_url, body).await?, // Pass the FULL URL
+                     _ => error!("Unsupported HTTP method: {}", method),
+                 }
+                 info!("Applied relevant settings changes for repo {} via {}", repo.name, full_url); // Add success log
+             }
+        }
+
+
+        Ok(repo_json)
+    }
+
+    /// List the organization's repositories as raw API objects.
+    async fn list_org_repos(&self) -> AppResult<Vec<serde_json::Value>> {
+        let repo_url = format!("{}/orgs/{}/repos?per_page=100", GITHUB_API_BASE_URL, self.org);
+        self.get_all(&repo_url).await
+    }
+
+    ///
+    /// Rename or transfer a repository according to its `previous_names` and `transfer_to` fields.
+    ///
+    /// # Arguments
+    /// * `repo` - The repository as configured.
+    /// * `existing` - The organization's current repositories, keyed by name.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(RepoLifecycle::Continue(name))` with the name the repo should be addressed by from now on.
+    ///   In dry-run mode this is still the old name, since the rename has not happened.
+    /// * `Ok(RepoLifecycle::Transferred)` if the repo has left the organization.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    async fn apply_repo_lifecycle(&self, repo: &Repo, existing: &HashMap<String, serde_json::Value>, dry_run: bool) -> AppResult<RepoLifecycle> {
+        let transfer_target = repo.transfer_to.as_deref().filter(|target| *target != self.org);
+        let Some(current_name) = resolve_current_repo_name(repo, |name| existing.contains_key(name)) else {
+            if let Some(target) = transfer_target {
+                info!("Repo {} not found in {}; assuming it was already transferred to {}", repo.name, self.org, target);
+                return Ok(RepoLifecycle::Transferred);
+            }
+            // Let the settings sync report the missing repo.
+            return Ok(RepoLifecycle::Continue(repo.name.clone()));
+        };
+
+        if let Some(target) = transfer_target {
+            let url = format!("{}/repos/{}/{}/transfer", GITHUB_API_BASE_URL, self.org, current_name);
+            let mut body = json!({ "new_owner": target });
+            if current_name != repo.name {
+                body["new_name"] = json!(repo.name);
+            }
+            if dry_run {
+                info!("[Dry Run] Would transfer {}/{} to {} with body: {:?}", self.org, current_name, target, body);
+            } else {
+                info!("Transferring {}/{} to {}", self.org, current_name, target);
+                self.send_post(&url, body).await?;
+            }
+            return Ok(RepoLifecycle::Transferred);
+        }
+
+        if current_name != repo.name {
+            if dry_run {
+                info!("[Dry Run] Would rename {}/{} -> {}", self.org, current_name, repo.name);
+                return Ok(RepoLifecycle::Continue(current_name.to_string()));
+            }
+            let url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, current_name);
+            info!("Renaming {}/{} -> {}", self.org, current_name, repo.name);
+            self.send_patch(&url, json!({ "name": repo.name })).await?;
+        }
+        Ok(RepoLifecycle::Continue(repo.name.clone()))
+    }
+
+    /// Archive or unarchive a repository.
+    async fn set_repo_archived(&self, repo_name: &str, archived: bool, dry_run: bool) -> AppResult<()> {
+        let action = if archived { "archive" } else { "unarchive" };
+        if dry_run {
+            info!("[Dry Run] Would {} {}/{}", action, self.org, repo_name);
+            return Ok(());
+        }
+        let url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name);
+        info!("Applying {} to {}/{}", action, self.org, repo_name);
+        self.send_patch(&url, json!({ "archived": archived })).await
+    }
+
+    ///
+    /// Change a repository's visibility to match the desired configuration.
+    ///
+    /// Making a repository public is refused unless `allow_visibility_change` is set, so a typo in the
+    /// config can never expose a private or internal repository.
+    ///
+    /// # Arguments
+    /// * `repo` - The repository whose visibility should be applied.
+    /// * `allow_visibility_change` - Confirms that transitions to `public` may be applied.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(())` if visibility already matches, is unmanaged, or was changed successfully.
+    /// * `Err(AppError::Validation)` if the repo would become public without confirmation.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    pub async fn update_repo_visibility(&self, repo: &Repo, allow_visibility_change: bool, dry_run: bool) -> AppResult<()> {
+        let Some(desired) = repo.visibility else {
+            return Ok(());
+        };
+        let current = self.get_repo_visibility(&repo.name).await?;
+        if current == desired {
+            debug!("Visibility for {}/{} already {}", self.org, repo.name, desired);
+            return Ok(());
+        }
+        check_visibility_transition(&repo.name, current, desired, allow_visibility_change)?;
+
+        if dry_run {
+            info!("[Dry Run] Would change visibility of {}/{}: {} -> {}", self.org, repo.name, current, desired);
+        } else {
+            let full_url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo.name);
+            info!("Changing visibility of {}/{}: {} -> {}", self.org, repo.name, current, desired);
+            self.send_patch(&full_url, json!({ "visibility": desired.as_str() })).await?;
+        }
+        Ok(())
+    }
+
+        // Add a helper for DELETE
+    async fn send_delete(&self, url: &str) -> AppResult<()> {
+        self.send_delete_with_body(url, None).await
+    }
+
+    async fn send_delete_with_body(&self, url: &str, body: Option<serde_json::Value>) -> AppResult<()> {
+        debug!("Attempting to build DELETE request for URL: '{}'", url);
+        // Add token/URL checks if desired
+        let mut request = self
+            .client
+            .delete(url)
+            .header(reqwest::header::AUTHORIZATION, format!("Bearer {}", self.token))
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .header(reqwest::header::USER_AGENT, "gh-config");
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request.send().await?;
+
+        let status = response.status();
+        debug!("DELETE {} returned status: {}", url, status);
+        // DELETE for membership returns 204 No Content on success
+        if status.is_success() || status == reqwest::StatusCode::NO_CONTENT {
+            Ok(())
+        } else {
+            let text = response.text().await?;
+            error!("DELETE {} failed with status {}: {}", url, status, text);
+            Err(AppError::GitHubApi(text))
+        }
+    }
+
+    // Add a helper to get team members. `role` is "all", "member" or "maintainer".
+    async fn get_team_members(&self, team_slug: &str, role: &str) -> AppResult<HashSet<String>> {
+        let url = format!("https://api.github.com/orgs/{}/teams/{}/members?per_page=100&role={}", self.org, team_slug, role);
+        let members_json: Vec<serde_json::Value> = self.get_all(&url).await?;
+        let members = members_json.iter()
+            .filter_map(|m| m["login"].as_str().map(String::from))
+            .collect::<HashSet<String>>();
+        Ok(members)
+    }
+
+    /// Get every member of a team along with their team role ("member" or "maintainer").
+    async fn get_team_member_roles(&self, team_slug: &str) -> AppResult<HashMap<String, &'static str>> {
+        let members = self.get_team_members(team_slug, "all").await?;
+        let maintainers = self.get_team_members(team_slug, "maintainer").await?;
+        Ok(members
+            .into_iter()
+            .map(|login| {
+                let role = if maintainers.contains(&login) { TEAM_ROLE_MAINTAINER } else { TEAM_ROLE_MEMBER };
+                (login, role)
+            })
+            .collect())
+    }
+
+    /// Add a user to a team, or change their role if they are already on it.
+    async fn put_team_membership(&self, team_slug: &str, login: &str, role: &str) -> AppResult<()> {
+        let member_url = format!("https://api.github.com/orgs/{}/teams/{}/memberships/{}", self.org, team_slug, login);
+        self.send_put(&member_url, Some(json!({ "role": role }))).await
+    }
+
+    ///
+    /// Update an existing team's name, description, privacy, parent team and notification setting
+    /// where they drift from the config. Returns the team's slug afterwards, which changes on rename.
+    ///
+    /// # Arguments
+    /// * `team` - The team as configured.
+    /// * `current` - The team as currently returned by GitHub.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    async fn update_team_properties(&self, team: &Team, current: &TeamResponse, dry_run: bool) -> AppResult<String> {
+        let mut changes = team_property_changes(team, current);
+        if let Some(parent) = &team.parent {
+            if !current.parent.as_ref().is_some_and(|p| team_ref_matches(parent, &p.name, &p.slug)) {
+                match self.find_team(parent).await? {
+                    Some(parent_team) => {
+                        changes.insert("parent_team_id".to_string(), json!(parent_team.id));
+                    }
+                    None if dry_run => info!("[Dry Run] Would move team {} under parent {} once it exists", current.slug, parent),
+                    None => return Err(AppError::GitHubApi(format!("Parent team '{}' of team '{}' not found", parent, current.slug))),
+                }
+            }
+        }
+        if changes.is_empty() {
+            debug!("Team {} properties already match config.", current.slug);
+            return Ok(current.slug.clone());
+        }
+
+        let renamed = changes.contains_key("name");
+        let body = serde_json::Value::Object(changes);
+        if dry_run {
+            if renamed {
+                info!("[Dry Run] Would rename team {} from '{}' to '{}'", current.slug, current.name, team.name);
+            }
+            info!("[Dry Run] Would update team {} with body: {:?}", current.slug, body);
+            return Ok(current.slug.clone());
+        }
+
+        let url = format!("{}/orgs/{}/teams/{}", GITHUB_API_BASE_URL, self.org, current.slug);
+        info!("Updating team {} properties", current.slug);
+        self.send_patch(&url, body).await?;
+        if !renamed {
+            return Ok(current.slug.clone());
+        }
+        // Renaming a team changes its slug
+        self.forget_teams();
+        let new_slug = self.resolve_team_slug(&team.name).await?;
+        warn!("Team '{}' was renamed and its slug is now '{}'; update 'slug' in the config to match.", team.name, new_slug);
+        Ok(new_slug)
+    }
+
+    ///
+    /// Create a team in the GitHub organization and add members.
+    ///
+    /// If the team already exists, ensures all specified members are present.
+    ///
+    /// # Arguments
+    /// * `team` - The team to create or update.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the team is created/updated successfully or in dry-run mode.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    pub async fn create_team(&self, team: &Team, dry_run: bool) -> AppResult<()> {
+        let url_create = format!("https://api.github.com/orgs/{}/teams", self.org); // For POST
+
+        let existing_team = self.find_configured_team(team).await?;
+
+        if existing_team.is_none() {
+            // GitHub derives the slug from the name; it's only known for sure after creation
+            let mut team_slug = team.slug.clone().unwrap_or_else(|| slugify_team_name(&team.name));
+            // --- Create Team ---
+            let mut body = json!({
+                "name": team.name, // Use team.name for creation
+                "privacy": team.privacy.unwrap_or(TeamPrivacy::Closed).as_str(),
+            });
+            if let Some(description) = &team.description {
+                body["description"] = json!(description);
+            }
+            if let Some(notification_setting) = team.notification_setting {
+                body["notification_setting"] = json!(notification_setting.as_str());
+            }
+            if let Some(parent) = &team.parent {
+                match self.find_team(parent).await? {
+                    Some(parent_team) => body["parent_team_id"] = json!(parent_team.id),
+                    None if dry_run => info!("[Dry Run] Parent team {} of {} does not exist yet", parent, team_slug),
+                    None => return Err(AppError::GitHubApi(format!("Parent team '{}' of team '{}' not found", parent, team_slug))),
+                }
+            }
+            let desired_roles = desired_team_roles(team, &HashMap::new());
+            if dry_run {
+                info!("[Dry Run] Would create team {} with body: {:?}", team_slug, body);
+                for (member, role) in &desired_roles {
+                   info!("[Dry Run] Would add {} to team {} as {}", member, team_slug, role);
+                }
+            } else {
+                info!("Creating team: {}", team.name);
+                self.send_post(&url_create, body).await?;
+                self.forget_teams();
+                team_slug = self.resolve_team_slug(&team.name).await?;
+                if team.slug.as_ref().is_some_and(|configured| *configured != team_slug) {
+                    warn!("Team '{}' was created with slug '{}'; update 'slug' in the config to match.", team.name, team_slug);
+                }
+                for (member, role) in &desired_roles {
+                    // Use PUT for initial add too, it works as add/update
+                    match self.put_team_membership(&team_slug, member, role).await {
+                        Ok(()) => info!("Added {} to new team {} as {}", member, team_slug, role),
+                        Err(e) => error!("Failed to add {} to new team {}: {}", member, team_slug, e),
+                    }
+                }
+            }
+        } else if let Some(current) = existing_team {
+             let team_slug = &self.update_team_properties(team, &current, dry_run).await?;
+
+             // --- Update Existing Team Members ---
+             info!("Team {} already exists, syncing members", team_slug);
+
+             let github_roles = self.get_team_member_roles(team_slug).await?;
+             let desired_roles = desired_team_roles(team, &github_roles);
+             let (memberships_to_put, members_to_remove) = plan_team_memberships(&desired_roles, &github_roles);
+
+             for (member, role) in &memberships_to_put {
+                 let action = match github_roles.get(member.as_str()) {
+                     Some(current_role) => format!("change role of {} in team {}: {} -> {}", member, team_slug, current_role, role),
+                     None => format!("add {} to team {} as {}", member, team_slug, role),
+                 };
+                if dry_run {
+                    info!("[Dry Run] Would {}", action);
+                } else {
+                    match self.put_team_membership(team_slug, member, role).await {
+                         Ok(()) => info!("Done: {}", action),
+                         Err(e) => error!("Failed to {}: {}", action, e),
+                     }
+                }
+             }
+
+             for member in &members_to_remove {
+                  let member_url = format!("https://api.github.com/orgs/{}/teams/{}/memberships/{}", self.org, team_slug, member);
+                 if dry_run {
+                     info!("[Dry Run] Would remove {} from team {}", member, team_slug);
+                 } else {
+                     match self.send_delete(&member_url).await {
+                         Ok(()) => info!("Removed {} from team {}", member, team_slug),
+                         Err(e) => error!("Failed to remove {} from team {}: {}", member, team_slug, e),
+                     }
+                 }
+             }
+
+              if memberships_to_put.is_empty() && members_to_remove.is_empty() {
+                   debug!("Team {} members already match config.", team_slug);
+              }
+        }
+        Ok(())
+    }
+
+    /// Get the organization's members, keyed by lowercase login.
+    async fn get_org_members(&self) -> AppResult<BTreeMap<String, User>> {
+        let list = |role: &str| format!("{}/orgs/{}/members?role={}&per_page=100", GITHUB_API_BASE_URL, self.org, role);
+        let admins: Vec<LoginResponse> = self.get_all(&list("admin")).await?;
+        let admins: HashSet<String> = admins.into_iter().map(|a| a.login).collect();
+        let members: Vec<LoginResponse> = self.get_all(&list("all")).await?;
+        Ok(members
+            .into_iter()
+            .map(|m| {
+                let role = if admins.contains(&m.login) { "admin" } else { "member" };
+                (m.login.to_lowercase(), User { role: role.to_string(), login: m.login, ..Default::default() })
+            })
+            .collect())
+    }
+
+    /// List the organization's pending invitations.
+    async fn get_org_invitations(&self) -> AppResult<Vec<OrgInvitationResponse>> {
+        let url = format!("{}/orgs/{}/invitations?per_page=100", GITHUB_API_BASE_URL, self.org);
+        self.get_all(&url).await
+    }
+
+    /// Get the login of the user the token belongs to.
+    async fn get_authenticated_login(&self) -> AppResult<String> {
+        let url = format!("{}/user", GITHUB_API_BASE_URL);
+        let user: UserResponse = self.get(&url).await?.json().await.map_err(AppError::Http)?;
+        Ok(user.login)
+    }
+
+    /// Invite a user to the organization, adding them to their configured teams once they accept.
+    async fn invite_user_to_org(&self, user: &User) -> AppResult<()> {
+        if user.login.is_empty() || !user.teams.is_empty() {
+            let mut team_ids = Vec::new();
+            for reference in &user.teams {
+                let team = self.find_team(reference).await?.ok_or_else(|| {
+                    AppError::Validation(format!("Team '{}' for invitation of {} does not exist", reference, user.label()))
+                })?;
+                team_ids.push(team.id);
+            }
+            let mut body = json!({ "role": invitation_role(&user.role), "team_ids": team_ids });
+            if user.login.is_empty() {
+                body["email"] = json!(user.email);
+            } else {
+                let url = format!("{}/users/{}", GITHUB_API_BASE_URL, user.login);
+                let invitee: UserResponse = self.get(&url).await?.json().await.map_err(AppError::Http)?;
+                body["invitee_id"] = json!(invitee.id);
+            }
+            let url = format!("{}/orgs/{}/invitations", GITHUB_API_BASE_URL, self.org);
+            self.send_post(&url, body).await?;
+        } else {
+            let url = format!("{}/orgs/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, user.login);
+            self.send_put(&url, Some(json!({ "role": user.role }))).await?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Bring the organization's membership and pending invitations in line with the configured users.
+    ///
+    /// Users who are neither members nor invited are invited, by login or by email, and roles are
+    /// updated for members and pending invitations alike.
+    ///
+    /// # Arguments
+    /// * `users` - The users that should belong to the organization.
+    /// * `prune` - If true, members and invitations not in `users` are removed or cancelled.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all changes succeed or are skipped in dry-run mode.
+    /// * `Err(AppError)` if any API call fails or a user has neither a login nor an email.
+    ///
+    pub async fn sync_org_memberships(&self, users: &[User], prune: bool, dry_run: bool) -> AppResult<()> {
+        if let Some(user) = users.iter().find(|u| u.login.is_empty() && u.email.is_none()) {
+            return Err(AppError::Validation(format!("User with role '{}' needs a login or an email", user.role)));
+        }
+        let members = self.get_org_members().await?;
+        let invitations = self.get_org_invitations().await?;
+        let protected = if prune { Some(self.get_authenticated_login().await?) } else { None };
+
+        for change in plan_membership_changes(users, &members, &invitations, prune, protected.as_deref()) {
+            let prefix = if dry_run { "[Dry Run] Would" } else { "Will" };
+            match change {
+                MembershipChange::SetRole(login, role) => {
+                    info!("{} update {} role to {}", prefix, login, role);
+                    if !dry_run {
+                        let url = format!("{}/orgs/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, login);
+                        self.send_put(&url, Some(json!({ "role": role }))).await?;
+                    }
+                }
+                MembershipChange::Invite(user) => {
+                    info!("{} invite {} with role {}", prefix, user.label(), user.role);
+                    if !dry_run {
+                        self.invite_user_to_org(&user).await?;
+                    }
+                }
+                MembershipChange::CancelInvitation(id, label) => {
+                    info!("{} cancel invitation for {}", prefix, label);
+                    if !dry_run {
+                        let url = format!("{}/orgs/{}/invitations/{}", GITHUB_API_BASE_URL, self.org, id);
+                        self.send_delete(&url).await?;
+                    }
+                }
+                MembershipChange::Remove(login) => {
+                    info!("{} remove {} from {}", prefix, login, self.org);
+                    if !dry_run {
+                        let url = format!("{}/orgs/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, login);
+                        self.send_delete(&url).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Revoke a single access grant of `login`.
+    async fn revoke_access(&self, login: &str, grant: &AccessGrant) -> AppResult<()> {
+        match grant {
+            AccessGrant::Collaborator { repo, .. } => {
+                let remaining: Vec<Collaborator> = self
+                    .get_repo_collaborators_with_invitations(repo)
+                    .await?
+                    .into_iter()
+                    .filter(|c| !c.login.eq_ignore_ascii_case(login))
+                    .collect();
+                self.manage_collaborators(repo, &remaining, true, false).await
+            }
+            AccessGrant::EnvironmentReviewer { repo, environment } => {
+                let environments = self.get_environments(repo).await?;
+                let Some(mut environment) = environments.into_iter().find(|e| e.name == *environment) else {
+                    return Ok(());
+                };
+                environment.reviewers.users.retain(|u| !u.eq_ignore_ascii_case(login));
+                self.put_environment(repo, &environment).await
+            }
+            AccessGrant::Team { slug, .. } => {
+                let url = format!("{}/orgs/{}/teams/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, slug, login);
+                self.send_delete(&url).await
+            }
+            AccessGrant::OutsideCollaborator => {
+                let url = format!("{}/orgs/{}/outside_collaborators/{}", GITHUB_API_BASE_URL, self.org, login);
+                self.send_delete(&url).await
+            }
+            AccessGrant::OrgMember { pending: false, .. } => {
+                let url = format!("{}/orgs/{}/memberships/{}", GITHUB_API_BASE_URL, self.org, login);
+                self.send_delete(&url).await
+            }
+            AccessGrant::OrgMember { pending: true, .. } => {
+                let invitations = self.get_org_invitations().await?;
+                let matching = invitations.iter().filter(|i| i.login.as_deref().is_some_and(|l| l.eq_ignore_ascii_case(login)));
+                for invitation in matching {
+                    let url = format!("{}/orgs/{}/invitations/{}", GITHUB_API_BASE_URL, self.org, invitation.id);
+                    self.send_delete(&url).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    ///
+    /// Revoke every way a user has access to the organization and remove them from the local config.
+    ///
+    /// Access is found from org membership and invitations, team memberships, direct repo
+    /// collaborator grants, environment reviewers and outside collaborator status. Any listing that
+    /// fails aborts the offboarding, so no grant is missed. The plan is printed before anything is
+    /// revoked.
+    ///
+    /// # Arguments
+    /// * `config_path` - Path to the config file to remove the user from.
+    /// * `login` - The user to offboard.
+    /// * `dry_run` - If true, only the plan is printed; nothing is revoked and the config is not edited.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all access is revoked or in dry-run mode.
+    /// * `Err(AppError)` if any API call or config edit fails.
+    ///
+    pub async fn offboard(&self, config_path: &str, login: &str, dry_run: bool) -> AppResult<()> {
+        let github_config = self.get_access_config().await?;
+        let grants = find_user_access(&github_config, login);
+
+        if grants.is_empty() {
+            println!("{} has no access to {}.", login, self.org);
+        } else {
+            println!("Access to revoke for {} in {}:", login, self.org);
+            for grant in &grants {
+                println!("  - {}", grant);
+            }
+        }
+
+        for grant in &grants {
+            if dry_run {
+                info!("[Dry Run] Would revoke {} for {}", grant, login);
+            } else {
+                info!("Revoking {} for {}", grant, login);
+                self.revoke_access(login, grant).await?;
+            }
+        }
+
+        let removed = Config::remove_login_from_file(config_path, login, dry_run)?;
+        if removed == 0 {
+            println!("{} is not referenced in {}.", login, config_path);
+        } else if dry_run {
+            println!("Dry run: Would remove {} references to {} from {}.", removed, login, config_path);
+        } else {
+            println!("Removed {} references to {} from {}.", removed, login, config_path);
+            println!("Note: {} was rewritten as plain YAML, so its comments were not preserved.", config_path);
+        }
+        Ok(())
+    }
+
+    /// Fetch everything that grants access to the org: each repo's collaborators and environment
+    /// reviewers, team memberships, outside collaborators and org members. Unlike `sync-from-org`,
+    /// every fetch error is returned rather than logged and skipped.
+    async fn get_access_config(&self) -> AppResult<Config> {
+        let mut repos = Vec::new();
+        for repo in self.list_org_repos().await? {
+            let Some(name) = repo["name"].as_str() else { continue };
+            repos.push(Repo {
+                name: name.to_string(),
+                collaborators: Some(self.get_repo_collaborators_with_invitations(name).await?),
+                environments: Some(self.get_environments(name).await?),
+                ..Default::default()
+            });
+        }
+        let mut teams = Vec::new();
+        for team in self.list_org_teams().await? {
+            let roles = self.get_team_member_roles(&team.slug).await?;
+            teams.push(team_from_response(team, &roles));
+        }
+        Ok(Config {
+            org: self.org.clone(),
+            repos,
+            teams,
+            users: self.get_org_users().await?,
+            outside_collaborators: Some(self.get_outside_collaborators().await?),
+            ..Default::default()
+        })
+    }
+
+    /// The organization's members and pending invitations as configured users.
+    async fn get_org_users(&self) -> AppResult<Vec<User>> {
+        let mut users: Vec<User> = self.get_org_members().await?.into_values().collect();
+        for invitation in self.get_org_invitations().await? {
+            users.push(User {
+                login: invitation.login.unwrap_or_default(),
+                role: org_role_from_invitation(&invitation.role).to_string(),
+                email: invitation.email,
+                state: Some(MembershipState::Pending),
+                ..Default::default()
+            });
+        }
+        Ok(users)
+    }
+
+    /// Get a repo's direct collaborators, keyed by lowercase login.
+    async fn get_repo_collaborators(&self, repo_name: &str) -> AppResult<BTreeMap<String, Collaborator>> {
+        let url = format!("{}/repos/{}/{}/collaborators?affiliation=direct&per_page=100", GITHUB_API_BASE_URL, self.org, repo_name);
+        let collaborators: Vec<CollaboratorResponse> = self.get_all(&url).await?;
+        Ok(collaborators
+            .into_iter()
+            .filter_map(|c| {
+                let permission = permission_from_role(c.role_name.as_deref(), &c.permissions)?;
+                Some((c.login.to_lowercase(), Collaborator { login: c.login, permission }))
+            })
+            .collect())
+    }
+
+    /// Get a repo's pending collaborator invitations, keyed by lowercase invitee login.
+    async fn get_repo_invitations(&self, repo_name: &str) -> AppResult<BTreeMap<String, (i64, Collaborator)>> {
+        let url = format!("{}/repos/{}/{}/invitations?per_page=100", GITHUB_API_BASE_URL, self.org, repo_name);
+        let invitations: Vec<InvitationResponse> = self.get_all(&url).await?;
+        Ok(invitations
+            .into_iter()
+            .filter_map(|i| {
+                let login = i.invitee?.login;
+                Some((login.to_lowercase(), (i.id, Collaborator { login, permission: Permission::from(i.permissions) })))
+            })
+            .collect())
+    }
+
+    /// Get a repo's direct collaborators, counting pending invitations as collaborators, sorted by login.
+    async fn get_repo_collaborators_with_invitations(&self, repo_name: &str) -> AppResult<Vec<Collaborator>> {
+        let mut collaborators = self.get_repo_collaborators(repo_name).await?;
+        for (key, (_, invited)) in self.get_repo_invitations(repo_name).await? {
+            collaborators.entry(key).or_insert(invited);
+        }
+        Ok(collaborators.into_values().collect())
+    }
+
+    ///
+    /// Bring a repository's direct collaborators and pending invitations in line with the config.
+    ///
+    /// # Arguments
+    /// * `repo_name` - The name of the repository.
+    /// * `desired` - The collaborators the repository should have.
+    /// * `prune` - If true, collaborators and invitations that aren't listed are removed.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all changes succeed or are skipped in dry-run mode.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    pub async fn manage_collaborators(&self, repo_name: &str, desired: &[Collaborator], prune: bool, dry_run: bool) -> AppResult<()> {
+        let active = self.get_repo_collaborators(repo_name).await?;
+        let pending = self.get_repo_invitations(repo_name).await?;
+        let changes = plan_collaborator_changes(desired, &active, &pending, prune);
+        if changes.is_empty() {
+            debug!("Collaborators for {}/{} already match config.", self.org, repo_name);
+        }
+
+        let repo_url = format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, self.org, repo_name);
+        for change in changes {
+            let prefix = if dry_run { "[Dry Run] Would" } else { "Will" };
+            match change {
+                CollaboratorChange::Grant(login, permission) => {
+                    info!("{} grant {} {} on {}/{}", prefix, login, permission, self.org, repo_name);
+                    if !dry_run {
+                        let url = format!("{}/collaborators/{}", repo_url, login);
+                        self.send_put(&url, Some(json!({ "permission": permission.api_value() }))).await?;
+                    }
+                }
+                CollaboratorChange::UpdateInvitation(id, login, permission) => {
+                    info!("{} change pending invitation of {} on {}/{} to {}", prefix, login, self.org, repo_name, permission);
+                    if !dry_run {
+                        let url = format!("{}/invitations/{}", repo_url, id);
+                        self.send_patch(&url, json!({ "permissions": permission.as_str() })).await?;
+                    }
+                }
+                CollaboratorChange::Remove(login) => {
+                    info!("{} remove collaborator {} from {}/{}", prefix, login, self.org, repo_name);
+                    if !dry_run {
+                        self.send_delete(&format!("{}/collaborators/{}", repo_url, login)).await?;
+                    }
+                }
+                CollaboratorChange::CancelInvitation(id, login) => {
+                    info!("{} cancel pending invitation of {} to {}/{}", prefix, login, self.org, repo_name);
+                    if !dry_run {
+                        self.send_delete(&format!("{}/invitations/{}", repo_url, id)).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// List the logins of the organization's outside collaborators.
+    async fn get_outside_collaborators(&self) -> AppResult<Vec<String>> {
+        let url = format!("{}/orgs/{}/outside_collaborators?per_page=100", GITHUB_API_BASE_URL, self.org);
+        let collaborators: Vec<LoginResponse> = self.get_all(&url).await?;
+        let mut logins: Vec<String> = collaborators.into_iter().map(|c| c.login).collect();
+        logins.sort();
+        Ok(logins)
+    }
+
+    ///
+    /// Remove outside collaborators that are not listed in the config from all of the organization's
+    /// repositories. Only called with `--prune collaborators`.
+    ///
+    /// # Arguments
+    /// * `allowed` - Logins permitted to be outside collaborators.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    pub async fn sync_outside_collaborators(&self, allowed: &[String], dry_run: bool) -> AppResult<()> {
+        let allowed: HashSet<String> = allowed.iter().map(|l| l.to_lowercase()).collect();
+        for login in self.get_outside_collaborators().await? {
+            if allowed.contains(&login.to_lowercase()) {
+                continue;
+            }
+            if dry_run {
+                info!("[Dry Run] Would remove outside collaborator {} from all {} repos", login, self.org);
+            } else {
+                info!("Removing outside collaborator {} from all {} repos", login, self.org);
+                let url = format!("{}/orgs/{}/outside_collaborators/{}", GITHUB_API_BASE_URL, self.org, login);
+                self.send_delete(&url).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// List the names of the organization's custom repository roles.
+    async fn get_custom_repository_roles(&self) -> AppResult<HashSet<String>> {
+        Ok(self.get_custom_repository_role_bases().await?.into_keys().collect())
+    }
+
+    /// Map each of the organization's custom repository roles to the built-in role it extends.
+    async fn get_custom_repository_role_bases(&self) -> AppResult<HashMap<String, Permission>> {
+        let url = format!("{}/orgs/{}/custom-repository-roles", GITHUB_API_BASE_URL, self.org);
+        // Custom roles are an Enterprise feature; without them every role is built in.
+        let Some(response) = self.get_optional(&url).await? else {
+            return Ok(HashMap::new());
+        };
+        let roles: CustomRepositoryRolesResponse = response.json().await.map_err(AppError::Http)?;
+        Ok(roles
+            .custom_roles
+            .into_iter()
+            .map(|r| {
+                let base = Permission::from(r.base_role.unwrap_or_else(|| "read".to_string()));
+                (r.name, base)
+            })
+            .collect())
+    }
+
+    /// Get the permission organization members have on every repository, if any.
+    async fn get_org_base_permission(&self) -> AppResult<Option<Permission>> {
+        let url = format!("{}/orgs/{}", GITHUB_API_BASE_URL, self.org);
+        let org: OrgResponse = self.get(&url).await?.json().await.map_err(AppError::Http)?;
+        Ok(org.default_repository_permission.filter(|p| p != "none").map(Permission::from))
+    }
+
+    ///
+    /// Compute the effective access of every user on every repository and write it as a report.
+    ///
+    /// # Arguments
+    /// * `format` - The report format.
+    /// * `output` - File to write the report to; the report is printed if `None`.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the report was written.
+    /// * `Err(AppError)` if any API call or write fails.
+    ///
+    pub async fn access_report(&self, format: ReportFormat, output: Option<&str>) -> AppResult<()> {
+        let mut github_config = self.get_access_config().await?;
+        github_config.assignments = self.get_team_assignments(&github_config.teams).await?;
+        let base_permission = self.get_org_base_permission().await?;
+        let custom_bases = self.get_custom_repository_role_bases().await?;
+        let entries = compute_access_matrix(&github_config, base_permission.as_ref(), &custom_bases);
+        let report = render_access_matrix(&entries, format)?;
+        match output {
+            Some(path) => {
+                std::fs::write(path, report).map_err(AppError::Io)?;
+                println!("Wrote access report with {} entries to {}", entries.len(), path);
+            }
+            None => print!("{}", report),
+        }
+        Ok(())
+    }
+
+    ///
+    /// Check that every assignment and repo collaborator uses a built-in permission or an existing
+    /// custom repository role.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all permissions are valid.
+    /// * `Err(AppError::Validation)` listing every assignment and collaborator with an unknown permission.
+    ///
+    pub async fn validate_permissions(&self, config: &Config) -> AppResult<()> {
+        let collaborators = config.repos.iter().flat_map(|r| r.collaborators.iter().flatten());
+        let permissions = config.assignments.iter().map(|a| &a.permission).chain(collaborators.map(|c| &c.permission));
+        if !permissions.into_iter().any(|p| matches!(p, Permission::Custom(_))) {
+            return Ok(());
+        }
+        let custom_roles = self.get_custom_repository_roles().await?;
+        let mut problems = invalid_assignment_permissions(&config.assignments, &custom_roles);
+        problems.extend(invalid_collaborator_permissions(&config.repos, &custom_roles));
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::Validation(format!(
+                "{}. Valid permissions are read, triage, write, maintain, admin or a custom role name.",
+                problems.join("; ")
+            )))
+        }
+    }
+
+    ///
+    /// Assign a team to a repository with a specific permission level.
+    ///
+    /// If the team already has a different permission, updates it.
+    ///
+    /// # Arguments
+    /// * `assignment` - The assignment specifying team, repo, and permission.
+    /// * `dry_run` - If true, no changes are made; actions are logged for preview.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the assignment is applied successfully or in dry-run mode.
+    /// * `Err(AppError)` if any API call fails.
+    ///
+    pub async fn assign_team_to_repo(&self, assignment: &Assignment, dry_run: bool) -> AppResult<()> {
+        let team_slug = self.resolve_team_slug(&assignment.team).await?;
+        if dry_run {
+            let current_perm = self
+                .get_team_repo_permission(&team_slug, &assignment.repo)
+                .await?;
+            match current_perm {
+                Some(perm) if perm != assignment.permission => {
+                    info!(
+                        "[Dry Run] Would update {}/{} permission for team {}: {} -> {}",
+                        self.org, assignment.repo, assignment.team, perm, assignment.permission
+                    );
+                }
+                None => {
+                    info!(
+                        "[Dry Run] Would assign team {} to {}/{} with permission {}",
+                        assignment.team, self.org, assignment.repo, assignment.permission
+                    );
+                }
+                _ => debug!(
+                    "[Dry Run] No changes needed for team {} on {}/{}",
+                    assignment.team, self.org, assignment.repo
+                ),
+            }
+            Ok(())
+        } else {
+            let full_url = format!(
+                "{}/orgs/{}/teams/{}/repos/{}/{}",
+                GITHUB_API_BASE_URL, self.org, team_slug, self.org, assignment.repo
+            );
+            let body = json!({
+                "permission": assignment.permission.api_value()
+            });
+            info!(
+                "Assigning team {} to repo {} with permission {}",
+                assignment.team, assignment.repo, assignment.permission
+            );
+            self.send_put(&full_url, Some(body)).await?;
+            Ok(())
+        }
+    }
+
+    async fn get_team_repo_permission(&self, team: &str, repo: &str) -> AppResult<Option<Permission>> {
+        let full_url = format!(
+            "{}/orgs/{}/teams/{}/repos/{}/{}",
+            GITHUB_API_BASE_URL, self.org, team, self.org, repo
+        );
+        match self.get_with_accept(&full_url, REPOSITORY_MEDIA_TYPE).await {
+            Ok(response) => {
+                let text = response.text().await?;
+                if text.is_empty() {
+                    debug!("Empty response body from GET {}, assuming permission exists but not detailed", full_url);
+                    return Ok(Some(Permission::Write));
+                }
+                let perms: TeamRepoResponse = serde_json::from_str(&text)
+                    .map_err(|e| AppError::GitHubApi(format!("Failed to parse response from {}: {}", full_url, e)))?;
+                Ok(permission_from_response(&perms))
+            }
+            Err(AppError::GitHubApi(e)) if e.contains("404") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn generate_config_and_write(&self, config_path: &str, dry_run: bool) -> AppResult<()> {
+        info!("Generating config from GitHub org: {}", self.org);
+        let config = self.generate_unfiltered_config_from_org().await?;  // Make sure to call an unfiltered version
+
+        // ... rest of the YAML writing logic ...
+        // (Ensure this part uses the full, unfiltered config)
+
+         let mut yaml_content = String::new();
+         yaml_content.push_str(&format!("org: {}\n\n", config.org));
+
+         if !config.org_settings.is_empty() {
+             yaml_content.push_str("org_settings:\n");
+             for (key, value) in &config.org_settings {
+                 let val_str = serde_yaml::to_string(value).unwrap_or_default().trim().to_string();
+                 yaml_content.push_str(&format!("  {}: {}\n", key, val_str));
+             }
+             yaml_content.push('\n');
+         }
+
+         if !config.org_webhooks.is_empty() {
+             yaml_content.push_str("org_webhooks:\n");
+             for webhook in &config.org_webhooks {
+                 yaml_content.push_str(&webhook_yaml(webhook, "", true));
+             }
+             yaml_content.push('\n');
+         }
+
+         if let Some(actions) = &config.actions {
+             yaml_content.push_str(&nested_yaml("actions", actions, ""));
+             yaml_content.push('\n');
+         }
+         if let Some(custom_properties) = &config.custom_properties {
+             yaml_content.push_str(&nested_yaml("custom_properties", custom_properties, ""));
+             yaml_content.push('\n');
+         }
+         if let Some(configurations) = &config.code_security_configurations {
+             yaml_content.push_str(&nested_yaml("code_security_configurations", configurations, ""));
+             yaml_content.push('\n');
+         }
+         if let Some(secrets) = &config.secrets {
+             yaml_content.push_str(&actions_secrets_yaml(secrets, ""));
+             yaml_content.push('\n');
+         }
+         if let Some(variables) = &config.variables {
+             yaml_content.push_str(&actions_variables_yaml(variables, ""));
+             yaml_content.push('\n');
+         }
+
+         // Add assignments (sorted)
+         let mut assignments = config.assignments;
+         assignments.sort_by(|a, b| a.team.cmp(&b.team).then(a.repo.cmp(&b.repo)));
+         if !assignments.is_empty() {
+              yaml_content.push_str("assignments:\n");
+              for assignment in &assignments {
+                  yaml_content.push_str(&format!(
+                      "- repo: {}\n  team: {}\n  permission: {}\n",
+                      assignment.repo, assignment.team, assignment.permission
+                  ));
+              }
+              yaml_content.push('\n');
+         } else {
+             yaml_content.push_str("assignments: []\n\n");
+         }
+
+         // Add default webhook if present
+         if let Some(default_webhook) = &config.default_webhook {
+             yaml_content.push_str("default_webhook:\n");
+             yaml_content.push_str(&webhook_yaml(default_webhook, "  ", false));
+             yaml_content.push('\n');
+         }
+
+         // Add repos (sorted)
+         let mut repos = config.repos;
+         repos.sort_by(|a, b| a.name.cmp(&b.name));
+         if !repos.is_empty() {
+             yaml_content.push_str("repos:\n");
+             for repo in &repos {
+                 yaml_content.push_str(&format!("- name: {}\n", repo.name));
+
+                 // Only write settings actually fetched/relevant (potentially limited set here)
+                 if !repo.settings.is_empty() {
+                       yaml_content.push_str("  settings:\n");
+                       // Example: Write only specific known settings for cleaner output
+                       let keys_to_write = ["allow_merge_commit", "allow_squash_merge", "allow_rebase_merge"];
+                       let mut setting_keys: Vec<_> = repo.settings.keys().collect();
+                       setting_keys.sort(); // Sort keys within settings
+                       for key in setting_keys {
+                           if keys_to_write.contains(&key.as_str()) {
+                             if let Some(value) = repo.settings.get(key) {
+                                let val_str = serde_yaml::to_string(value).unwrap_or_default().trim().to_string();
+                                // Basic indentation and handling for simple values
+                                yaml_content.push_str(&format!("    {}: {}\n", key, val_str));
+                              }
+                           }
+                       }
+                 } else {
+                      // Still ensure settings key exists if empty
+                      yaml_content.push_str("  settings: {}\n");
+                 }
+
+
+                 if let Some(description) = &repo.description {
+                     yaml_content.push_str(&format!("  description: {}\n", yaml_scalar(description)));
+                 }
+                 if let Some(homepage) = &repo.homepage {
+                     yaml_content.push_str(&format!("  homepage: {}\n", yaml_scalar(homepage)));
+                 }
+                 if let Some(topics) = &repo.topics {
+                     yaml_content.push_str(&nested_yaml("topics", topics, "  "));
+                 }
+                 if let Some(default_branch) = &repo.default_branch {
+                     yaml_content.push_str(&format!("  default_branch: {}\n", yaml_scalar(default_branch)));
+                 }
+                 if let Some(visibility) = &repo.visibility {
+                     yaml_content.push_str(&format!("  visibility: {}\n", visibility));
+                 }
+                 if repo.archived == Some(true) {
+                     yaml_content.push_str("  archived: true\n");
+                 }
+                 if let Some(collaborators) = &repo.collaborators {
+                     yaml_content.push_str("  collaborators:\n");
+                     for collaborator in collaborators {
+                         yaml_content.push_str(&format!("  - login: {}\n    permission: {}\n", collaborator.login, yaml_scalar(collaborator.permission.as_str())));
+                     }
+                 }
+                 if let Some(deploy_keys) = &repo.deploy_keys {
+                     yaml_content.push_str(&nested_yaml("deploy_keys", deploy_keys, "  "));
+                 }
+                 // Write webhooks only if they aren't just the default (if default exists)
+                 if let Some(webhooks) = &repo.webhooks {
+                     let is_default = matches!((webhooks.as_slice(), &config.default_webhook), ([only], Some(default)) if only == default);
+                     if !is_default {
+                         yaml_content.push_str("  webhooks:\n");
+                         for webhook in webhooks {
+                             yaml_content.push_str(&webhook_yaml(webhook, "  ", true));
+                         }
+                     }
+                 }
+                 if let Some(labels) = &repo.labels {
+                     yaml_content.push_str(&nested_yaml("labels", labels, "  "));
+                 }
+                 if let Some(autolinks) = &repo.autolinks {
+                     yaml_content.push_str(&nested_yaml("autolinks", autolinks, "  "));
+                 }
+                 if let Some(milestones) = &repo.milestones {
+                     yaml_content.push_str(&nested_yaml("milestones", milestones, "  "));
+                 }
+                 if let Some(properties) = &repo.properties {
+                     yaml_content.push_str(&nested_yaml("properties", properties, "  "));
+                 }
+                 if let Some(security) = &repo.security {
+                     yaml_content.push_str(&nested_yaml("security", security, "  "));
+                 }
+                 if let Some(actions) = &repo.actions {
+                     yaml_content.push_str(&nested_yaml("actions", actions, "  "));
+                 }
+                 if let Some(secrets) = &repo.secrets {
+                     yaml_content.push_str(&actions_secrets_yaml(secrets, "  "));
+                 }
+                 if let Some(variables) = &repo.variables {
+                     yaml_content.push_str(&actions_variables_yaml(variables, "  "));
+                 }
+                 if let Some(environments) = &repo.environments {
+                     yaml_content.push_str(&environments_yaml(environments, "  "));
+                 }
+                // Add branch protections if needed
+                if !repo.branch_protections.is_empty() {
+                    // Serialize properly
+                }
+             }
+             yaml_content.push('\n');
+         }
+
+         // Add teams (sorted)
+         let mut teams = config.teams;
+         teams.sort_by(|a, b| a.name.cmp(&b.name));
+         if !teams.is_empty() {
+             yaml_content.push_str("teams:\n");
+             for team in &teams {
+                 yaml_content.push_str(&format!("- name: {}\n", yaml_scalar(&team.name)));
+                 if let Some(slug) = &team.slug {
+                     yaml_content.push_str(&format!("  slug: {}\n", slug));
+                 }
+                 if let Some(description) = &team.description {
+                     yaml_content.push_str(&format!("  description: {}\n", yaml_scalar(description)));
+                 }
+                 if let Some(privacy) = team.privacy {
+                     yaml_content.push_str(&format!("  privacy: {}\n", privacy));
+                 }
+                 if let Some(parent) = &team.parent {
+                     yaml_content.push_str(&format!("  parent: {}\n", parent));
+                 }
+                 if let Some(notification_setting) = team.notification_setting {
+                     yaml_content.push_str(&format!("  notification_setting: {}\n", notification_setting));
+                 }
+                 yaml_content.push_str("  members:\n");
+                 let mut members = team.members.clone();
+                 members.sort();
+                 for member in &members {
+                     yaml_content.push_str(&format!("  - {}\n", member));
+                 }
+                 if let Some(maintainers) = team.maintainers.as_ref().filter(|m| !m.is_empty()) {
+                     yaml_content.push_str("  maintainers:\n");
+                     let mut maintainers = maintainers.clone();
+                     maintainers.sort();
+                     for maintainer in &maintainers {
+                         yaml_content.push_str(&format!("  - {}\n", maintainer));
+                     }
+                 }
+             }
+             yaml_content.push('\n');
+         }
+
+         // Add users (sorted)
+         let mut users = config.users;
+         users.sort_by(|a, b| a.label().cmp(b.label()));
+         if !users.is_empty() {
+             yaml_content.push_str("users:\n");
+             for user in &users {
+                 if user.login.is_empty() {
+                     yaml_content.push_str(&format!("- email: {}\n", yaml_scalar(user.email.as_deref().unwrap_or_default())));
+                 } else {
+                     yaml_content.push_str(&format!("- login: {}\n", user.login));
+                     if let Some(email) = &user.email {
+                         yaml_content.push_str(&format!("  email: {}\n", yaml_scalar(email)));
+                     }
+                 }
+                 yaml_content.push_str(&format!("  role: {}\n", user.role));
+                 if let Some(state) = user.state {
+                     yaml_content.push_str(&format!("  state: {}\n", state));
+                 }
+             }
+             yaml_content.push('\n');
+         }
+
+         if let Some(outside_collaborators) = &config.outside_collaborators {
+             yaml_content.push_str("outside_collaborators:\n");
+             for login in outside_collaborators {
+                 yaml_content.push_str(&format!("- {}\n", login));
+             }
+             yaml_content.push('\n');
+         }
+
+         if dry_run {
+             println!("Dry run: Would write the following config to {}:\n{}", config_path, yaml_content);
+         } else {
+             println!("Writing generated config to {}", config_path);
+             let mut file = File::create(config_path).map_err(AppError::Io)?;
+             file.write_all(yaml_content.as_bytes()).map_err(AppError::Io)?;
+             println!("Config generation completed successfully.");
+         }
+        Ok(())
+    }
+
+    async fn generate_unfiltered_config_from_org(&self) -> AppResult<Config> {
+        // This is essentially the original logic of generate_config_from_org
+         let mut repos = Vec::new();
+        let repo_json = self.list_org_repos().await?;
+        let mut property_values = match self.get_property_values().await {
+            Ok(values) => values,
+            Err(e) => {
+                error!("Failed to fetch custom property values for {}: {}", self.org, e);
+                HashMap::new()
+            }
+        };
+
+        for repo in repo_json {
+            let name = repo["name"].as_str().ok_or_else(|| AppError::GitHubApi("Missing repo name".to_string()))?.to_string();
+            // Fetch *limited* settings relevant for generating a *manageable* config
+            let settings = match self.get_repo_settings(&name).await {
+                Ok(full_settings) => {
+                    let mut manageable_settings = RepoSettings::new();
+                    // Only include settings we typically manage
+                    let managed_keys = ["allow_merge_commit", "allow_squash_merge", "allow_rebase_merge"];
+                    for key in managed_keys {
+                         if let Some(value) = full_settings.get(key) {
+                             manageable_settings.insert(key.to_string(), value.clone());
+                         }
+                    }
+                     manageable_settings
+                }
+                Err(e) => {
+                   error!("Failed to fetch settings for repo {}: {}", name, e);
+                    RepoSettings::new() // Return empty settings on error
+                }
+            };
+
+            let visibility = Some(visibility_from_api(repo["visibility"].as_str(), repo["private"].as_bool().unwrap_or(false)));
+            let text = |key: &str| repo[key].as_str().filter(|v| !v.is_empty()).map(str::to_string);
+            let (description, homepage, default_branch) = (text("description"), text("homepage"), text("default_branch"));
+            let topics: Vec<String> = repo["topics"].as_array().into_iter().flatten().filter_map(|t| t.as_str().map(str::to_string)).collect();
+            let topics = Some(normalize_topics(&topics)).filter(|t| !t.is_empty());
+            let webhooks = self.get_webhooks(&name).await.unwrap_or_default(); // Handle potential error
+            let webhooks = Some(webhook_configs(webhooks)).filter(|w| !w.is_empty());
+
+            // Only archived repos are marked, so unarchived ones don't need an explicit `archived: false`.
+            let archived = repo["archived"].as_bool().filter(|archived| *archived);
+            let collaborators = match self.get_repo_collaborators_with_invitations(&name).await {
+                Ok(collaborators) if collaborators.is_empty() => None,
+                Ok(collaborators) => Some(collaborators),
+                Err(e) => {
+                    error!("Failed to fetch collaborators for repo {}: {}", name, e);
+                    None
+                }
+            };
+
+            let deploy_keys = match self.get_deploy_keys(&name).await {
+                Ok(keys) => Some(keys.into_iter().map(|k| DeployKey { title: k.title, key: Some(k.key), key_file: None, read_only: k.read_only }).collect::<Vec<_>>())
+                    .filter(|k| !k.is_empty()),
+                Err(e) => {
+                    error!("Failed to fetch deploy keys for repo {}: {}", name, e);
+                    None
+                }
+            };
+            let labels = self.export_repo_resources(&name).await;
+            let autolinks = self.export_repo_resources(&name).await;
+            let milestones = self.export_repo_resources(&name).await;
+            let properties = property_values
+                .remove(&name)
+                .filter(|values| !values.is_empty())
+                .map(|values| values.into_iter().filter_map(|(k, v)| Some((k, serde_yaml::to_value(v).ok()?))).collect());
+            let security = match self.get_repo_security(&name).await {
+                Ok(security) => Some(security).filter(|s| *s != RepoSecurity::default()),
+                Err(e) => {
+                    error!("Failed to fetch security features for repo {}: {}", name, e);
+                    None
+                }
+            };
+            let private = repo["private"].as_bool().unwrap_or(false);
+            let actions = match self.get_repo_actions(&name, private, !private).await {
+                Ok(actions) => Some(actions),
+                Err(e) => {
+                    error!("Failed to fetch Actions permissions for repo {}: {}", name, e);
+                    None
+                }
+            };
+            let (secrets, variables) = self.export_actions(&self.repo_actions_scope(&name)).await;
+            let environments = match self.get_environments(&name).await {
+                Ok(mut environments) => {
+                    for environment in &mut environments {
+                        (environment.secrets, environment.variables) =
+                            self.export_actions(&self.environment_actions_scope(&name, &environment.name)).await;
+                    }
+                    Some(environments).filter(|e| !e.is_empty())
+                }
+                Err(e) => {
+                    error!("Failed to fetch environments for repo {}: {}", name, e);
+                    None
+                }
+            };
+
+            repos.push(Repo {
+                name,
+                settings,
+                description,
+                homepage,
+                topics,
+                default_branch,
+                visibility,
+                archived,
+                collaborators,
+                deploy_keys,
+                webhooks,
+                labels,
+                autolinks,
+                milestones,
+                properties,
+                security,
+                actions,
+                secrets,
+                variables,
+                environments,
+                branch_protections: vec![], // Add logic to fetch these if needed
+                ..Default::default()
+            });
+        }
+
+        let org_mapping = get_org_settings_mapping();
+        let org_settings = match self.get_org_settings().await {
+            Ok(mut settings) => {
+                settings.retain(|key, value| {
+                    !value.is_null() && (org_mapping.contains_key(key.as_str()) || ORG_READ_ONLY_SETTINGS.contains(&key.as_str()))
+                });
+                settings
+            }
+            Err(e) => {
+                error!("Failed to fetch org settings for {}: {}", self.org, e);
+                OrgSettings::new()
+            }
+        };
+
+        let org_webhooks = match self.get_hooks(&self.org_hooks_url()).await {
+            Ok(hooks) => webhook_configs(hooks),
+            Err(e) => {
+                error!("Failed to fetch org webhooks for {}: {}", self.org, e);
+                Vec::new()
+            }
+        };
+
+        let custom_properties = match self.get_custom_properties().await {
+            Ok(properties) => Some(properties).filter(|p| !p.is_empty()),
+            Err(e) => {
+                error!("Failed to fetch custom properties for {}: {}", self.org, e);
+                None
+            }
+        };
+        let code_security_configurations = match self.export_code_security_configurations().await {
+            Ok(configurations) => Some(configurations).filter(|c| !c.is_empty()),
+            Err(e) => {
+                error!("Failed to fetch code security configurations for {}: {}", self.org, e);
+                None
+            }
+        };
+        let actions = match self.get_org_actions().await {
+            Ok(actions) => Some(actions),
+            Err(e) => {
+                error!("Failed to fetch Actions permissions for {}: {}", self.org, e);
+                None
+            }
+        };
+        let (secrets, variables) = self.export_actions(&self.org_actions_scope()).await;
+
+        // Fetch teams, users, assignments as before (full state needed for generation)
+        let mut teams = Vec::new();
+        let team_json = self.list_org_teams().await?;
+
+        for team in team_json {
+            // Fetch full team data including members and their roles
+            let roles = self.get_team_member_roles(&team.slug).await?;
+            teams.push(team_from_response(team, &roles));
+        }
+
+        let users = self.get_org_users().await?;
+
+        let assignments = self.get_team_assignments(&teams).await?;
+
+         let outside_collaborators = Some(self.get_outside_collaborators().await?).filter(|c| !c.is_empty());
+
+        // Determine default webhook - find the most common one perhaps?
+         // Or just pick the first one found for simplicity? Let's pick first.
+        // Only a repo's sole hook can serve as the default, since explicit lists don't inherit it.
+        let default_webhook = repos.iter().find_map(|r| match r.webhooks.as_deref() {
+            Some([only]) => Some(only.clone()),
+            _ => None,
+        });
+
+        Ok(Config {
+            org: self.org.clone(),
+            repos,
+            teams,
+            users,
+            org_settings,
+            org_webhooks,
+            actions,
+            custom_properties,
+            code_security_configurations,
+            secrets,
+            variables,
+            assignments,
+            outside_collaborators,
+            default_webhook,
+            default_branch_protections: vec![], // Add logic if needed
+            default_security: None,
+            default_labels: None,
+            default_autolinks: None,
+            default_milestones: None,
+            extra: std::collections::HashMap::new(),
+        })
+    }
+
+    pub async fn sync(&mut self, config_path: &str, dry_run: bool, allow_visibility_change: bool, prune: &[PruneScope]) -> AppResult<()> {
+        let config = crate::config::Config::from_file_with_defaults(config_path, None)?;
+        self.org = config.org.clone(); // Set org from config
+
+        if dry_run {
+            info!("Running in dry-run mode; validating changes without applying.");
+        } else {
+            info!("Running in apply mode; changes will be applied.");
+        }
+
+        // Catch permission typos, invalid config and conflicting collaborator lists before changing anything
+        self.validate_permissions(&config).await?;
+        check_org_settings(&config.org_settings)?;
+        check_outside_collaborators(&config)?;
+        check_environments(&config)?;
+        check_actions(&config)?;
+        check_code_security_configurations(&config)?;
+        check_custom_properties(&config)?;
+        check_deploy_keys(&config)?;
+        let existing_repos: HashMap<String, serde_json::Value> = self
+            .list_org_repos()
+            .await?
+            .into_iter()
+            .filter_map(|r| Some((r["name"].as_str()?.to_string(), r)))
+            .collect();
+        check_visibility_transitions(&config.repos, &existing_repos, allow_visibility_change)?;
+
+        info!("Processing org settings for {}", self.org);
+        self.update_org_settings(&config.org_settings, dry_run).await?;
+        self.manage_org_webhooks(&config.org_webhooks, dry_run).await?;
+        if let Some(custom_properties) = &config.custom_properties {
+            self.sync_custom_properties(custom_properties, prune.contains(&PruneScope::Properties), dry_run).await?;
+        }
+
+        // Sync resources
+        for repo in &config.repos {
+            // --- Add logging just before the failing call ---
+            info!("Processing repo: {}/{}", self.org, repo.name);
+            if repo.name.trim().is_empty() {
+                 error!("Found repo with empty name in config file '{}'.", config_path);
+                 return Err(AppError::GitHubApi("Invalid empty repo name found in config.".to_string()));
+            }
+            // You might add more validation for repo.name characters here if needed
+            // --- End logging ---
+
+            let currently_archived = resolve_current_repo_name(repo, |name| existing_repos.contains_key(name))
+                .and_then(|name| existing_repos[name]["archived"].as_bool())
+                .unwrap_or(false);
+            let repo = match self.apply_repo_lifecycle(repo, &existing_repos, dry_run).await? {
+                RepoLifecycle::Transferred => continue,
+                RepoLifecycle::Continue(name) => Repo { name, ..repo.clone() },
+            };
+
+            // Archived repos are read-only, so unarchive before applying anything else.
+            if currently_archived {
+                if repo.archived == Some(false) {
+                    self.set_repo_archived(&repo.name, false, dry_run).await?;
+                } else {
+                    info!("Repo {}/{} is archived; skipping settings (set 'archived: false' to unarchive).", self.org, repo.name);
+                    continue;
+                }
+            }
+
+            let current_repo = self.update_repo_settings(&repo, dry_run).await?;
+            self.update_repo_metadata(&repo, &current_repo, dry_run).await?;
+            match repo_webhook_plan(&repo, config.default_webhook.as_ref(), prune.contains(&PruneScope::Webhooks)) {
+                Some((webhooks, delete_unlisted)) if !webhooks.is_empty() || delete_unlisted => {
+                    self.manage_webhooks(&repo.name, webhooks, delete_unlisted, dry_run).await?;
+                }
+                _ => debug!("Webhooks for {}/{} are not managed", self.org, repo.name),
+            }
+            self.update_repo_resources(&repo, &config, prune, dry_run).await?;
+            if let Some(security) = repo.effective_security(config.default_security.as_ref()) {
+                self.update_repo_security(&repo.name, &security, dry_run).await?;
+            }
+            if let Some(actions) = &repo.actions {
+                self.update_repo_actions(&repo.name, actions, dry_run).await?;
+            }
+            let scope = self.repo_actions_scope(&repo.name);
+            self.sync_actions(&scope, repo.secrets.as_deref(), repo.variables.as_deref(), prune, dry_run).await?;
+            if let Some(environments) = &repo.environments {
+                self.manage_environments(&repo.name, environments, repo.prune_environments, prune, dry_run).await?;
+            }
+            self.update_repo_visibility(&repo, allow_visibility_change, dry_run).await?;
+            if let Some(collaborators) = &repo.collaborators {
+                self.manage_collaborators(&repo.name, collaborators, prune.contains(&PruneScope::Collaborators), dry_run).await?;
+            }
+            if let Some(deploy_keys) = &repo.deploy_keys {
+                self.manage_deploy_keys(&repo.name, deploy_keys, prune.contains(&PruneScope::DeployKeys), dry_run).await?;
+            }
+
+            if repo.archived == Some(true) && !currently_archived {
+                self.set_repo_archived(&repo.name, true, dry_run).await?;
+            }
+        }
+
+        // Custom property values, in bulk once repos have their final names
+        self.sync_property_values(&config.repos, dry_run).await?;
+
+        // Code security configurations, after repos so they can be attached
+        if let Some(configurations) = &config.code_security_configurations {
+            self.sync_code_security_configurations(configurations, prune.contains(&PruneScope::CodeSecurity), dry_run).await?;
+        }
+
+        // Org Actions settings, secrets and variables, after repos so selected repositories exist
+        if let Some(actions) = &config.actions {
+            self.update_org_actions(actions, dry_run).await?;
+        }
+        let org_scope = self.org_actions_scope();
+        self.sync_actions(&org_scope, config.secrets.as_deref(), config.variables.as_deref(), prune, dry_run).await?;
+
+        // Teams
+        // Parents first, so nested teams can be attached to them
+        for team in order_teams_parent_first(&config.teams) {
+            info!("Processing team: {}", team.name); // Add similar logging for other resources
+          self.create_team(team, dry_run).await?;
+        }
+
+        // Users
+        info!("Processing {} users", config.users.len());
+        self.sync_org_memberships(&config.users, prune.contains(&PruneScope::Members), dry_run).await?;
+
+        // Outside collaborators
+        if let Some(outside_collaborators) = &config.outside_collaborators {
+            if prune.contains(&PruneScope::Collaborators) {
+                info!("Processing outside collaborators");
+                self.sync_outside_collaborators(outside_collaborators, dry_run).await?;
+            } else {
+                debug!("Outside collaborators of {} are not pruned (pass '--prune collaborators' to remove unlisted ones)", self.org);
+            }
+        }
+
+        // Assignments
+        for assignment in &config.assignments {
+            info!("Processing assignment: Team '{}' on Repo '{}'", assignment.team, assignment.repo); // Add similar logging
+            self.assign_team_to_repo(assignment, dry_run).await?;
+        }
+
+        if dry_run {
+            println!("Dry run completed successfully. No changes were applied.");
+        } else {
+            println!("Sync completed successfully. All changes applied.");
+        }
+        Ok(())
+    }
+
+    /// Generates a Config object representing the current GitHub state,
+    /// but ONLY includes resources and fields explicitly mentioned in the provided local_config.
+    /// This is used specifically for the `diff` command.
+    #[allow(dead_code)]
+    async fn generate_filtered_config_from_org(&self, local_config: &Config) -> AppResult<Config> {
+        info!("Fetching relevant GitHub state based on local config structure for diffing.");
+
+        let mut filtered_github_config = Config {
+            org: self.org.clone(),
+            org_settings: OrgSettings::new(),
+            org_webhooks: Vec::new(),
+            actions: None,
+            custom_properties: None,
+            code_security_configurations: None,
+            secrets: None,
+            variables: None,
+            repos: Vec::new(),
+            teams: Vec::new(),
+            users: Vec::new(),
+            assignments: Vec::new(),
+            outside_collaborators: None,
+            // We don't compare default_webhook or default_branch_protections directly in diff,
+            // they are applied to individual repos before comparison.
+            default_webhook: None, // Not needed for filtered diff comparison
+            default_branch_protections: Vec::new(), // Not needed for filtered diff comparison
+            default_security: None,
+            default_labels: None,
+            default_autolinks: None,
+            default_milestones: None,
+            extra: HashMap::new(), // Ignore extra fields for diff
+        };
+
+        // --- Filter Repos ---
+        // Get the names of repos defined locally
+        let local_repo_names: HashSet<&str> = local_config.repos.iter().map(|r| r.name.as_str()).collect();
+
+        // Fetch settings only for locally defined repos
+        let mut github_repo_settings_map = HashMap::new();
+        let mut unprocessed_repos = Vec::new(); // Store full repo data temporarily
+
+        let repo_url = format!("https://api.github.com/orgs/{}/repos?per_page=100", self.org);
+        let repo_response = self.get(&repo_url).await?;
+        let repo_json: Vec<serde_json::Value> = repo_response.json().await.map_err(AppError::Http)?;
+
+        for repo_data in repo_json {
+             if let Some(name) = repo_data["name"].as_str() {
+                if local_repo_names.contains(name) {
+                    // Fetch detailed settings ONLY if the repo is in the local config
+                    match self.get_repo_settings(name).await {
+                       Ok(settings) => {
+                           github_repo_settings_map.insert(name.to_string(), settings);
+                           unprocessed_repos.push(repo_data.clone()); // Store for visibility/webhook check
+                       },
+                       Err(e) => {
+                           error!("Failed to get settings for repo {}: {}. Skipping for diff.", name, e);
+                           // Optionally decide how to handle repos that exist locally but fail to fetch from GitHub
+                       }
+                    }
+                }
+            }
+        }
+
+
+        for local_repo in &local_config.repos {
+            let repo_name = &local_repo.name;
+
+            // Find the basic repo data fetched earlier
+            let github_basic_data = unprocessed_repos.iter().find(|r| r["name"].as_str() == Some(repo_name));
+             // Find the detailed settings fetched earlier
+            let full_github_settings = github_repo_settings_map.get(repo_name);
+
+            if let (Some(basic_data) , Some(github_settings) ) = (github_basic_data, full_github_settings) {
+                let mut filtered_settings = RepoSettings::new();
+                // Only include settings that are present in the local config's settings map
+                for key in local_repo.settings.keys() {
+                    if let Some(value) = github_settings.get(key) {
+                        filtered_settings.insert(key.clone(), value.clone());
+                    } else {
+                         // Key is in local config but not returned by API (might be an error or just not set)
+                         // Insert a Null value to explicitly show it's missing in the diff compared to local definition.
+                         filtered_settings.insert(key.clone(), Value::Null);
+                    }
+                }
+
+                 // Handle visibility if defined locally
+                let github_visibility = if local_repo.visibility.is_some() {
+                     Some(visibility_from_api(basic_data["visibility"].as_str(), basic_data["private"].as_bool().unwrap_or(false)))
+                 } else {
+                     None // Don't include visibility if not in local config
+                 };
+
+                 // Handle webhooks if defined locally (either directly or via default)
+                 let local_webhooks = local_repo.webhooks.clone().or_else(|| local_config.default_webhook.clone().map(|wh| vec![wh]));
+                 let github_webhooks = match local_webhooks {
+                    Some(local_webhooks) => match self.get_webhooks(repo_name).await {
+                        // Keep only the hooks with a URL the local config knows about
+                        Ok(hooks) => Some(
+                            webhook_configs(hooks)
+                                .into_iter()
+                                .filter(|wh| local_webhooks.iter().any(|local| local.url == wh.url))
+                                .collect(),
+                        ),
+                        Err(e) => {
+                           error!("Failed to get webhooks for repo {}: {}. Skipping webhook diff.", repo_name, e);
+                           None
+                        },
+                    },
+                    None => None, // Don't include webhooks if not effectively defined locally
+                };
+
+
+                filtered_github_config.repos.push(Repo {
+                    name: repo_name.clone(),
+                    settings: filtered_settings,
+                    visibility: github_visibility,
+                    webhooks: github_webhooks, // Add the potentially filtered webhooks
+                    // Keep branch protections and extra empty as they aren't diffed this way (yet)
+                    branch_protections: vec![],
+                    ..Default::default()
+                });
+            } else {
+                 // Repo defined locally but not found on GitHub (or settings fetch failed)
+                 // Add a placeholder or log an error. For diff, maybe omit it or add a special marker.
+                 // For now, we'll just omit it, the diff will show the local one as an addition (+)
+                 info!("Repo '{}' defined locally but not found or settings fetch failed on GitHub. Will show as addition in diff.", repo_name);
+            }
+        }
+
+        // --- Filter Teams ---
+        let local_team_names: HashSet<&str> = local_config.teams.iter().map(|t| t.name.as_str()).collect();
+        let team_url = format!("https://api.github.com/orgs/{}/teams?per_page=100", self.org);
+        match self.get(&team_url).await {
+            Ok(team_response) => {
+                let team_json: Vec<serde_json::Value> = team_response.json().await.map_err(AppError::Http)?;
+                for github_team_data in team_json {
+                    if let Some(name) = github_team_data["slug"].as_str() { // Use slug for member fetching
+                        if local_team_names.contains(name) { // Check against local name
+                            let members_url = format!("https://api.github.com/orgs/{}/teams/{}/members?per_page=100", self.org, name);
+                            match self.get(&members_url).await {
+                                Ok(members_response) => {
+                                     let members_json: Vec<serde_json::Value> = members_response.json().await.map_err(AppError::Http)?;
+                                     let mut members: Vec<String> = members_json.iter()
+                                        .filter_map(|m| m["login"].as_str().map(String::from))
+                                        .collect();
+                                     members.sort(); // Sort for consistent diff
+                                     filtered_github_config.teams.push(Team { name: name.to_string(), members, ..Default::default() });
+                                },
+                                Err(e) => error!("Failed to get members for team {}: {}. Skipping team for diff.", name, e),
+                            }
+                        }
+                    }
+                 }
+            }
+            Err(e) => error!("Failed to fetch teams from GitHub: {}. Skipping teams diff.", e),
+        }
+
+
+         // --- Filter Users ---
+         let local_user_logins: HashSet<&str> = local_config.users.iter().map(|u| u.login.as_str()).collect();
+         let members_url = format!("https://api.github.com/orgs/{}/members?per_page=100", self.org);
+         match self.get(&members_url).await {
+            Ok(members_response) => {
+                let members_json: Vec<serde_json::Value> = members_response.json().await.map_err(AppError::Http)?;
+                 for member_data in members_json {
+                     if let Some(login) = member_data["login"].as_str() {
+                         if local_user_logins.contains(login) {
+                             match self.get_user_membership(login).await {
+                                 Ok(Some(role)) => {
+                                     filtered_github_config.users.push(User { login: login.to_string(), role, ..Default::default() });
+                                 },
+                                 Ok(None) => error!("User {} known locally but membership fetch returned None. Skipping user for diff.", login), // Should not happen for members
+                                 Err(e) => error!("Failed to get membership for user {}: {}. Skipping user for diff.", login, e),
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => error!("Failed to fetch members from GitHub: {}. Skipping users diff.", e),
+         }
+
+        // --- Filter Assignments ---
+        // Create lookups for faster checks
+        let local_assignments_set: HashSet<(&str, &str)> = local_config.assignments.iter().map(|a| (a.team.as_str(), a.repo.as_str())).collect();
+        let local_teams_map: HashMap<&str, &Team> = local_config.teams.iter().map(|t| (t.name.as_str(), t)).collect();
+
+        for local_team_name in local_teams_map.keys() {
+              // Check if team exists on GitHub side (fetched earlier)
+              if filtered_github_config.teams.iter().any(|t| t.name == *local_team_name) {
+                    match self.get_team_repos(local_team_name).await {
+                        Ok(github_team_repos) => {
+                            for github_repo_perm in github_team_repos {
+                                // Only include assignments if the (team, repo) pair is in local config
+                                if local_assignments_set.contains(&(local_team_name, github_repo_perm.name.as_str())) {
+                                    if let Some(permission) = permission_from_response(&github_repo_perm) {
+                                        filtered_github_config.assignments.push(Assignment {
+                                             repo: github_repo_perm.name.clone(),
+                                             team: local_team_name.to_string(),
+                                             permission,
+                                         });
+                                    }
+                                 }
+                             }
+                         },
+                        Err(e) => error!("Failed to get repos for team {}: {}. Skipping assignments for this team.", local_team_name, e),
+                     }
+                }
+         }
+
+
+        Ok(filtered_github_config)
+    }
+
+    /// Diffs the local configuration against the full GitHub org state (as written by sync-from-github).
+    pub async fn diff(&self, config_path: &str) -> AppResult<bool> {
+        info!("Generating diff between full GitHub org state and local config: {} (this matches what sync-from-github would write)", config_path);
+
+        // --- Step 1: Load original local config & track explicit webhooks ---
+        let local_config = crate::config::Config::from_file_with_defaults(config_path, None)?;
+        let local_default_webhook = local_config.default_webhook.clone();
+        check_org_settings(&local_config.org_settings)?;
+
+        // --- Step 2: Fetch FULL GitHub org state (unfiltered, as sync-from-github would write) ---
+        let github_config = self.generate_unfiltered_config_from_org().await?;
+
+        // --- Step 3: Prepare final versions for diffing ---
+        let mut diff_local_config = local_config.clone(); // Clone original local config
+        let mut diff_github_config = github_config;      // Use the fetched FULL GitHub org state
+
+        // Show pending renames/transfers as such rather than as a deleted plus a new repo
+        let lifecycle_notes = normalize_repo_lifecycle_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_team_properties_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_collaborators_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_org_settings_for_diff(&diff_local_config, &mut diff_github_config);
+        normalize_org_webhooks_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_actions_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_actions_permissions_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_security_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_code_security_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_custom_properties_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_repo_resources_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_repo_metadata_for_diff(&mut diff_local_config, &mut diff_github_config);
+        normalize_environments_for_diff(&mut diff_local_config, &mut diff_github_config);
+        let deploy_key_notes = normalize_deploy_keys_for_diff(&mut diff_local_config, &mut diff_github_config);
+        let pending_notes = normalize_memberships_for_diff(&mut diff_local_config, &mut diff_github_config);
+        let write_only_notes = self.write_only_notes(&local_config)?;
+
+        // --- Step 4: Apply local default webhook logic to the local config *copy* ---
+        // Repos without webhooks and without a default don't have their webhooks managed
+        if local_default_webhook.is_none() {
+            let unmanaged: HashSet<&str> = diff_local_config.repos.iter().filter(|r| r.webhooks.is_none()).map(|r| r.name.as_str()).collect();
+            for repo in &mut diff_github_config.repos {
+                if unmanaged.contains(repo.name.as_str()) {
+                    repo.webhooks = None;
+                }
+            }
+        }
+        // GitHub has no notion of a default hook, so collapse both sides against the local one
+        diff_github_config.default_webhook = local_default_webhook.clone();
+        if let Some(ref default_webhook) = local_default_webhook { // Use original for application logic
+            let mut defaulted = HashSet::new();
+            for repo in &mut diff_local_config.repos {
+                if repo.webhooks.is_none() {
+                    repo.webhooks = Some(vec![default_webhook.clone()]);
+                    defaulted.insert(repo.name.clone());
+                }
+            }
+            // Sync only ensures the default hook exists on these repos, so compare just that hook
+            for repo in &mut diff_github_config.repos {
+                if defaulted.contains(&repo.name) {
+                    let mut webhooks = repo.webhooks.take().unwrap_or_default();
+                    webhooks.retain(|wh| wh.url == default_webhook.url);
+                    repo.webhooks = Some(webhooks);
+                }
+            }
+        }
+
+        // --- Step 5: Normalization - Remove matching default webhooks for both configs ---
+        // Helper closure to normalize webhooks and default_webhook
+        let normalize_webhooks = |config: &mut crate::config::Config| {
+            // Sort default webhook events for comparison
+            let mut sorted_default = config.default_webhook.clone();
+            if let Some(ref mut wh) = sorted_default { normalize_webhook_list(std::slice::from_mut(wh)); }
+
+            // Track repos that actually use the default
+            let mut used_default = false;
+            for repo in &mut config.repos {
+                // Sort hooks and events for explicit webhooks
+                if let Some(ref mut webhooks) = repo.webhooks { normalize_webhook_list(webhooks); }
+                // If the repo's only webhook matches default, set to None for diffing
+                match (repo.webhooks.as_deref(), &sorted_default) {
+                    (Some([only]), Some(def)) if only == def => {
+                        repo.webhooks = None;
+                        used_default = true;
+                    }
+                    // If repo has no webhooks, it implicitly uses the default
+                    (None, Some(_)) => used_default = true,
+                    _ => {}
+                }
+            }
+            // If no repo uses the default, set it to None for diffing
+            if !used_default {
+                config.default_webhook = None;
+            } else {
+                // Always store sorted version for diffing
+                config.default_webhook = sorted_default;
+            }
+        };
+        normalize_webhooks(&mut diff_local_config);
+        normalize_webhooks(&mut diff_github_config);
+
+        // --- Step 6: Remove top-level default key if not used by any repo ---
+        // (Handled above in normalization)
+
+        // --- Step 7: Sort both configs... (ensure BTreeMap logic is kept) ---
+        // Repos
+        diff_local_config.repos.sort_by(|a, b| a.name.cmp(&b.name));
+        diff_github_config.repos.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Teams (and members within teams)
+        diff_local_config.teams.sort_by(|a, b| a.name.cmp(&b.name));
+        diff_github_config.teams.sort_by(|a, b| a.name.cmp(&b.name));
+        // Without a local `maintainers` list roles aren't managed, so compare membership only
+        let unmanaged_roles: HashSet<String> = diff_local_config.teams.iter().filter(|t| t.maintainers.is_none()).map(|t| t.name.clone()).collect();
+        for team in diff_local_config.teams.iter_mut().chain(diff_github_config.teams.iter_mut()) {
+            if unmanaged_roles.contains(&team.name) {
+                if let Some(maintainers) = team.maintainers.take() {
+                    team.members.extend(maintainers);
+                }
+                team.members.sort();
+                team.members.dedup();
+                continue;
+            }
+            // A login listed under both is a maintainer, exactly as sync treats it
+            let maintainers: HashSet<String> = team.maintainers.iter().flatten().cloned().collect();
+            team.members.retain(|m| !maintainers.contains(m));
+            team.members.sort();
+            if let Some(maintainers) = &mut team.maintainers {
+                maintainers.sort();
+                maintainers.dedup();
+            }
+        }
+
+        // Users
+        diff_local_config.users.sort_by(|a, b| a.label().cmp(b.label()));
+        diff_github_config.users.sort_by(|a, b| a.label().cmp(b.label()));
+
+        // Assignments
+        diff_local_config.assignments.sort_by(|a, b| a.team.cmp(&b.team).then(a.repo.cmp(&b.repo)));
+        diff_github_config.assignments.sort_by(|a, b| a.team.cmp(&b.team).then(a.repo.cmp(&b.repo)));
+
+        // --- Step 8: Serialize and Diff ---
+        let github_yaml = serde_yaml::to_string(&diff_github_config)?;
+        let local_yaml = serde_yaml::to_string(&diff_local_config)?;
+
+        // Compute and print diff
+        let diff = TextDiff::from_lines(&github_yaml, &local_yaml);
+
+        // Corrected: Bind the configured builder to ensure it lives long enough
+        let mut unified_diff = diff.unified_diff();
+        let unified_diff_builder = unified_diff // Create the builder
+            .context_radius(3)                           // Configure it
+            .header("GitHub (Normalized)", "Local Config (Normalized)"); // Configure it further
+
+        let mut has_diffs = false;
+        let mut output_buffer = String::new();
+
+        // Iterate using the named builder instance
+        for hunk in unified_diff_builder.iter_hunks() { // Now using the variable that lives long enough
+            has_diffs = true;
+            output_buffer.push_str(&format!(
+                "@@ {} @@\n",
+                hunk.header()
+            ));
+            for change in hunk.iter_changes() {
+                match change.tag() {
+                    ChangeTag::Delete => output_buffer.push_str(&format!("{}\n", format!("- {}", change.value().trim_end()).red())),
+                    ChangeTag::Insert => output_buffer.push_str(&format!("{}\n", format!("+ {}", change.value().trim_end()).green())),
+                    ChangeTag::Equal => output_buffer.push_str(&format!("  {}\n", change.value().trim_end())),
+                }
+            }
+        }
+
+        for note in &lifecycle_notes {
+            println!("{}", note.yellow());
+        }
+        has_diffs |= !lifecycle_notes.is_empty();
+
+        // Deploy keys can't be updated, so changed ones are called out
+        for note in &deploy_key_notes {
+            println!("{}", note.yellow());
+        }
+
+        // GitHub doesn't return write-only values, so changes to them only show up here
+        if !write_only_notes.is_empty() {
+            println!("Write-only values that sync would send again:");
+            for note in &write_only_notes {
+                println!("  {}", note.yellow());
+            }
+        }
+        has_diffs |= !write_only_notes.is_empty();
+
+        // Pending invitations are reported, but aren't drift
+        if !pending_notes.is_empty() {
+            println!("Pending invitations:");
+            for note in &pending_notes {
+                println!("  {}", note.cyan());
+            }
+        }
+
+        if !has_diffs {
+            println!("No differences found between full GitHub org state and local config.");
+        } else {
+            println!("Differences found between full GitHub org state and local config:");
+            println!("--- GitHub Org (Normalized)"); // Adjusted header
+            println!("+++ Local Config (Normalized)"); // Adjusted header
+            print!("{}", output_buffer);
+        }
+        Ok(has_diffs)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_github_client_new() {
+        let client = GitHubClient::new("dummy_token", "dummy_org");
+        assert_eq!(client.token, "dummy_token");
+        assert_eq!(client.org, "dummy_org");
+    }
+
+    #[test]
+    fn test_get_webhooks_parses_response() {
+        let mut server = mockito::Server::new();
+
+        let _m = server
+            .mock("GET", "/repos/dummy_org/dummy_repo/hooks")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"
+[
+  {
+    "id": 123,
+    "url": "http://api.github.com/hook/123",
+    "config": {
+      "url": "http://example.com",
+      "content_type": "json"
+    },
+    "events": ["push", "pull_request"]
+  }
+]
+"#,
+            )
+            .create();
+
+        let rt = tokio::runtime::Runtime::new().expect("create runtime");
+        rt.block_on(async {
+            let mut client = GitHubClient::new("dummy_token", "dummy_org");
+            client.org = "dummy_org".to_string();
+
+            let url = format!("{}/repos/dummy_org/dummy_repo/hooks", server.url());
+            let response = client.get(&url).await.expect("HTTP GET failed");
+            let text = response.text().await.expect("read response text");
+            let hooks: Vec<WebhookResponse> = serde_json::from_str(&text).expect("parse JSON");
+
+            assert_eq!(hooks.len(), 1);
+            let hook = &hooks[0];
+            assert_eq!(hook.id, Some(123));
+            assert_eq!(hook.url, "http://api.github.com/hook/123");
+            assert_eq!(hook.config.url, "http://example.com");
+            assert_eq!(hook.config.content_type, "json");
+            assert_eq!(hook.events, vec!["push", "pull_request"]);
+        });
+    }
+
+    #[test]
+    fn test_next_page_url() {
+        let link = r#"<https://api.github.com/organizations/1/repos?page=2>; rel="next", <https://api.github.com/organizations/1/repos?page=5>; rel="last""#;
+        assert_eq!(next_page_url(link), Some("https://api.github.com/organizations/1/repos?page=2".to_string()));
+        let last = r#"<https://api.github.com/organizations/1/repos?page=4>; rel="prev", <https://api.github.com/organizations/1/repos?page=1>; rel="first""#;
+        assert_eq!(next_page_url(last), None);
+    }
+
+    #[test]
+    fn test_get_all_follows_link_header() {
+        let mut server = mockito::Server::new();
+        let next = format!("<{}/orgs/dummy_org/repos?page=2>; rel=\"next\"", server.url());
+        let _first = server
+            .mock("GET", "/orgs/dummy_org/repos")
+            .with_status(200)
+            .with_header("link", &next)
+            .with_body(r#"[{"name": "one"}, {"name": "two"}]"#)
+            .create();
+        let _second = server
+            .mock("GET", "/orgs/dummy_org/repos?page=2")
+            .with_status(200)
+            .with_body(r#"[{"name": "three"}]"#)
+            .create();
+
+        let rt = tokio::runtime::Runtime::new().expect("create runtime");
+        rt.block_on(async {
+            let client = GitHubClient::new("dummy_token", "dummy_org");
+            let repos: Vec<serde_json::Value> = client.get_all(&format!("{}/orgs/dummy_org/repos", server.url())).await.expect("paginated GET failed");
+            let names: Vec<&str> = repos.iter().filter_map(|r| r["name"].as_str()).collect();
+            assert_eq!(names, vec!["one", "two", "three"]);
+        });
+    }
+
+    #[test]
+    fn test_get_all_in_follows_link_header() {
+        let mut server = mockito::Server::new();
+        let next = format!("<{}/orgs/dummy_org/actions/secrets?page=2>; rel=\"next\"", server.url());
+        let _first = server
+            .mock("GET", "/orgs/dummy_org/actions/secrets")
+            .with_status(200)
+            .with_header("link", &next)
+            .with_body(r#"{"total_count": 3, "secrets": [{"name": "ONE"}, {"name": "TWO"}]}"#)
+            .create();
+        let _second = server
+            .mock("GET", "/orgs/dummy_org/actions/secrets?page=2")
+            .with_status(200)
+            .with_body(r#"{"total_count": 3, "secrets": [{"name": "THREE"}]}"#)
+            .create();
+
+        let rt = tokio::runtime::Runtime::new().expect("create runtime");
+        rt.block_on(async {
+            let client = GitHubClient::new("dummy_token", "dummy_org");
+            let url = format!("{}/orgs/dummy_org/actions/secrets", server.url());
+            let secrets: Vec<ActionsItemResponse> = client.get_all_in(&url, "secrets").await.expect("paginated GET failed");
+            let names: Vec<&str> = secrets.iter().map(|s| s.name.as_str()).collect();
+            assert_eq!(names, vec!["ONE", "TWO", "THREE"]);
+            assert!(client.get_all_in::<ActionsItemResponse>(&url, "variables").await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_get_optional_treats_404_as_missing() {
+        let mut server = mockito::Server::new();
+        let _found = server.mock("GET", "/found").with_status(204).create();
+        let _missing = server.mock("GET", "/missing").with_status(404).with_body(r#"{"message": "Not Found"}"#).create();
+        let _failing = server.mock("GET", "/failing").with_status(500).with_body("boom").create();
+
+        let rt = tokio::runtime::Runtime::new().expect("create runtime");
+        rt.block_on(async {
+            let client = GitHubClient::new("dummy_token", "dummy_org");
+            assert!(client.get_optional(&format!("{}/found", server.url())).await.unwrap().is_some());
+            assert!(client.get_optional(&format!("{}/missing", server.url())).await.unwrap().is_none());
+            assert!(client.get_optional(&format!("{}/failing", server.url())).await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_resolve_current_repo_name_follows_previous_names() {
+        let exists = |name: &str| name == "old";
+        let repo = Repo { name: "new".to_string(), previous_names: vec!["older".to_string(), "old".to_string()], ..Default::default() };
+        assert_eq!(resolve_current_repo_name(&repo, exists), Some("old"));
+
+        let missing = Repo { name: "other".to_string(), ..Default::default() };
+        assert_eq!(resolve_current_repo_name(&missing, exists), None);
+    }
+
+    #[test]
+    fn test_plan_collaborator_changes() {
+        let collaborator = |login: &str, permission: Permission| Collaborator { login: login.to_string(), permission };
+        let desired = vec![
+            collaborator("Alice", Permission::Write),
+            collaborator("bob", Permission::Admin),
+            collaborator("carol", Permission::Read),
+            collaborator("dave", Permission::Triage),
+        ];
+        let active = BTreeMap::from([
+            ("alice".to_string(), collaborator("alice", Permission::Write)),
+            ("bob".to_string(), collaborator("bob", Permission::Read)),
+            ("mallory".to_string(), collaborator("mallory", Permission::Admin)),
+        ]);
+        let pending = BTreeMap::from([
+            ("carol".to_string(), (1, collaborator("carol", Permission::Write))),
+            ("eve".to_string(), (2, collaborator("eve", Permission::Read))),
+        ]);
+
+        let changes = plan_collaborator_changes(&desired, &active, &pending, true);
+        assert_eq!(
+            changes,
+            vec![
+                CollaboratorChange::Grant("bob".to_string(), Permission::Admin),
+                CollaboratorChange::UpdateInvitation(1, "carol".to_string(), Permission::Read),
+                CollaboratorChange::Grant("dave".to_string(), Permission::Triage),
+                CollaboratorChange::Remove("mallory".to_string()),
+                CollaboratorChange::CancelInvitation(2, "eve".to_string()),
+            ]
+        );
+        // Without prune, unlisted collaborators and invitations are left alone
+        assert_eq!(plan_collaborator_changes(&desired, &active, &pending, false), changes[..3]);
+    }
+
+    #[test]
+    fn test_prune_scopes_are_independent() {
+        use clap::ValueEnum;
+        let prune: Vec<PruneScope> = "webhooks,deploy-keys".split(',').map(|s| PruneScope::from_str(s, false).unwrap()).collect();
+        assert_eq!(prune, vec![PruneScope::Webhooks, PruneScope::DeployKeys]);
+
+        // Cleaning up webhooks deletes unlisted hooks...
+        let repo = Repo { name: "web".to_string(), webhooks: Some(Vec::new()), labels: Some(Vec::new()), ..Default::default() };
+        assert_eq!(repo_webhook_plan(&repo, None, prune.contains(&PruneScope::Webhooks)), Some((&[][..], true)));
+        // ...but leaves org members and labels alone
+        let members = BTreeMap::from([("alice".to_string(), User { login: "alice".to_string(), role: "member".to_string(), ..Default::default() })]);
+        assert!(plan_membership_changes(&[], &members, &[], prune.contains(&PruneScope::Members), None).is_empty());
+        assert_eq!(repo_list_plan(repo.labels.as_deref(), None, prune.contains(&Label::PRUNE_SCOPE)), Some((&[][..], false)));
+    }
+
+    #[test]
+    fn test_plan_membership_changes() {
+        let user = |login: &str, role: &str| User { login: login.to_string(), role: role.to_string(), ..Default::default() };
+        let invitation = |id: i64, login: Option<&str>, email: Option<&str>, role: &str| OrgInvitationResponse {
+            id,
+            login: login.map(str::to_string),
+            email: email.map(str::to_string),
+            role: role.to_string(),
+        };
+        let members = BTreeMap::from([
+            ("alice".to_string(), user("Alice", "member")),
+            ("bot".to_string(), user("bot", "admin")),
+            ("mallory".to_string(), user("mallory", "member")),
+        ]);
+        let invitations = vec![
+            invitation(1, Some("bob"), None, "direct_member"),
+            invitation(2, None, Some("New@example.com"), "direct_member"),
+            invitation(3, Some("eve"), None, "admin"),
+        ];
+        let new_hire = User { email: Some("new@example.com".to_string()), ..user("", "member") };
+        let users = vec![user("alice", "admin"), user("bob", "admin"), new_hire, user("carol", "member")];
+
+        let changes = plan_membership_changes(&users, &members, &invitations, false, None);
+        assert_eq!(
+            changes,
+            vec![
+                MembershipChange::SetRole("Alice".to_string(), "admin".to_string()),
+                MembershipChange::SetRole("bob".to_string(), "admin".to_string()),
+                MembershipChange::Invite(user("carol", "member")),
+            ]
+        );
+
+        let changes = plan_membership_changes(&users, &members, &invitations, true, Some("BOT"));
+        assert_eq!(
+            &changes[3..],
+            &[MembershipChange::CancelInvitation(3, "eve".to_string()), MembershipChange::Remove("mallory".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_find_user_access() {
+        let config = Config {
+            repos: vec![
+                Repo {
+                    name: "infra".to_string(),
+                    collaborators: Some(vec![Collaborator { login: "Alice".to_string(), permission: Permission::Admin }]),
+                    ..Default::default()
+                },
+                Repo {
+                    name: "web".to_string(),
+                    environments: Some(vec![Environment {
+                        name: "production".to_string(),
+                        reviewers: EnvironmentReviewers { users: vec!["alice".to_string()], teams: vec![] },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ],
+            teams: vec![
+                Team { name: "Platform Eng".to_string(), slug: Some("platform".to_string()), maintainers: Some(vec!["alice".to_string()]), ..Default::default() },
+                Team { name: "Core".to_string(), members: vec!["alice".to_string()], ..Default::default() },
+                Team { name: "Other".to_string(), members: vec!["bob".to_string()], ..Default::default() },
+            ],
+            users: vec![User { login: "alice".to_string(), role: "admin".to_string(), ..Default::default() }],
+            outside_collaborators: Some(vec!["bob".to_string()]),
+            ..Default::default()
+        };
+
+        let grants = find_user_access(&config, "ALICE");
+        assert_eq!(
+            grants,
+            vec![
+                AccessGrant::Collaborator { repo: "infra".to_string(), permission: Permission::Admin },
+                AccessGrant::EnvironmentReviewer { repo: "web".to_string(), environment: "production".to_string() },
+                AccessGrant::Team { slug: "platform".to_string(), role: TEAM_ROLE_MAINTAINER },
+                AccessGrant::Team { slug: "core".to_string(), role: TEAM_ROLE_MEMBER },
+                AccessGrant::OrgMember { role: "admin".to_string(), pending: false },
+            ]
+        );
+        assert_eq!(find_user_access(&config, "bob").last(), Some(&AccessGrant::OutsideCollaborator));
+        assert!(find_user_access(&config, "carol").is_empty());
+    }
+
+    #[test]
+    fn test_normalize_memberships_for_diff() {
+        let user = |login: &str, role: &str| User { login: login.to_string(), role: role.to_string(), ..Default::default() };
+        let pending = |u: User| User { state: Some(MembershipState::Pending), ..u };
+        let mut local = Config {
+            users: vec![user("alice", "member"), User { teams: vec!["core".to_string()], ..user("bob", "admin") }],
+            ..Default::default()
+        };
+        let mut github = Config {
+            users: vec![user("alice", "member"), pending(user("bob", "member")), pending(user("eve", "member"))],
+            ..Default::default()
+        };
+
+        let notes = normalize_memberships_for_diff(&mut local, &mut github);
+        assert_eq!(notes, vec!["bob: invitation pending (member, config says admin)".to_string()]);
+        assert_eq!(local.users, vec![user("alice", "member")]);
+        assert_eq!(github.users, vec![user("alice", "member"), pending(user("eve", "member"))]);
+    }
+
+    #[test]
+    fn test_org_settings_updates() {
+        let setting = |v: &str| serde_yaml::from_str::<serde_yaml::Value>(v).unwrap();
+        let desired = OrgSettings::from([
+            ("billing_email".to_string(), setting("billing@example.com")),
+            ("blog".to_string(), setting("https://example.com")),
+            ("members_can_create_repositories".to_string(), setting("false")),
+            ("two_factor_requirement_enabled".to_string(), setting("true")),
+        ]);
+        let current = OrgSettings::from([
+            ("billing_email".to_string(), setting("billing@example.com")),
+            ("members_can_create_repositories".to_string(), setting("true")),
+            ("two_factor_requirement_enabled".to_string(), setting("false")),
+        ]);
+
+        let (updates, read_only_drift) = org_settings_updates(&desired, &current);
+        assert_eq!(read_only_drift, vec!["two_factor_requirement_enabled"]);
+        assert_eq!(updates.len(), 1);
+        let body = &updates[&("/orgs/{org}", "PATCH")];
+        assert_eq!(body.len(), 2);
+        assert_eq!(body["blog"], json!("https://example.com"));
+        assert_eq!(body["members_can_create_repositories"], json!(false));
+    }
+
+    #[test]
+    fn test_check_org_settings() {
+        let setting = |v: &str| serde_yaml::from_str::<serde_yaml::Value>(v).unwrap();
+        let mut settings = OrgSettings::from([
+            ("blog".to_string(), setting("https://example.com")),
+            ("two_factor_requirement_enabled".to_string(), setting("true")),
+        ]);
+        assert!(check_org_settings(&settings).is_ok());
+        settings.insert("not_an_org_field".to_string(), setting("1"));
+        assert!(check_org_settings(&settings).unwrap_err().to_string().contains("not_an_org_field"));
+    }
+
+    #[test]
+    fn test_plan_webhook_changes() {
+        let current: Vec<WebhookResponse> = serde_json::from_value(json!([
+            { "id": 1, "url": "u", "events": ["push", "pull_request"], "active": true,
+              "config": { "url": "https://ci.example.com", "content_type": "json", "insecure_ssl": "0", "secret": "********" } },
+            { "id": 2, "url": "u", "events": ["push"], "active": true,
+              "config": { "url": "https://chat.example.com", "content_type": "json", "insecure_ssl": 0 } },
+            { "id": 3, "url": "u", "events": ["push"], "active": true,
+              "config": { "url": "https://old.example.com", "content_type": "json" } }
+        ]))
+        .unwrap();
+        let hook = |url: &str, events: &[&str]| WebhookConfig {
+            url: url.to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            ..Default::default()
+        };
+        let desired = vec![
+            // Same hook with a secret and events in another order: nothing to do
+            WebhookConfig { secret: Some(crate::config::SecretRef::Env("CI_SECRET".to_string())), ..hook("https://ci.example.com", &["pull_request", "push"]) },
+            WebhookConfig { active: false, ..hook("https://chat.example.com", &["push"]) },
+            hook("https://deploy.example.com", &["deployment"]),
+        ];
+
+        assert_eq!(
+            plan_webhook_changes(&desired, &current, true, &HashSet::new()),
+            vec![
+                WebhookChange::Update(2, &desired[1]),
+                WebhookChange::Create(&desired[2]),
+                WebhookChange::Delete(3, "https://old.example.com".to_string()),
+            ]
+        );
+        assert_eq!(plan_webhook_changes(&desired, &current, false, &HashSet::new()).len(), 2);
+        // A secret that changed since the last sync is sent again
+        let stale = HashSet::from(["https://ci.example.com"]);
+        assert_eq!(plan_webhook_changes(&desired, &current, false, &stale)[0], WebhookChange::Update(1, &desired[0]));
+
+        // Hooks without an ID are never addressed as hooks/0
+        let mut without_ids = current;
+        for hook in &mut without_ids {
+            hook.id = None;
+        }
+        assert_eq!(plan_webhook_changes(&desired, &without_ids, true, &HashSet::new()), vec![WebhookChange::Create(&desired[2])]);
+    }
+
+    #[test]
+    fn test_write_only_status() {
+        std::env::set_var("GH_CONFIG_TEST_STATUS_SECRET", "s3cret");
+        let mut state = StateFile::default();
+        let secret = SecretRef::Env("GH_CONFIG_TEST_STATUS_SECRET".to_string());
+        let key = webhook_secret_key("org/repo", "https://ci.example.com");
+        assert_eq!(write_only_status(&state, &key, Some(&secret)).unwrap(), WriteOnlyStatus::Untracked);
+        assert_eq!(write_only_status(&state, &key, None).unwrap(), WriteOnlyStatus::Unchanged);
+
+        state.record(&key, "s3cret").unwrap();
+        assert_eq!(write_only_status(&state, &key, Some(&secret)).unwrap(), WriteOnlyStatus::Unchanged);
+        assert_eq!(write_only_status(&state, &webhook_secret_key("org/other", "https://ci.example.com"), Some(&secret)).unwrap(), WriteOnlyStatus::Untracked);
+        // The recorded secret is still set on GitHub until the hook is updated
+        assert_eq!(write_only_status(&state, &key, None).unwrap(), WriteOnlyStatus::Changed);
+
+        std::env::set_var("GH_CONFIG_TEST_STATUS_SECRET", "rotated");
+        assert_eq!(write_only_status(&state, &key, Some(&secret)).unwrap(), WriteOnlyStatus::Changed);
+    }
+
+    #[test]
+    fn test_plan_actions_item_changes() {
+        let item = |name: &str, value: Option<&str>| ActionsItem { name: name.to_string(), value: value.map(str::to_string), ..Default::default() };
+        let current = vec![item("NPM_TOKEN", None), item("REGION", Some("eu-west-1")), item("OLD", None)];
+        let desired = vec![item("npm_token", None), item("REGION", Some("us-east-1")), item("NEW", None)];
+
+        assert_eq!(
+            plan_actions_item_changes(&desired, &current, &HashSet::new(), true),
+            vec![ActionsItemChange::Update(1), ActionsItemChange::Create(2), ActionsItemChange::Delete("OLD".to_string())]
+        );
+        assert_eq!(plan_actions_item_changes(&desired, &current, &HashSet::new(), false).len(), 2);
+        // A secret whose value changed since the last sync is sent again
+        let stale = HashSet::from(["npm_token"]);
+        assert_eq!(plan_actions_item_changes(&desired, &current, &stale, false)[0], ActionsItemChange::Update(0));
+    }
+
+    #[test]
+    fn test_actions_item_org_visibility() {
+        let secret = ActionsSecret {
+            name: "NPM_TOKEN".to_string(),
+            value: SecretRef::Env("NPM_TOKEN".to_string()),
+            visibility: None,
+            selected_repositories: vec!["web".to_string()],
+        };
+        // Org secrets default to private, and selected repositories only count when selected
+        let item = ActionsItem::from_secret(&secret, true);
+        assert_eq!(item.visibility, Some(ActionsVisibility::Private));
+        assert!(item.selected_repositories.is_empty());
+        assert_eq!(ActionsItem::from_secret(&secret, false).visibility, None);
+
+        let selected = ActionsSecret { visibility: Some(ActionsVisibility::Selected), selected_repositories: vec!["Web".to_string(), "api".to_string()], ..secret };
+        let current = ActionsItem::desired("NPM_TOKEN", None, Some(ActionsVisibility::Selected), &["API".to_string(), "web".to_string()], true);
+        assert!(current.matches(&ActionsItem::from_secret(&selected, true)));
+    }
+
+    #[test]
+    fn test_encrypt_secret_is_a_sealed_box() {
+        use base64::Engine;
+        let engine = base64::engine::general_purpose::STANDARD;
+        let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+        let public_key = engine.encode(secret_key.public_key().as_bytes());
+
+        let encrypted = encrypt_secret(&public_key, "hunter2").unwrap();
+        let decrypted = secret_key.unseal(&engine.decode(encrypted).unwrap()).unwrap();
+        assert_eq!(decrypted, b"hunter2");
+        assert!(encrypt_secret("not a key", "hunter2").is_err());
+    }
+
+    #[test]
+    fn test_normalize_actions_for_diff() {
+        let env = |name: &str| SecretRef::Env(name.to_string());
+        let secret = |name: &str, value: SecretRef| ActionsSecret { name: name.to_string(), value, visibility: None, selected_repositories: vec![] };
+        let variable = |name: &str, value: &str| ActionsVariable { name: name.to_string(), value: value.to_string(), ..Default::default() };
+        let mut local = Config {
+            secrets: Some(vec![secret("npm_token", SecretRef::File("/run/npm".to_string()))]),
+            repos: vec![
+                Repo { name: "web".to_string(), variables: Some(vec![variable("region", "eu")]), ..Default::default() },
+                Repo { name: "api".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let mut github = Config {
+            secrets: Some(vec![ActionsSecret { visibility: Some(ActionsVisibility::Private), ..secret("NPM_TOKEN", env("NPM_TOKEN")) }]),
+            variables: Some(vec![variable("UNMANAGED", "x")]),
+            repos: vec![
+                Repo { name: "web".to_string(), variables: Some(vec![variable("REGION", "eu")]), secrets: Some(vec![secret("X", env("X"))]), ..Default::default() },
+                Repo { name: "api".to_string(), variables: Some(vec![variable("A", "b")]), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_actions_for_diff(&mut local, &mut github);
+
+        assert_eq!(local.secrets, github.secrets);
+        assert_eq!(github.variables, None);
+        assert_eq!(local.repos[0].variables, github.repos[0].variables);
+        assert_eq!(github.repos[0].secrets, None);
+        assert_eq!(github.repos[1].variables, None);
+    }
+
+    #[test]
+    fn test_actions_yaml_round_trips() {
+        let secrets = vec![ActionsSecret {
+            name: "NPM_TOKEN".to_string(),
+            value: SecretRef::Env("NPM_TOKEN".to_string()),
+            visibility: Some(ActionsVisibility::Selected),
+            selected_repositories: vec!["web".to_string()],
+        }];
+        let variables = vec![ActionsVariable { name: "REGION".to_string(), value: "eu-west-1".to_string(), ..Default::default() }];
+        let yaml = format!("name: web\n{}{}", actions_secrets_yaml(&secrets, ""), actions_variables_yaml(&variables, ""));
+        let repo: Repo = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(repo.secrets, Some(secrets));
+        assert_eq!(repo.variables, Some(variables));
+    }
+
+    #[test]
+    fn test_json_path_lookup() {
+        let body = json!({ "cname": "docs.example.com", "source": { "branch": "main", "path": "/" } });
+        assert_eq!(json_path_lookup(&body, "cname"), Some(&json!("docs.example.com")));
+        assert_eq!(json_path_lookup(&body, "source.branch"), Some(&json!("main")));
+        assert_eq!(json_path_lookup(&body, "source.missing"), None);
+        assert_eq!(
+            repo_mapping_url("/repos/{owner}/{repo}/pages", "my-org", "web"),
+            "https://api.github.com/repos/my-org/web/pages"
+        );
+    }
 
-         // Determine default webhook - find the most common one perhaps?
-         // Or just pick the first one found for simplicity? Let's pick first.
-        let default_webhook = repos.iter().find_map(|r| r.webhook.clone());
+    #[test]
+    fn test_read_json_path_finds_wrapped_value() {
+        let mapping = get_github_api_mapping();
+        let enforce_admins = &mapping["enforce_admins"];
+        let body = json!({ "enforce_admins": { "url": "https://api.github.com/x", "enabled": true } });
+        // The write path holds the wrapper object, which never equals the configured bool
+        assert_ne!(json_path_lookup(&body, enforce_admins.json_path), Some(&json!(true)));
+        assert_eq!(json_path_lookup(&body, enforce_admins.read_json_path), Some(&json!(true)));
+    }
 
-        Ok(Config {
-            org: self.org.clone(),
-            repos,
-            teams,
-            users,
-            assignments,
-            default_webhook,
-            default_branch_protections: vec![], // Add logic if needed
-            extra: std::collections::HashMap::new(),
-        })
+    #[test]
+    fn test_repo_mapping_read_url_skips_unresolved_placeholders() {
+        let mapping = get_github_api_mapping();
+        assert_eq!(
+            repo_mapping_read_url(&mapping["cname"], "my-org", "web").as_deref(),
+            Some("https://api.github.com/repos/my-org/web/pages")
+        );
+        assert_eq!(repo_mapping_read_url(&mapping["allow_deletions"], "my-org", "web"), None);
+        assert_eq!(repo_mapping_read_url(&mapping["wait_timer"], "my-org", "web"), None);
     }
 
-    pub async fn sync(&mut self, config_path: &str, dry_run: bool) -> AppResult<()> {
-        let config = crate::config::Config::from_file_with_defaults(config_path, None)?;
-        self.org = config.org.clone(); // Set org from config
+    fn code_security_response() -> CodeSecurityConfigurationResponse {
+        serde_json::from_value(json!({
+            "id": 7,
+            "name": "strict",
+            "target_type": "organization",
+            "description": "Strict settings",
+            "secret_scanning": "enabled",
+            "secret_scanning_push_protection": "disabled",
+            "enforcement": "enforced",
+            "html_url": "https://github.com/organizations/my-org/settings/security_products/configurations/7"
+        }))
+        .unwrap()
+    }
 
-        if dry_run {
-            info!("Running in dry-run mode; validating changes without applying.");
-        } else {
-            info!("Running in apply mode; changes will be applied.");
-        }
+    #[test]
+    fn test_code_security_changes_and_validation() {
+        let desired = CodeSecurityConfiguration {
+            name: "strict".to_string(),
+            settings: BTreeMap::from([
+                ("secret_scanning".to_string(), Value::from("enabled")),
+                ("secret_scanning_push_protection".to_string(), Value::from("enabled")),
+            ]),
+            ..Default::default()
+        };
+        let changes = code_security_changes(&desired, &code_security_response());
+        assert_eq!(serde_json::Value::Object(changes), json!({ "secret_scanning_push_protection": "enabled" }));
+        assert!(invalid_code_security_settings(std::slice::from_ref(&desired)).is_empty());
 
-        let mut config = config;
-        // Apply default webhook *before* iterating repos for sync
-        if let Some(default_webhook) = &config.default_webhook {
-            for repo in &mut config.repos {
-                if repo.webhook.is_none() {
-                    repo.webhook = Some(default_webhook.clone());
-                }
-            }
-        } else {
-             // If no default webhook, ensure all repos have one explicitly defined
-             if config.repos.iter().any(|r| r.webhook.is_none()) {
-                  return Err(AppError::GitHubApi(
-                     "Sync requires either a 'default_webhook' or explicit 'webhook' definition for every repo in the config.".to_string()
-                 ));
-             }
-        }
+        let typo = CodeSecurityConfiguration { settings: BTreeMap::from([("secret_scaning".to_string(), Value::from("enabled"))]), ..desired };
+        assert_eq!(invalid_code_security_settings(std::slice::from_ref(&typo)), vec!["strict: secret_scaning"]);
+        assert!(check_code_security_configurations(&Config { code_security_configurations: Some(vec![typo]), ..Default::default() }).is_err());
+    }
 
-        // Sync resources
-        for repo in &config.repos {
-            // --- Add logging just before the failing call ---
-            info!("Processing repo: {}/{}", self.org, repo.name);
-            if repo.name.trim().is_empty() {
-                 error!("Found repo with empty name in config file '{}'.", config_path);
-                 return Err(AppError::GitHubApi("Invalid empty repo name found in config.".to_string()));
-            }
-            // You might add more validation for repo.name characters here if needed
-            // --- End logging ---
+    #[test]
+    fn test_plan_attachment_changes() {
+        let repos = vec![("web".to_string(), Visibility::Public), ("api".to_string(), Visibility::Private), ("infra".to_string(), Visibility::Internal)];
+        let desired = CodeSecurityConfiguration {
+            name: "strict".to_string(),
+            attach_to: Some(AttachScope::PrivateOrInternal),
+            repositories: Some(vec!["docs".to_string()]),
+            ..Default::default()
+        };
+        let expected = expected_attachments(&desired, &repos).unwrap();
+        assert_eq!(expected, vec!["api", "docs", "infra"]);
+        assert_eq!(expected_attachments(&CodeSecurityConfiguration::default(), &repos), None);
 
-            self.update_repo_settings(repo, dry_run).await?; // This is the first call in the loop
-        }
+        let attached = vec!["API".to_string(), "web".to_string()];
+        assert_eq!(plan_attachment_changes(&expected, &attached, false), (vec!["docs".to_string(), "infra".to_string()], vec![]));
+        assert_eq!(plan_attachment_changes(&expected, &attached, true).1, vec!["web".to_string()]);
+    }
 
-        // Teams
-        for team in &config.teams {
-            info!("Processing team: {}", team.name); // Add similar logging for other resources
-          self.create_team(team, dry_run).await?;
-        }
+    #[test]
+    fn test_normalize_code_security_for_diff() {
+        let attached = vec!["web".to_string(), "api".to_string()];
+        let exported = code_security_configuration_from_response(code_security_response(), Some(DefaultForNewRepos::All), attached);
+        assert_eq!(exported.settings.len(), 3);
+        assert!(!exported.settings.contains_key("html_url"));
+
+        let mut local = Config {
+            code_security_configurations: Some(vec![CodeSecurityConfiguration {
+                name: "strict".to_string(),
+                settings: BTreeMap::from([("secret_scanning".to_string(), Value::from("enabled"))]),
+                attach_to: Some(AttachScope::Public),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let mut github = Config {
+            code_security_configurations: Some(vec![exported]),
+            repos: vec![
+                Repo { name: "web".to_string(), visibility: Some(Visibility::Public), ..Default::default() },
+                Repo { name: "api".to_string(), visibility: Some(Visibility::Private), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_code_security_for_diff(&mut local, &mut github);
+        // `api` is attached without being selected, which sync only undoes with --prune
+        assert_eq!(local.code_security_configurations, github.code_security_configurations);
 
-        // Users
-        for user in &config.users {
-            info!("Processing user: {}", user.login); // Add similar logging
-            self.add_user_to_org(user, dry_run).await?;
-        }
+        let mut unmanaged = Config::default();
+        normalize_code_security_for_diff(&mut unmanaged, &mut github);
+        assert_eq!(github.code_security_configurations, None);
+    }
 
-        // Assignments
-        for assignment in &config.assignments {
-            info!("Processing assignment: Team '{}' on Repo '{}'", assignment.team, assignment.repo); // Add similar logging
-            self.assign_team_to_repo(assignment, dry_run).await?;
-        }
+    #[test]
+    fn test_property_value_json() {
+        assert_eq!(property_value_json(&Value::from(true)), json!("true"));
+        assert_eq!(property_value_json(&Value::from(3)), json!("3"));
+        assert_eq!(property_value_json(&Value::from("prod")), json!("prod"));
+        assert_eq!(property_value_json(&Value::Null), serde_json::Value::Null);
+        let list: Value = serde_yaml::from_str("[a, 1]").unwrap();
+        assert_eq!(property_value_json(&list), json!(["a", "1"]));
+    }
 
-        if dry_run {
-            println!("Dry run completed successfully. No changes were applied.");
-        } else {
-            println!("Sync completed successfully. All changes applied.");
-        }
-        Ok(())
+    #[test]
+    fn test_custom_property_from_response() {
+        let response: CustomPropertyResponse = serde_json::from_value(json!({
+            "property_name": "tier",
+            "value_type": "single_select",
+            "required": true,
+            "default_value": "3",
+            "description": null,
+            "allowed_values": ["1", "2", "3"],
+            "values_editable_by": "org_actors"
+        }))
+        .unwrap();
+        let mut exported = custom_property_from_response(response);
+        assert_eq!(exported.value_type, CustomPropertyType::SingleSelect);
+        assert_eq!(exported.allowed_values, vec!["1", "2", "3"]);
+
+        let mut local: CustomProperty =
+            serde_yaml::from_str("name: tier\nvalue_type: single_select\nrequired: true\ndefault_value: 3\nallowed_values: ['1', '2', '3']").unwrap();
+        normalize_custom_property(&mut local);
+        normalize_custom_property(&mut exported);
+        assert_eq!(local, exported);
+
+        let body = custom_property_body(&local);
+        assert_eq!(body["default_value"], json!("3"));
+        assert_eq!(body["values_editable_by"], json!("org_actors"));
     }
 
-    /// Generates a Config object representing the current GitHub state,
-    /// but ONLY includes resources and fields explicitly mentioned in the provided local_config.
-    /// This is used specifically for the `diff` command.
-    async fn generate_filtered_config_from_org(&self, local_config: &Config) -> AppResult<Config> {
-        info!("Fetching relevant GitHub state based on local config structure for diffing.");
+    #[test]
+    fn test_plan_property_value_updates() {
+        let team = BTreeMap::from([("team".to_string(), Value::from("core"))]);
+        let team_and_tier = BTreeMap::from([("team".to_string(), Value::from("core")), ("tier".to_string(), Value::from(1))]);
+        let names: Vec<String> = (0..32).map(|i| format!("repo-{}", i)).collect();
+        let mut desired: Vec<(&str, &BTreeMap<String, Value>)> = names.iter().map(|n| (n.as_str(), &team)).collect();
+        desired.push(("Web", &team_and_tier));
+        let current = HashMap::from([
+            ("repo-0".to_string(), BTreeMap::from([("team".to_string(), json!("core"))])),
+            ("web".to_string(), BTreeMap::from([("team".to_string(), json!("core"))])),
+        ]);
+
+        let requests = plan_property_value_updates(&desired, &current);
+        assert_eq!(requests.len(), 3);
+        let team_requests: Vec<_> = requests.iter().filter(|r| r["properties"] == json!([{"property_name": "team", "value": "core"}])).collect();
+        assert_eq!(team_requests.len(), 2);
+        assert_eq!(team_requests[0]["repository_names"].as_array().unwrap().len(), PROPERTY_VALUES_BATCH_SIZE);
+        assert_eq!(team_requests[1]["repository_names"].as_array().unwrap().len(), 1);
+        assert!(!requests.iter().any(|r| r["repository_names"].as_array().unwrap().contains(&json!("repo-0"))));
+        assert!(requests.contains(&json!({"repository_names": ["Web"], "properties": [{"property_name": "tier", "value": "1"}]})));
+    }
 
-        let mut filtered_github_config = Config {
-            org: self.org.clone(),
-            repos: Vec::new(),
-            teams: Vec::new(),
-            users: Vec::new(),
-            assignments: Vec::new(),
-            // We don't compare default_webhook or default_branch_protections directly in diff,
-            // they are applied to individual repos before comparison.
-            default_webhook: None, // Not needed for filtered diff comparison
-            default_branch_protections: Vec::new(), // Not needed for filtered diff comparison
-            extra: HashMap::new(), // Ignore extra fields for diff
+    #[test]
+    fn test_check_custom_properties() {
+        let property = |name: &str| CustomProperty {
+            name: name.to_string(),
+            value_type: CustomPropertyType::String,
+            required: false,
+            default_value: None,
+            description: None,
+            allowed_values: vec![],
+            values_editable_by: None,
         };
+        let repo = Repo { name: "web".to_string(), properties: Some(BTreeMap::from([("tier".to_string(), Value::from(1))])), ..Default::default() };
+        let config = |schema: Option<Vec<CustomProperty>>| Config { custom_properties: schema, repos: vec![repo.clone()], ..Default::default() };
+        assert!(check_custom_properties(&config(None)).is_ok());
+        assert!(check_custom_properties(&config(Some(vec![property("tier")]))).is_ok());
+        assert!(check_custom_properties(&config(Some(vec![property("team")]))).is_err());
+        let required = CustomProperty { required: true, ..property("tier") };
+        assert!(check_custom_properties(&config(Some(vec![required]))).is_err());
+    }
 
-        // --- Filter Repos ---
-        // Get the names of repos defined locally
-        let local_repo_names: HashSet<&str> = local_config.repos.iter().map(|r| r.name.as_str()).collect();
+    #[test]
+    fn test_normalize_custom_properties_for_diff() {
+        let mut local = Config {
+            repos: vec![Repo {
+                name: "web".to_string(),
+                properties: Some(BTreeMap::from([("tier".to_string(), Value::from(1)), ("owner".to_string(), Value::Null)])),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut github = Config {
+            custom_properties: Some(Vec::new()),
+            repos: vec![
+                Repo {
+                    name: "web".to_string(),
+                    properties: Some(BTreeMap::from([("tier".to_string(), Value::from("1")), ("team".to_string(), Value::from("core"))])),
+                    ..Default::default()
+                },
+                Repo { name: "api".to_string(), properties: Some(BTreeMap::new()), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_custom_properties_for_diff(&mut local, &mut github);
+        assert_eq!(github.custom_properties, None);
+        assert_eq!(local.repos[0].properties, github.repos[0].properties);
+        assert_eq!(github.repos[1].properties, Some(BTreeMap::new()));
+    }
 
-        // Fetch settings only for locally defined repos
-        let mut github_repo_settings_map = HashMap::new();
-        let mut unprocessed_repos = Vec::new(); // Store full repo data temporarily
+    #[test]
+    fn test_plan_security_changes() {
+        let current = RepoSecurity {
+            secret_scanning: Some(true),
+            secret_scanning_push_protection: Some(false),
+            dependabot_alerts: Some(false),
+            dependabot_security_updates: Some(false),
+            code_scanning_default_setup: None,
+            ..Default::default()
+        };
+        let desired = RepoSecurity {
+            secret_scanning: Some(true),
+            secret_scanning_push_protection: Some(true),
+            dependabot_alerts: Some(true),
+            dependabot_security_updates: Some(true),
+            code_scanning_default_setup: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            plan_security_changes(&desired, &current),
+            vec![
+                SecurityChange::AnalysisFeatures(json!({ "secret_scanning_push_protection": { "status": "enabled" } })),
+                SecurityChange::Toggle { path: "/vulnerability-alerts", enable: true },
+                SecurityChange::Toggle { path: "/automated-security-fixes", enable: true },
+                SecurityChange::CodeScanningDefaultSetup(true),
+            ]
+        );
 
-        let repo_url = format!("https://api.github.com/orgs/{}/repos?per_page=100", self.org);
-        let repo_response = self.get(&repo_url).await?;
-        let repo_json: Vec<serde_json::Value> = repo_response.json().await.map_err(AppError::Http)?;
+        // Security updates are switched off before the alerts they depend on
+        let disable = RepoSecurity { dependabot_alerts: Some(false), dependabot_security_updates: Some(false), ..Default::default() };
+        assert_eq!(
+            plan_security_changes(&disable, &desired),
+            vec![
+                SecurityChange::Toggle { path: "/automated-security-fixes", enable: false },
+                SecurityChange::Toggle { path: "/vulnerability-alerts", enable: false },
+            ]
+        );
+        assert!(plan_security_changes(&RepoSecurity::default(), &current).is_empty());
+    }
 
-        for repo_data in repo_json {
-             if let Some(name) = repo_data["name"].as_str() {
-                if local_repo_names.contains(name) {
-                    // Fetch detailed settings ONLY if the repo is in the local config
-                    match self.get_repo_settings(name).await {
-                       Ok(settings) => {
-                           github_repo_settings_map.insert(name.to_string(), settings);
-                           unprocessed_repos.push(repo_data.clone()); // Store for visibility/webhook check
-                       },
-                       Err(e) => {
-                           error!("Failed to get settings for repo {}: {}. Skipping for diff.", name, e);
-                           // Optionally decide how to handle repos that exist locally but fail to fetch from GitHub
-                       }
-                    }
-                }
-            }
-        }
+    #[test]
+    fn test_security_from_repo_json() {
+        let repo = json!({ "security_and_analysis": {
+            "secret_scanning": { "status": "enabled" },
+            "secret_scanning_push_protection": { "status": "disabled" }
+        } });
+        let security = security_from_repo_json(&repo);
+        assert_eq!(security.secret_scanning, Some(true));
+        assert_eq!(security.secret_scanning_push_protection, Some(false));
+        assert_eq!(security.secret_scanning_non_provider_patterns, None);
+        assert_eq!(security_from_repo_json(&json!({})), RepoSecurity::default());
+    }
 
+    #[test]
+    fn test_normalize_security_for_diff() {
+        let mut local = Config {
+            default_security: Some(RepoSecurity { secret_scanning_push_protection: Some(true), ..Default::default() }),
+            repos: vec![
+                Repo { name: "web".to_string(), security: Some(RepoSecurity { dependabot_alerts: Some(true), ..Default::default() }), ..Default::default() },
+                Repo { name: "api".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let github_security = RepoSecurity {
+            secret_scanning: Some(true),
+            secret_scanning_push_protection: Some(false),
+            dependabot_alerts: Some(true),
+            ..Default::default()
+        };
+        let mut github = Config {
+            repos: vec![
+                Repo { name: "web".to_string(), security: Some(github_security.clone()), ..Default::default() },
+                Repo { name: "api".to_string(), security: Some(github_security), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_security_for_diff(&mut local, &mut github);
+        assert_eq!(local.default_security, None);
+        assert_eq!(
+            github.repos[0].security,
+            Some(RepoSecurity { secret_scanning_push_protection: Some(false), dependabot_alerts: Some(true), ..Default::default() })
+        );
+        assert_eq!(local.repos[1].security, Some(RepoSecurity { secret_scanning_push_protection: Some(true), ..Default::default() }));
+        assert_eq!(github.repos[1].security, Some(RepoSecurity { secret_scanning_push_protection: Some(false), ..Default::default() }));
+    }
 
-        for local_repo in &local_config.repos {
-            let repo_name = &local_repo.name;
+    #[test]
+    fn test_plan_actions_policy_requests() {
+        let current = ActionsPolicy {
+            allowed_actions: Some(AllowedActions::Selected),
+            selected_actions: Some(SelectedActions { github_owned_allowed: true, verified_allowed: false, patterns_allowed: vec!["b/*".to_string(), "a/*".to_string()] }),
+            default_workflow_permissions: Some(WorkflowPermissions::Write),
+            can_approve_pull_request_reviews: Some(false),
+            fork_pr_approval: None,
+        };
 
-            // Find the basic repo data fetched earlier
-            let github_basic_data = unprocessed_repos.iter().find(|r| r["name"].as_str() == Some(repo_name));
-             // Find the detailed settings fetched earlier
-            let full_github_settings = github_repo_settings_map.get(repo_name);
+        // Matching values and unset fields don't produce requests
+        let desired = ActionsPolicy {
+            selected_actions: Some(SelectedActions { github_owned_allowed: true, verified_allowed: false, patterns_allowed: vec!["a/*".to_string(), "b/*".to_string()] }),
+            can_approve_pull_request_reviews: Some(false),
+            ..Default::default()
+        };
+        assert!(plan_actions_policy_requests(("enabled", Some(json!(true)), json!(true)), &desired, &current).is_empty());
 
-            if let (Some(basic_data) , Some(github_settings) ) = (github_basic_data, full_github_settings) {
-                let mut filtered_settings = RepoSettings::new();
-                // Only include settings that are present in the local config's settings map
-                for key in local_repo.settings.keys() {
-                    if let Some(value) = github_settings.get(key) {
-                        filtered_settings.insert(key.clone(), value.clone());
-                    } else {
-                         // Key is in local config but not returned by API (might be an error or just not set)
-                         // Insert a Null value to explicitly show it's missing in the diff compared to local definition.
-                         filtered_settings.insert(key.clone(), Value::Null);
-                    }
-                }
+        let desired = ActionsPolicy {
+            default_workflow_permissions: Some(WorkflowPermissions::Read),
+            fork_pr_approval: Some(ForkPrApproval::AllExternalContributors),
+            ..Default::default()
+        };
+        let requests = plan_actions_policy_requests(("enabled", Some(json!(false)), json!(true)), &desired, &current);
+        assert_eq!(
+            requests,
+            vec![
+                ("", json!({ "enabled": false, "allowed_actions": "selected" })),
+                ("/workflow", json!({ "default_workflow_permissions": "read", "can_approve_pull_request_reviews": false })),
+                ("/fork-pr-contributor-approval", json!({ "approval_policy": "all_external_contributors" })),
+            ]
+        );
 
-                 // Handle visibility if defined locally
-                let github_visibility = if local_repo.visibility.is_some() {
-                     Some(if basic_data["private"].as_bool().unwrap_or(false) { "private" } else { "public" }.to_string())
-                 } else {
-                     None // Don't include visibility if not in local config
-                 };
+        let desired = ActionsPolicy { allowed_actions: Some(AllowedActions::LocalOnly), ..Default::default() };
+        let requests = plan_actions_policy_requests(("enabled_repositories", None, json!("all")), &desired, &current);
+        assert_eq!(requests, vec![("", json!({ "enabled_repositories": "all", "allowed_actions": "local_only" }))]);
+    }
 
-                 // Handle webhook if defined locally (either directly or via default)
-                 let github_webhook = if local_repo.webhook.is_some() || local_config.default_webhook.is_some() { // Check if local *config* effectively has a webhook
-                    match self.get_webhooks(repo_name).await {
-                        Ok(hooks) => {
-                            // Determine the target URL: Use explicit if defined, else default if available
-                            let target_url = match &local_repo.webhook {
-                                Some(wh) => &wh.url,
-                                None => match &local_config.default_webhook { // Check the original local_config
-                                    Some(def_wh) => &def_wh.url,
-                                    None => { // Should not happen if the outer 'if' is correct, but safeguard
-                                        error!("Inconsistency: Webhook check requested for repo {} but no effective webhook URL found.", repo_name);
-                                        continue; // Skip webhook processing
-                                    }
-                                }
-                            };
+    #[test]
+    fn test_validate_actions_policy() {
+        let selected = Some(SelectedActions::default());
+        let policy = ActionsPolicy { allowed_actions: Some(AllowedActions::Selected), selected_actions: selected.clone(), ..Default::default() };
+        assert!(validate_actions_policy("my-org", &policy).is_ok());
+        let policy = ActionsPolicy { allowed_actions: Some(AllowedActions::All), selected_actions: selected, ..Default::default() };
+        assert!(validate_actions_policy("my-org", &policy).is_err());
+
+        let repo = Repo { name: "web".to_string(), actions: Some(RepoActions { policy, ..Default::default() }), ..Default::default() };
+        assert!(check_actions(&Config { org: "my-org".to_string(), repos: vec![repo], ..Default::default() }).is_err());
+        let org_actions = OrgActions { selected_repositories: vec!["web".to_string()], ..Default::default() };
+        assert!(check_actions(&Config { actions: Some(org_actions), ..Default::default() }).is_err());
+    }
 
-                            // Find the hook matching the target URL from GitHub API response
-                            hooks.iter().find(|h| h.config.url == *target_url).map(|wh| {
-                                // Create WebhookConfig from the found GitHub hook
-                                let mut events = wh.events.clone();
-                                events.sort(); // <-- SORT EVENTS HERE
-                                WebhookConfig {
-                                    url: wh.config.url.clone(),
-                                    content_type: wh.config.content_type.clone(),
-                                    events, // Use sorted events
-                                }
-                            })
-                        },
-                        Err(e) => {
-                           error!("Failed to get webhooks for repo {}: {}. Skipping webhook diff.", repo_name, e);
-                           None
-                        },
-                    }
-                } else {
-                    None // Don't include webhook if not effectively defined locally
-                };
+    #[test]
+    fn test_normalize_actions_permissions_for_diff() {
+        let mut local = Config {
+            actions: Some(OrgActions { policy: ActionsPolicy { allowed_actions: Some(AllowedActions::All), ..Default::default() }, ..Default::default() }),
+            repos: vec![
+                Repo { name: "web".to_string(), actions: Some(RepoActions { enabled: Some(true), ..Default::default() }), ..Default::default() },
+                Repo { name: "api".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let github_actions = RepoActions {
+            enabled: Some(true),
+            access_level: Some(ActionsAccessLevel::None),
+            policy: ActionsPolicy { default_workflow_permissions: Some(WorkflowPermissions::Read), ..Default::default() },
+        };
+        let mut github = Config {
+            actions: Some(OrgActions {
+                enabled_repositories: Some(EnabledRepositories::All),
+                policy: ActionsPolicy { allowed_actions: Some(AllowedActions::All), can_approve_pull_request_reviews: Some(true), ..Default::default() },
+                ..Default::default()
+            }),
+            repos: vec![
+                Repo { name: "web".to_string(), actions: Some(github_actions.clone()), ..Default::default() },
+                Repo { name: "api".to_string(), actions: Some(github_actions), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_actions_permissions_for_diff(&mut local, &mut github);
+        assert_eq!(local.actions, github.actions);
+        assert_eq!(local.repos[0].actions, github.repos[0].actions);
+        assert_eq!(github.repos[1].actions, None);
+    }
 
+    #[test]
+    fn test_actions_permissions_yaml_round_trips() {
+        let actions = RepoActions {
+            enabled: Some(true),
+            access_level: Some(ActionsAccessLevel::Organization),
+            policy: ActionsPolicy {
+                allowed_actions: Some(AllowedActions::Selected),
+                selected_actions: Some(SelectedActions { github_owned_allowed: true, verified_allowed: false, patterns_allowed: vec!["my-org/*".to_string()] }),
+                default_workflow_permissions: Some(WorkflowPermissions::Read),
+                can_approve_pull_request_reviews: Some(false),
+                fork_pr_approval: Some(ForkPrApproval::FirstTimeContributors),
+            },
+        };
+        let yaml = format!("name: web\n{}", nested_yaml("actions", &actions, ""));
+        let repo: Repo = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(repo.actions, Some(actions));
+    }
 
-                filtered_github_config.repos.push(Repo {
-                    name: repo_name.clone(),
-                    settings: filtered_settings,
-                    visibility: github_visibility,
-                    webhook: github_webhook, // Add the potentially filtered webhook
-                    // Keep branch protections and extra empty as they aren't diffed this way (yet)
-                    branch_protections: vec![],
-                    extra: HashMap::new(),
-                });
-            } else {
-                 // Repo defined locally but not found on GitHub (or settings fetch failed)
-                 // Add a placeholder or log an error. For diff, maybe omit it or add a special marker.
-                 // For now, we'll just omit it, the diff will show the local one as an addition (+)
-                 info!("Repo '{}' defined locally but not found or settings fetch failed on GitHub. Will show as addition in diff.", repo_name);
-            }
-        }
+    #[test]
+    fn test_environment_from_response() {
+        let response: EnvironmentResponse = serde_json::from_value(json!({
+            "name": "production",
+            "protection_rules": [
+                { "id": 1, "type": "wait_timer", "wait_timer": 30 },
+                { "id": 2, "type": "required_reviewers", "prevent_self_review": true, "reviewers": [
+                    { "type": "User", "reviewer": { "login": "alice", "id": 1 } },
+                    { "type": "Team", "reviewer": { "slug": "core", "id": 2 } }
+                ] },
+                { "id": 3, "type": "branch_policy" }
+            ],
+            "deployment_branch_policy": { "protected_branches": false, "custom_branch_policies": true }
+        }))
+        .unwrap();
+        let environment = environment_from_response(&response, vec!["release/*".to_string()]);
+        assert_eq!(environment.wait_timer, 30);
+        assert_eq!(environment.reviewers, EnvironmentReviewers { users: vec!["alice".to_string()], teams: vec!["core".to_string()] });
+        assert!(environment.prevent_self_review);
+        assert_eq!(environment.deployment_branch_policy.unwrap().custom_branches, vec!["release/*"]);
+    }
 
-        // --- Filter Teams ---
-        let local_team_names: HashSet<&str> = local_config.teams.iter().map(|t| t.name.as_str()).collect();
-        let team_url = format!("https://api.github.com/orgs/{}/teams?per_page=100", self.org);
-        match self.get(&team_url).await {
-            Ok(team_response) => {
-                let team_json: Vec<serde_json::Value> = team_response.json().await.map_err(AppError::Http)?;
-                for github_team_data in team_json {
-                    if let Some(name) = github_team_data["slug"].as_str() { // Use slug for member fetching
-                        if local_team_names.contains(name) { // Check against local name
-                            let members_url = format!("https://api.github.com/orgs/{}/teams/{}/members?per_page=100", self.org, name);
-                            match self.get(&members_url).await {
-                                Ok(members_response) => {
-                                     let members_json: Vec<serde_json::Value> = members_response.json().await.map_err(AppError::Http)?;
-                                     let mut members: Vec<String> = members_json.iter()
-                                        .filter_map(|m| m["login"].as_str().map(String::from))
-                                        .collect();
-                                     members.sort(); // Sort for consistent diff
-                                     filtered_github_config.teams.push(Team { name: name.to_string(), members });
-                                },
-                                Err(e) => error!("Failed to get members for team {}: {}. Skipping team for diff.", name, e),
-                            }
-                        }
-                    }
-                 }
-            }
-            Err(e) => error!("Failed to fetch teams from GitHub: {}. Skipping teams diff.", e),
-        }
+    #[test]
+    fn test_environment_protection_and_validation() {
+        let environment = Environment {
+            name: "production".to_string(),
+            reviewers: EnvironmentReviewers { users: vec!["Bob".to_string(), "alice".to_string()], teams: vec![] },
+            deployment_branch_policy: Some(DeploymentBranchPolicy { protected_branches: false, custom_branches: vec!["release/*".to_string(), "main".to_string()] }),
+            secrets: Some(vec![]),
+            ..Default::default()
+        };
+        let protection = environment_protection(&environment);
+        assert_eq!(protection.reviewers.users, vec!["alice", "bob"]);
+        assert_eq!(protection.deployment_branch_policy.unwrap().custom_branches, vec!["main", "release/*"]);
+        assert_eq!(protection.secrets, None);
+        assert!(validate_environment("web", &environment).is_ok());
+
+        let both = Environment {
+            deployment_branch_policy: Some(DeploymentBranchPolicy { protected_branches: true, custom_branches: vec!["main".to_string()] }),
+            ..environment.clone()
+        };
+        assert!(validate_environment("web", &both).is_err());
+        let repo = Repo { name: "web".to_string(), environments: Some(vec![environment.clone(), both]), ..Default::default() };
+        assert!(check_environments(&Config { repos: vec![repo], ..Default::default() }).is_err());
+        let neither = Environment { deployment_branch_policy: Some(DeploymentBranchPolicy::default()), ..environment.clone() };
+        assert!(validate_environment("web", &neither).is_err());
+        let crowded = Environment {
+            reviewers: EnvironmentReviewers { users: (0..7).map(|i| format!("user{}", i)).collect(), teams: vec![] },
+            ..environment
+        };
+        assert!(validate_environment("web", &crowded).is_err());
+    }
 
+    #[test]
+    fn test_plan_branch_policy_changes() {
+        let current = vec![BranchPolicyResponse { id: 1, name: "main".to_string() }, BranchPolicyResponse { id: 2, name: "hotfix/*".to_string() }];
+        let desired = vec!["main".to_string(), "release/*".to_string()];
+        let (create, delete) = plan_branch_policy_changes(&desired, &current);
+        assert_eq!(create, vec!["release/*"]);
+        assert_eq!(delete, vec![(2, "hotfix/*".to_string())]);
+    }
 
-         // --- Filter Users ---
-         let local_user_logins: HashSet<&str> = local_config.users.iter().map(|u| u.login.as_str()).collect();
-         let members_url = format!("https://api.github.com/orgs/{}/members?per_page=100", self.org);
-         match self.get(&members_url).await {
-            Ok(members_response) => {
-                let members_json: Vec<serde_json::Value> = members_response.json().await.map_err(AppError::Http)?;
-                 for member_data in members_json {
-                     if let Some(login) = member_data["login"].as_str() {
-                         if local_user_logins.contains(login) {
-                             match self.get_user_membership(login).await {
-                                 Ok(Some(role)) => {
-                                     filtered_github_config.users.push(User { login: login.to_string(), role });
-                                 },
-                                 Ok(None) => error!("User {} known locally but membership fetch returned None. Skipping user for diff.", login), // Should not happen for members
-                                 Err(e) => error!("Failed to get membership for user {}: {}. Skipping user for diff.", login, e),
-                            }
-                        }
-                    }
-                }
+    #[test]
+    fn test_normalize_environments_for_diff() {
+        let environment = |name: &str, teams: &[&str]| Environment {
+            name: name.to_string(),
+            reviewers: EnvironmentReviewers { users: vec![], teams: teams.iter().map(|t| t.to_string()).collect() },
+            ..Default::default()
+        };
+        let mut local = Config {
+            repos: vec![
+                Repo { name: "web".to_string(), environments: Some(vec![environment("production", &["Platform Eng"])]), ..Default::default() },
+                Repo { name: "api".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let mut github = Config {
+            teams: vec![Team { name: "Platform Eng".to_string(), slug: Some("platform-eng".to_string()), ..Default::default() }],
+            repos: vec![
+                Repo {
+                    name: "web".to_string(),
+                    environments: Some(vec![environment("production", &["platform-eng"]), environment("github-pages", &[]), environment("legacy", &[])]),
+                    ..Default::default()
+                },
+                Repo { name: "api".to_string(), environments: Some(vec![environment("staging", &[])]), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_environments_for_diff(&mut local, &mut github);
+        assert_eq!(local.repos[0].environments, github.repos[0].environments);
+        assert_eq!(github.repos[1].environments, None);
+
+        // An opted-in repo shows the environments sync would delete
+        local.repos[0].prune_environments = true;
+        github.repos[0].environments.as_mut().unwrap().push(environment("legacy", &[]));
+        normalize_environments_for_diff(&mut local, &mut github);
+        assert!(!local.repos[0].prune_environments);
+        let names: Vec<&str> = github.repos[0].environments.iter().flatten().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["legacy", "production"]);
+    }
+
+    #[test]
+    fn test_environment_url_encodes_name() {
+        let client = GitHubClient::new("dummy_token", "my-org");
+        assert_eq!(
+            client.environment_url("web", "staging/eu west"),
+            "https://api.github.com/repos/my-org/web/environments/staging%2Feu%20west"
+        );
+        assert_eq!(
+            client.environment_actions_scope("web", "qa#1").url,
+            "https://api.github.com/repos/my-org/web/environments/qa%231"
+        );
+    }
+
+    #[test]
+    fn test_unlisted_environments_skip_system_managed() {
+        let environment = |name: &str| Environment { name: name.to_string(), ..Default::default() };
+        let current = vec![environment("production"), environment("github-pages"), environment("legacy")];
+        assert_eq!(unlisted_environments(&current, &[environment("production")]), vec!["legacy"]);
+        assert!(unlisted_environments(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_environments_yaml_round_trips() {
+        let environments = vec![Environment {
+            name: "production".to_string(),
+            wait_timer: 30,
+            reviewers: EnvironmentReviewers { users: vec!["alice".to_string()], teams: vec!["core".to_string()] },
+            prevent_self_review: true,
+            deployment_branch_policy: Some(DeploymentBranchPolicy { protected_branches: false, custom_branches: vec!["release/*".to_string()] }),
+            secrets: Some(vec![ActionsSecret { name: "TOKEN".to_string(), value: SecretRef::Env("TOKEN".to_string()), visibility: None, selected_repositories: vec![] }]),
+            variables: Some(vec![ActionsVariable { name: "URL".to_string(), value: "https://example.com".to_string(), ..Default::default() }]),
+        }];
+        let yaml = format!("name: web\n{}", environments_yaml(&environments, ""));
+        let repo: Repo = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(repo.environments, Some(environments));
+    }
+
+    #[test]
+    fn test_repo_webhook_plan_without_any_webhooks() {
+        let yaml = r#"
+org: test-org
+repos:
+  - name: repo1
+    settings:
+      allow_squash_merge: true
+  - name: repo2
+teams: []
+users: []
+assignments: []
+"#;
+        let mut file = tempfile::NamedTempFile::new().expect("create temp file");
+        std::io::Write::write_all(&mut file, yaml.as_bytes()).expect("write config");
+        let config = Config::from_file_with_defaults(file.path().to_str().unwrap(), None).expect("load config");
+
+        assert!(config.default_webhook.is_none());
+        for prune in [false, true] {
+            for repo in &config.repos {
+                assert_eq!(repo_webhook_plan(repo, config.default_webhook.as_ref(), prune), None);
             }
-            Err(e) => error!("Failed to fetch members from GitHub: {}. Skipping users diff.", e),
-         }
+        }
+    }
 
-        // --- Filter Assignments ---
-        // Create lookups for faster checks
-        let local_assignments_set: HashSet<(&str, &str)> = local_config.assignments.iter().map(|a| (a.team.as_str(), a.repo.as_str())).collect();
-        let local_teams_map: HashMap<&str, &Team> = local_config.teams.iter().map(|t| (t.name.as_str(), t)).collect();
+    #[test]
+    fn test_repo_webhook_plan() {
+        let default_webhook = WebhookConfig { url: "https://default.example.com".to_string(), ..Default::default() };
+        let cleared = Repo { name: "cleared".to_string(), webhooks: Some(vec![]), ..Default::default() };
+        let implicit = Repo { name: "implicit".to_string(), ..Default::default() };
+
+        assert_eq!(repo_webhook_plan(&cleared, None, true), Some((&[][..], true)));
+        assert_eq!(repo_webhook_plan(&cleared, Some(&default_webhook), false), Some((&[][..], false)));
+        let (webhooks, delete_unlisted) = repo_webhook_plan(&implicit, Some(&default_webhook), true).unwrap();
+        assert_eq!(webhooks, std::slice::from_ref(&default_webhook));
+        assert!(!delete_unlisted);
+    }
 
-        for local_team_name in local_teams_map.keys() {
-              // Check if team exists on GitHub side (fetched earlier)
-              if filtered_github_config.teams.iter().any(|t| t.name == *local_team_name) {
-                    match self.get_team_repos(local_team_name).await {
-                        Ok(github_team_repos) => {
-                            for github_repo_perm in github_team_repos {
-                                // Only include assignments if the (team, repo) pair is in local config
-                                if local_assignments_set.contains(&(local_team_name, github_repo_perm.name.as_str())) {
-                                    let permission = if github_repo_perm.permissions.admin {
-                                        "admin"
-                                    } else if github_repo_perm.permissions.push {
-                                        "push" // Changed from "write" to "push" to match config example
-                                    } else if github_repo_perm.permissions.pull {
-                                        "pull" // changed from "read" to "pull"
-                                    } else {
-                                        "none"
-                                    };
-                                    if permission != "none" {
-                                        filtered_github_config.assignments.push(Assignment {
-                                             repo: github_repo_perm.name.clone(),
-                                             team: local_team_name.to_string(),
-                                             permission: permission.to_string(),
-                                         });
-                                    }
-                                 }
-                             }
-                         },
-                        Err(e) => error!("Failed to get repos for team {}: {}. Skipping assignments for this team.", local_team_name, e),
-                     }
-                }
-         }
+    #[test]
+    fn test_plan_deploy_key_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_file = dir.path().join("ci.pub");
+        std::fs::write(&key_file, "ssh-ed25519 AAAAnew ci@example.com\n").unwrap();
+        let configured = vec![
+            DeployKey { title: "ci".to_string(), key_file: Some(key_file.to_string_lossy().into_owned()), ..Default::default() },
+            DeployKey { title: "docs".to_string(), key: Some("ssh-ed25519 AAAAdocs".to_string()), read_only: true, ..Default::default() },
+            DeployKey { title: "release".to_string(), key: Some("ssh-ed25519 AAAArelease".to_string()), read_only: false, ..Default::default() },
+        ];
+        let desired = resolve_deploy_keys(&configured).unwrap();
+        assert_eq!(desired[0].key.as_deref(), Some("ssh-ed25519 AAAAnew"));
+        assert!(resolve_deploy_keys(&[DeployKey { title: "none".to_string(), ..Default::default() }]).is_err());
+        let missing = DeployKey { title: "ci".to_string(), key_file: Some(dir.path().join("missing.pub").to_string_lossy().into_owned()), ..Default::default() };
+        let repo = Repo { name: "web".to_string(), deploy_keys: Some(vec![missing]), ..Default::default() };
+        assert!(check_deploy_keys(&Config { repos: vec![repo], ..Default::default() }).is_err());
+
+        let existing = |id: u64, title: &str, key: &str, read_only: bool| DeployKeyResponse { id, title: title.to_string(), key: key.to_string(), read_only };
+        let current = vec![
+            existing(1, "ci", "ssh-ed25519 AAAAold", false),
+            existing(2, "docs", "ssh-ed25519 AAAAdocs", false),
+            existing(3, "old", "ssh-ed25519 AAAAold", true),
+        ];
+        assert_eq!(
+            plan_deploy_key_changes(&desired, &current, false),
+            vec![DeployKeyChange::Replace(1, &desired[0]), DeployKeyChange::ChangeAccess(2, &desired[1]), DeployKeyChange::Add(&desired[2])]
+        );
+        assert_eq!(plan_deploy_key_changes(&desired, &current, true).last(), Some(&DeployKeyChange::Delete(3, "old".to_string())));
+    }
 
+    #[test]
+    fn test_normalize_deploy_keys_for_diff() {
+        let deploy_key = |key: &str| DeployKey { title: "ci".to_string(), key: Some(key.to_string()), read_only: true, ..Default::default() };
+        let mut local = Config {
+            repos: vec![
+                Repo { name: "web".to_string(), deploy_keys: Some(vec![deploy_key("ssh-ed25519 AAAAnew comment")]), ..Default::default() },
+                Repo { name: "api".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let mut github = Config {
+            repos: vec![
+                Repo { name: "web".to_string(), deploy_keys: Some(vec![deploy_key("ssh-ed25519 AAAAold")]), ..Default::default() },
+                Repo { name: "api".to_string(), deploy_keys: Some(vec![deploy_key("ssh-ed25519 AAAAapi")]), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let notes = normalize_deploy_keys_for_diff(&mut local, &mut github);
+        assert_eq!(notes, vec!["Deploy key 'ci' of repo 'web' would be deleted and added again"]);
+        assert_eq!(local.repos[0].deploy_keys, Some(vec![deploy_key("ssh-ed25519 AAAAnew")]));
+        assert_eq!(github.repos[1].deploy_keys, None);
+    }
 
-        Ok(filtered_github_config)
+    #[test]
+    fn test_plan_repo_metadata() {
+        let current = json!({
+            "description": null,
+            "homepage": "https://example.com",
+            "topics": ["rust", "cli"],
+            "default_branch": "master",
+        });
+        let unmanaged = Repo { name: "web".to_string(), ..Default::default() };
+        assert_eq!(plan_repo_metadata(&unmanaged, &current), RepoMetadataPlan::default());
+
+        let unchanged = Repo {
+            description: Some(String::new()),
+            homepage: Some("https://example.com".to_string()),
+            topics: Some(vec!["CLI".to_string(), "rust".to_string()]),
+            default_branch: Some("master".to_string()),
+            ..unmanaged.clone()
+        };
+        assert_eq!(plan_repo_metadata(&unchanged, &current), RepoMetadataPlan::default());
+
+        let changed = Repo {
+            description: Some("Web frontend".to_string()),
+            homepage: Some(String::new()),
+            topics: Some(vec![]),
+            default_branch: Some("main".to_string()),
+            ..unmanaged
+        };
+        let plan = plan_repo_metadata(&changed, &current);
+        assert_eq!(serde_json::Value::Object(plan.patch), json!({ "description": "Web frontend", "homepage": "" }));
+        assert_eq!(plan.topics, Some(vec![]));
+        assert_eq!(plan.default_branch, Some(("master".to_string(), "main".to_string())));
     }
 
-    /// Diffs the local configuration against the full GitHub org state (as written by sync-from-github).
-    pub async fn diff(&self, config_path: &str) -> AppResult<bool> {
-        info!("Generating diff between full GitHub org state and local config: {} (this matches what sync-from-github would write)", config_path);
+    #[test]
+    fn test_check_default_branch_rename() {
+        let repo = Repo { name: "web".to_string(), default_branch: Some("main".to_string()), ..Default::default() };
+        assert!(matches!(check_default_branch_rename(&repo, "master", "main"), Err(AppError::Validation(_))));
 
-        // --- Step 1: Load original local config & track explicit webhooks ---
-        let local_config = crate::config::Config::from_file_with_defaults(config_path, None)?;
-        let local_default_webhook = local_config.default_webhook.clone();
-        // Keep track of original explicit webhooks
-        let originally_explicit_webhooks: HashSet<String> = local_config.repos.iter()
-            .filter(|r| r.webhook.is_some())
-            .map(|r| r.name.clone())
-            .collect();
+        let opted_in = Repo { rename_default_branch_from: Some("master".to_string()), ..repo };
+        assert!(check_default_branch_rename(&opted_in, "master", "main").is_ok());
+        assert!(matches!(check_default_branch_rename(&opted_in, "develop", "main"), Err(AppError::Validation(_))));
+    }
 
-        // --- Step 2: Fetch FULL GitHub org state (unfiltered, as sync-from-github would write) ---
-        let github_config = self.generate_unfiltered_config_from_org().await?;
+    #[test]
+    fn test_normalize_repo_metadata_for_diff() {
+        let mut local = Config {
+            repos: vec![Repo {
+                name: "web".to_string(),
+                description: Some(String::new()),
+                topics: Some(vec!["Rust".to_string()]),
+                default_branch: Some("main".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut github = Config {
+            repos: vec![Repo {
+                name: "web".to_string(),
+                description: Some("Old description".to_string()),
+                homepage: Some("https://example.com".to_string()),
+                default_branch: Some("master".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        normalize_repo_metadata_for_diff(&mut local, &mut github);
+        let (local_repo, github_repo) = (&local.repos[0], &github.repos[0]);
+        assert_eq!(local_repo.description, None);
+        assert_eq!(github_repo.description.as_deref(), Some("Old description"));
+        assert_eq!(github_repo.homepage, None);
+        assert_eq!(local_repo.topics, Some(vec!["rust".to_string()]));
+        assert_eq!(github_repo.topics, Some(vec![]));
+        assert_eq!(github_repo.default_branch.as_deref(), Some("master"));
+    }
 
-        // --- Step 3: Prepare final versions for diffing ---
-        let mut diff_local_config = local_config.clone(); // Clone original local config
-        let mut diff_github_config = github_config;      // Use the fetched FULL GitHub org state
+    #[test]
+    fn test_plan_label_changes() {
+        let label = |name: &str, color: &str| Label { name: name.to_string(), color: color.to_string(), ..Default::default() };
+        let current: Vec<(String, Label)> = ["bug", "defect", "wontfix", "docs"]
+            .iter()
+            .map(|name| Label::from_response(LabelResponse { name: name.to_string(), color: "d73a4a".to_string(), description: Some(String::new()) }))
+            .collect();
+        let desired = vec![
+            // Unchanged apart from the leading `#` and case of the color
+            label("bug", "#D73A4A"),
+            // Renamed from `defect`
+            Label { previous_name: Some("defect".to_string()), ..label("regression", "d73a4a") },
+            // Only the case of the name changes
+            label("Docs", "d73a4a"),
+            label("feature", "a2eeef"),
+        ];
+
+        let changes = plan_resource_changes(&desired, &current, false);
+        assert_eq!(
+            changes,
+            vec![
+                ResourceChange::Update("defect".to_string(), &desired[1]),
+                ResourceChange::Update("docs".to_string(), &desired[2]),
+                ResourceChange::Create(&desired[3]),
+            ]
+        );
+        let pruned = plan_resource_changes(&desired, &current, true);
+        assert_eq!(pruned.last(), Some(&ResourceChange::Delete("wontfix".to_string(), "wontfix".to_string())));
+        assert_eq!(pruned.len(), 4);
 
-        // --- Step 4: Apply local default webhook logic to the local config *copy* ---
-        let mut sorted_local_default_webhook = local_default_webhook.clone(); // Clone to sort optional default
-        if let Some(ref mut wh) = sorted_local_default_webhook {
-            wh.events.sort(); // Sort events in the default webhook we'll use for comparison
-        }
+        assert_eq!(desired[1].update_body(), json!({ "new_name": "regression", "color": "d73a4a" }));
+        assert_eq!(encode_path_segment("good first issue"), "good%20first%20issue");
+    }
 
-        if let Some(ref default_webhook) = local_default_webhook { // Use original for application logic
-            for repo in &mut diff_local_config.repos {
-                if repo.webhook.is_none() {
-                    repo.webhook = Some(default_webhook.clone());
-                }
-                // Sort events for consistent comparison (both applied default and explicit)
-                if let Some(wh) = repo.webhook.as_mut() {
-                    wh.events.sort();
-                }
-            }
-        }
-        // Events in diff_github_config were sorted during generate_filtered_config_from_org
+    #[test]
+    fn test_plan_autolink_and_milestone_changes() {
+        let autolink = Autolink { key_prefix: "JIRA-".to_string(), url_template: "https://jira.example.com/browse/JIRA-<num>".to_string(), is_alphanumeric: true };
+        let current = vec![Autolink::from_response(AutolinkResponse {
+            id: 7,
+            key_prefix: "JIRA-".to_string(),
+            url_template: "https://old.example.com/JIRA-<num>".to_string(),
+            is_alphanumeric: true,
+        })];
+        let desired = vec![autolink];
+        // Autolinks can't be updated, so a changed one is replaced
+        assert_eq!(
+            plan_resource_changes(&desired, &current, false),
+            vec![ResourceChange::Delete("7".to_string(), "JIRA-".to_string()), ResourceChange::Create(&desired[0])]
+        );
 
-        // --- Step 5: Normalization - Remove matching default webhooks for both configs ---
-        // Helper closure to normalize webhooks and default_webhook
-        let normalize_webhooks = |config: &mut crate::config::Config| {
-            // Sort default webhook events for comparison
-            let mut sorted_default = config.default_webhook.clone();
-            if let Some(ref mut wh) = sorted_default { wh.events.sort(); }
+        let current = vec![Milestone::from_response(MilestoneResponse {
+            number: 3,
+            title: "v1.0".to_string(),
+            description: None,
+            due_on: Some("2026-03-31T07:00:00Z".to_string()),
+            state: MilestoneState::Closed,
+        })];
+        // The state isn't managed and the due date matches, so nothing changes
+        let desired = vec![Milestone { title: "v1.0".to_string(), due_on: Some("2026-03-31".to_string()), ..Default::default() }];
+        assert!(plan_resource_changes(&desired, &current, true).is_empty());
+        let reopened = vec![Milestone { state: Some(MilestoneState::Open), ..desired[0].clone() }];
+        assert_eq!(plan_resource_changes(&reopened, &current, true), vec![ResourceChange::Update("3".to_string(), &reopened[0])]);
+        assert_eq!(reopened[0].create_body(), json!({ "title": "v1.0", "due_on": "2026-03-31T00:00:00Z", "state": "open" }));
+    }
 
-            // Track repos that actually use the default
-            let mut used_default = false;
-            for repo in &mut config.repos {
-                // Sort events for explicit webhooks
-                if let Some(ref mut wh) = repo.webhook { wh.events.sort(); }
-                // If repo webhook matches default, set to None for diffing
-                if let (Some(ref wh), Some(ref def)) = (&repo.webhook, &sorted_default) {
-                    if wh == def {
-                        repo.webhook = None;
-                        used_default = true;
-                    }
-                } else if repo.webhook.is_none() && sorted_default.is_some() {
-                    // If repo has no webhook, it implicitly uses the default
-                    used_default = true;
-                }
-            }
-            // If no repo uses the default, set it to None for diffing
-            if !used_default {
-                config.default_webhook = None;
-            } else {
-                // Always store sorted version for diffing
-                config.default_webhook = sorted_default;
-            }
+    #[test]
+    fn test_normalize_repo_resources_for_diff() {
+        let label = |name: &str| Label { name: name.to_string(), color: "ededed".to_string(), ..Default::default() };
+        let mut local = Config {
+            default_labels: Some(vec![label("triage")]),
+            repos: vec![
+                Repo { name: "web".to_string(), ..Default::default() },
+                Repo { name: "api".to_string(), labels: Some(vec![label("bug")]), ..Default::default() },
+            ],
+            ..Default::default()
         };
-        normalize_webhooks(&mut diff_local_config);
-        normalize_webhooks(&mut diff_github_config);
+        let mut github = Config {
+            repos: vec![
+                Repo { name: "web".to_string(), labels: Some(vec![label("bug"), label("triage")]), ..Default::default() },
+                Repo { name: "api".to_string(), labels: Some(vec![label("bug"), label("triage")]), autolinks: Some(vec![]), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        normalize_repo_resources_for_diff(&mut local, &mut github);
+        assert_eq!(local.default_labels, None);
+        // Other labels are only deleted with --prune, so only the defaulted one is compared
+        assert_eq!(github.repos[0].labels, Some(vec![label("triage")]));
+        assert_eq!(local.repos[0].labels, github.repos[0].labels);
+        // The same goes for an explicit list
+        assert_eq!(github.repos[1].labels, Some(vec![label("bug")]));
+        assert_eq!(local.repos[1].labels, github.repos[1].labels);
+        assert_eq!(github.repos[1].autolinks, None);
+    }
 
-        // --- Step 6: Remove top-level default key if not used by any repo ---
-        // (Handled above in normalization)
+    #[test]
+    fn test_webhook_body_resolves_secret() {
+        std::env::set_var("GH_CONFIG_TEST_WEBHOOK_BODY_SECRET", "hunter2");
+        let webhook = WebhookConfig {
+            url: "https://ci.example.com".to_string(),
+            events: vec!["push".to_string()],
+            secret: Some(crate::config::SecretRef::Env("GH_CONFIG_TEST_WEBHOOK_BODY_SECRET".to_string())),
+            insecure_ssl: true,
+            ..Default::default()
+        };
+        let body = webhook_body(&webhook).unwrap();
+        assert_eq!(body["config"]["secret"], "hunter2");
+        assert_eq!(body["config"]["insecure_ssl"], "1");
+        assert_eq!(body["active"], true);
+    }
 
-        // --- Step 7: Sort both configs... (ensure BTreeMap logic is kept) ---
-        // Repos
-        diff_local_config.repos.sort_by(|a, b| a.name.cmp(&b.name));
-        diff_github_config.repos.sort_by(|a, b| a.name.cmp(&b.name));
+    #[test]
+    fn test_normalize_org_webhooks_for_diff() {
+        let hook = |url: &str, events: &[&str]| WebhookConfig {
+            url: url.to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            ..Default::default()
+        };
+        let mut local = Config { org_webhooks: vec![hook("https://audit.example.com", &["repository", "member"])], ..Default::default() };
+        let mut github = Config {
+            org_webhooks: webhook_configs(serde_json::from_value(json!([
+                { "id": 1, "url": "https://api.github.com/orgs/o/hooks/1", "events": ["repository", "member"],
+                  "config": { "url": "https://audit.example.com", "content_type": "json" } },
+                { "id": 2, "url": "https://api.github.com/orgs/o/hooks/2", "events": ["push"],
+                  "config": { "url": "https://manual.example.com", "content_type": "form" } }
+            ])).unwrap()),
+            ..Default::default()
+        };
 
-        // Teams (and members within teams)
-        diff_local_config.teams.sort_by(|a, b| a.name.cmp(&b.name));
-        diff_github_config.teams.sort_by(|a, b| a.name.cmp(&b.name));
-        for team in &mut diff_local_config.teams { team.members.sort(); }
-        for team in &mut diff_github_config.teams { team.members.sort(); }
+        normalize_org_webhooks_for_diff(&mut local, &mut github);
+        assert_eq!(local.org_webhooks, vec![hook("https://audit.example.com", &["member", "repository"])]);
+        assert_eq!(github.org_webhooks, local.org_webhooks);
+    }
 
-        // Users
-        diff_local_config.users.sort_by(|a, b| a.login.cmp(&b.login));
-        diff_github_config.users.sort_by(|a, b| a.login.cmp(&b.login));
+    #[test]
+    fn test_normalize_collaborators_for_diff() {
+        let collaborator = |login: &str| Collaborator { login: login.to_string(), permission: Permission::Write };
+        let mut local = Config {
+            repos: vec![
+                Repo { name: "managed".to_string(), collaborators: Some(vec![collaborator("zed"), collaborator("amy")]), ..Default::default() },
+                Repo { name: "unmanaged".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let mut github = Config {
+            repos: vec![
+                Repo { name: "managed".to_string(), ..Default::default() },
+                Repo { name: "unmanaged".to_string(), collaborators: Some(vec![collaborator("x")]), ..Default::default() },
+            ],
+            outside_collaborators: Some(vec!["x".to_string()]),
+            ..Default::default()
+        };
 
-        // Assignments
-        diff_local_config.assignments.sort_by(|a, b| a.team.cmp(&b.team).then(a.repo.cmp(&b.repo)));
-        diff_github_config.assignments.sort_by(|a, b| a.team.cmp(&b.team).then(a.repo.cmp(&b.repo)));
+        normalize_collaborators_for_diff(&mut local, &mut github);
+        let logins: Vec<&str> = local.repos[0].collaborators.as_ref().unwrap().iter().map(|c| c.login.as_str()).collect();
+        assert_eq!(logins, vec!["amy", "zed"]);
+        assert_eq!(github.repos[0].collaborators, Some(vec![]));
+        assert_eq!(github.repos[1].collaborators, None);
+        assert_eq!(github.outside_collaborators, None);
+    }
 
-        // --- Step 8: Serialize and Diff ---
-        let github_yaml = serde_yaml::to_string(&diff_github_config)?;
-        let local_yaml = serde_yaml::to_string(&diff_local_config)?;
+    #[test]
+    fn test_check_outside_collaborators() {
+        let collaborator = |login: &str| Collaborator { login: login.to_string(), permission: Permission::Write };
+        let mut config = Config {
+            repos: vec![Repo { name: "web".to_string(), collaborators: Some(vec![collaborator("alice"), collaborator("Contractor")]), ..Default::default() }],
+            users: vec![User { login: "alice".to_string(), role: "member".to_string(), ..Default::default() }],
+            outside_collaborators: Some(vec!["vendor".to_string()]),
+            ..Default::default()
+        };
+        let err = check_outside_collaborators(&config).unwrap_err().to_string();
+        assert!(err.contains("Contractor (repo web)"));
+        assert!(!err.contains("alice"));
+
+        config.outside_collaborators = Some(vec!["contractor".to_string()]);
+        assert!(check_outside_collaborators(&config).is_ok());
+        config.outside_collaborators = None;
+        assert!(check_outside_collaborators(&config).is_ok());
+    }
 
-        // Compute and print diff
-        let diff = TextDiff::from_lines(&github_yaml, &local_yaml);
+    #[test]
+    fn test_normalize_repo_lifecycle_for_diff() {
+        let repo = |name: &str| Repo { name: name.to_string(), ..Default::default() };
+        let config = |repos: Vec<Repo>| Config { org: "org".to_string(), repos, ..Default::default() };
+        let mut local = config(vec![
+            Repo { previous_names: vec!["old".to_string()], ..repo("new") },
+            Repo { transfer_to: Some("elsewhere".to_string()), ..repo("leaving") },
+            Repo { archived: Some(false), rename_default_branch_from: Some("master".to_string()), ..repo("kept") },
+        ]);
+        let mut github = config(vec![repo("old"), repo("leaving"), repo("kept")]);
+
+        let notes = normalize_repo_lifecycle_for_diff(&mut local, &mut github);
+        assert_eq!(notes.len(), 2);
+        let names = |c: &Config| c.repos.iter().map(|r| r.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&local), vec!["new", "kept"]);
+        assert_eq!(names(&github), vec!["new", "kept"]);
+        assert!(local.repos.iter().all(|r| r.previous_names.is_empty() && r.archived.is_none() && r.rename_default_branch_from.is_none()));
+    }
 
-        // Corrected: Bind the configured builder to ensure it lives long enough
-        let mut unified_diff = diff.unified_diff();
-        let unified_diff_builder = unified_diff // Create the builder
-            .context_radius(3)                           // Configure it
-            .header("GitHub (Normalized)", "Local Config (Normalized)"); // Configure it further
+    fn team_response(json: serde_json::Value) -> TeamResponse {
+        serde_json::from_value(json).expect("parse team response")
+    }
 
-        let mut has_diffs = false;
-        let mut output_buffer = String::new();
+    #[test]
+    fn test_team_property_changes_only_reports_managed_drift() {
+        let current = team_response(json!({
+            "id": 1, "name": "Platform", "slug": "platform", "description": "",
+            "privacy": "secret", "notification_setting": "notifications_enabled",
+            "parent": { "id": 2, "name": "Engineering", "slug": "engineering" }
+        }));
+        let team = Team {
+            name: "platform".to_string(),
+            description: Some("Platform engineering".to_string()),
+            privacy: Some(TeamPrivacy::Closed),
+            ..Default::default()
+        };
+        let changes = team_property_changes(&team, &current);
+        assert_eq!(changes.get("description"), Some(&json!("Platform engineering")));
+        assert_eq!(changes.get("privacy"), Some(&json!("closed")));
+        assert!(!changes.contains_key("notification_setting"));
+
+        let roles: HashMap<String, &'static str> =
+            [("bob".to_string(), TEAM_ROLE_MEMBER), ("alice".to_string(), TEAM_ROLE_MAINTAINER)].into_iter().collect();
+        let converted = team_from_response(current, &roles);
+        assert_eq!(converted.description, None);
+        assert_eq!(converted.parent.as_deref(), Some("engineering"));
+        assert_eq!(converted.members, vec!["bob"]);
+        assert_eq!(converted.maintainers, Some(vec!["alice".to_string()]));
+    }
 
-        // Iterate using the named builder instance
-        for hunk in unified_diff_builder.iter_hunks() { // Now using the variable that lives long enough
-            has_diffs = true;
-            output_buffer.push_str(&format!(
-                "@@ {} @@\n",
-                hunk.header()
-            ));
-            for change in hunk.iter_changes() {
-                match change.tag() {
-                    ChangeTag::Delete => output_buffer.push_str(&format!("{}\n", format!("- {}", change.value().trim_end()).red())),
-                    ChangeTag::Insert => output_buffer.push_str(&format!("{}\n", format!("+ {}", change.value().trim_end()).green())),
-                    ChangeTag::Equal => output_buffer.push_str(&format!("  {}\n", change.value().trim_end())),
-                }
-            }
-        }
+    #[test]
+    fn test_plan_team_memberships_promotes_demotes_and_removes() {
+        let team = Team {
+            name: "platform".to_string(),
+            members: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            maintainers: Some(vec!["carol".to_string(), "dave".to_string()]),
+            ..Default::default()
+        };
+        let current: HashMap<String, &'static str> = [
+            ("alice".to_string(), TEAM_ROLE_MEMBER),
+            ("bob".to_string(), TEAM_ROLE_MAINTAINER),
+            ("carol".to_string(), TEAM_ROLE_MEMBER),
+            ("erin".to_string(), TEAM_ROLE_MEMBER),
+        ].into_iter().collect();
+        let desired = desired_team_roles(&team, &current);
+        assert_eq!(desired["carol"], TEAM_ROLE_MAINTAINER);
+
+        let (to_put, to_remove) = plan_team_memberships(&desired, &current);
+        assert_eq!(to_put, vec![
+            ("bob".to_string(), TEAM_ROLE_MEMBER),
+            ("carol".to_string(), TEAM_ROLE_MAINTAINER),
+            ("dave".to_string(), TEAM_ROLE_MAINTAINER),
+        ]);
+        assert_eq!(to_remove, vec!["erin".to_string()]);
+    }
 
-        if !has_diffs {
-            println!("No differences found between full GitHub org state and local config.");
-        } else {
-            println!("Differences found between full GitHub org state and local config:");
-            println!("--- GitHub Org (Normalized)"); // Adjusted header
-            println!("+++ Local Config (Normalized)"); // Adjusted header
-            print!("{}", output_buffer);
-        }
-        Ok(has_diffs)
+    #[test]
+    fn test_plan_team_memberships_without_maintainers_keeps_roles() {
+        // A config written before `maintainers` existed must not demote anyone
+        let team: Team = serde_yaml::from_str("name: platform\nmembers: [alice, bob, carol]\n").expect("deserialize");
+        assert_eq!(team.maintainers, None);
+        let current: HashMap<String, &'static str> = [
+            ("alice".to_string(), TEAM_ROLE_MAINTAINER),
+            ("bob".to_string(), TEAM_ROLE_MEMBER),
+        ].into_iter().collect();
+        let desired = desired_team_roles(&team, &current);
+        let (to_put, to_remove) = plan_team_memberships(&desired, &current);
+        assert_eq!(to_put, vec![("carol".to_string(), TEAM_ROLE_MEMBER)]);
+        assert!(to_remove.is_empty());
     }
-}
 
+    #[test]
+    fn test_slugify_team_name() {
+        assert_eq!(slugify_team_name("Platform Eng"), "platform-eng");
+        assert_eq!(slugify_team_name("  Dev & Ops!! "), "dev-ops");
+        assert_eq!(slugify_team_name("core_team"), "core_team");
+    }
 
+    #[test]
+    fn test_team_matching_and_rename() {
+        let by_name = Team { name: "Platform Eng".to_string(), ..Default::default() };
+        assert!(team_matches(&by_name, "platform eng", "platform-eng-2"));
+        assert!(team_matches(&by_name, "Something Else", "platform-eng"));
+        assert!(!team_matches(&by_name, "Other", "other"));
+
+        let pinned = Team { name: "Platform Engineering".to_string(), slug: Some("platform-eng".to_string()), ..Default::default() };
+        assert!(team_matches(&pinned, "Platform Eng", "platform-eng"));
+        assert!(!team_matches(&pinned, "Platform Engineering", "platform-engineering"));
+
+        let current = team_response(json!({ "id": 1, "name": "Platform Eng", "slug": "platform-eng" }));
+        assert_eq!(team_property_changes(&pinned, &current).get("name"), Some(&json!("Platform Engineering")));
+        // Without a pinned slug the team was found by name, so it's never renamed
+        assert!(team_property_changes(&by_name, &current).is_empty());
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use mockito;
-    use tokio;
+    #[test]
+    fn test_normalize_team_refs_for_diff() {
+        let mut local = Config {
+            teams: vec![Team { name: "core-team".to_string(), ..Default::default() }],
+            assignments: vec![Assignment { repo: "r".to_string(), team: "Core Team".to_string(), permission: Permission::Write }],
+            ..Default::default()
+        };
+        let mut github = Config {
+            teams: vec![Team { name: "Core Team".to_string(), slug: Some("core-team".to_string()), ..Default::default() }],
+            ..Default::default()
+        };
+        normalize_team_properties_for_diff(&mut local, &mut github);
+        assert_eq!(github.teams[0].name, "core-team");
+        assert_eq!(github.teams[0].slug, None);
+        assert_eq!(local.assignments[0].team, "core-team");
+    }
 
-    #[tokio::test]
-    async fn test_github_client_new() {
-        let client = GitHubClient::new("dummy_token", "dummy_org");
-        assert_eq!(client.token, "dummy_token");
-        assert_eq!(client.org, "dummy_org");
+    #[test]
+    fn test_permission_from_response() {
+        let parse = |json: serde_json::Value| -> TeamRepoResponse { serde_json::from_value(json).expect("parse") };
+        let with_role = parse(json!({
+            "name": "r", "role_name": "security-auditor",
+            "permissions": { "pull": true, "triage": true, "push": false, "maintain": false, "admin": false }
+        }));
+        assert_eq!(permission_from_response(&with_role), Some(Permission::Custom("security-auditor".to_string())));
+
+        let flags_only = parse(json!({
+            "name": "r",
+            "permissions": { "pull": true, "triage": true, "push": true, "maintain": true, "admin": false }
+        }));
+        assert_eq!(permission_from_response(&flags_only), Some(Permission::Maintain));
+
+        let legacy = parse(json!({ "name": "r", "permissions": { "pull": true, "push": false, "admin": false } }));
+        assert_eq!(permission_from_response(&legacy), Some(Permission::Read));
     }
 
     #[test]
-    fn test_get_webhooks_parses_response() {
-        let mut server = mockito::Server::new();
+    fn test_invalid_assignment_permissions() {
+        let assignment = |permission: &str| Assignment {
+            repo: "r".to_string(),
+            team: "t".to_string(),
+            permission: Permission::from(permission),
+        };
+        let assignments = vec![assignment("push"), assignment("auditor"), assignment("wrte")];
+        let custom_roles: HashSet<String> = ["auditor".to_string()].into_iter().collect();
+        let problems = invalid_assignment_permissions(&assignments, &custom_roles);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("'wrte'"));
+
+        let collaborator = |permission: &str| Collaborator { login: "alice".to_string(), permission: Permission::from(permission) };
+        let repos = vec![Repo { name: "web".to_string(), collaborators: Some(vec![collaborator("auditor"), collaborator("maintian")]), ..Default::default() }];
+        let problems = invalid_collaborator_permissions(&repos, &custom_roles);
+        assert_eq!(problems, vec!["collaborator 'alice' on repo 'web' has unknown permission 'maintian'".to_string()]);
+    }
 
-        let _m = server
-            .mock("GET", "/repos/dummy_org/dummy_repo/hooks")
-            .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(
-                r#"
-[
-  {
-    "id": 123,
-    "url": "http://api.github.com/hook/123",
-    "config": {
-      "url": "http://example.com",
-      "content_type": "json"
-    },
-    "events": ["push", "pull_request"]
-  }
-]
-"#,
-            )
-            .create();
+    #[test]
+    fn test_order_teams_parent_first() {
+        let team = |name: &str, parent: Option<&str>| Team {
+            name: name.to_string(),
+            parent: parent.map(String::from),
+            ..Default::default()
+        };
+        let teams = vec![
+            team("leaf", Some("middle")),
+            team("middle", Some("root")),
+            team("root", None),
+            team("external-child", Some("not-in-config")),
+        ];
+        let order: Vec<&str> = order_teams_parent_first(&teams).iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(order, vec!["root", "external-child", "middle", "leaf"]);
+    }
 
-        let rt = tokio::runtime::Runtime::new().expect("create runtime");
-        rt.block_on(async {
-            let mut client = GitHubClient::new("dummy_token", "dummy_org");
-            client.org = "dummy_org".to_string();
+    #[test]
+    fn test_visibility_from_api() {
+        assert_eq!(visibility_from_api(Some("internal"), true), Visibility::Internal);
+        assert_eq!(visibility_from_api(Some("public"), false), Visibility::Public);
+        assert_eq!(visibility_from_api(None, true), Visibility::Private);
+        assert_eq!(visibility_from_api(None, false), Visibility::Public);
+    }
 
-            let url = format!("{}/repos/dummy_org/dummy_repo/hooks", server.url());
-            let response = client.get(&url).await.expect("HTTP GET failed");
-            let text = response.text().await.expect("read response text");
-            let hooks: Vec<WebhookResponse> = serde_json::from_str(&text).expect("parse JSON");
+    #[test]
+    fn test_check_visibility_transition_requires_confirmation_for_public() {
+        assert!(check_visibility_transition("r", Visibility::Private, Visibility::Public, false).is_err());
+        assert!(check_visibility_transition("r", Visibility::Internal, Visibility::Public, false).is_err());
+        assert!(check_visibility_transition("r", Visibility::Private, Visibility::Public, true).is_ok());
+        assert!(check_visibility_transition("r", Visibility::Public, Visibility::Private, false).is_ok());
+        assert!(check_visibility_transition("r", Visibility::Private, Visibility::Internal, false).is_ok());
+    }
 
-            assert_eq!(hooks.len(), 1);
-            let hook = &hooks[0];
-            assert_eq!(hook.id, Some(123));
-            assert_eq!(hook.url, "http://api.github.com/hook/123");
-            assert_eq!(hook.config.url, "http://example.com");
-            assert_eq!(hook.config.content_type, "json");
-            assert_eq!(hook.events, vec!["push", "pull_request"]);
-        });
+    #[test]
+    fn test_check_visibility_transitions_follows_previous_names() {
+        let existing = HashMap::from([
+            ("old-api".to_string(), json!({ "name": "old-api", "visibility": "private", "private": true })),
+            ("web".to_string(), json!({ "name": "web", "visibility": "public", "private": false })),
+        ]);
+        let repo = |name: &str, previous: &[&str]| Repo {
+            name: name.to_string(),
+            previous_names: previous.iter().map(|p| p.to_string()).collect(),
+            visibility: Some(Visibility::Public),
+            ..Default::default()
+        };
+        let repos = vec![repo("web", &[]), repo("new-repo", &[]), repo("api", &["old-api"])];
+        assert!(check_visibility_transitions(&repos, &existing, false).is_err());
+        assert!(check_visibility_transitions(&repos, &existing, true).is_ok());
+        assert!(check_visibility_transitions(&repos[..2], &existing, false).is_ok());
     }
 }
\ No newline at end of file
@@ -1,14 +1,19 @@
+mod access;
 mod config;
 mod error;
 mod github;
+mod ssh_key;
+mod state;
 mod api_mapping;
 mod github_api_mapping_generated;
 
 use clap::{Parser, Subcommand};
+use access::ReportFormat;
 use config::Config;
 use error::AppResult;
-use github::GitHubClient;
+use github::{GitHubClient, PruneScope};
 use log::{error, info};
+use state::StateFile;
 use std::process;
 
 #[derive(Parser, Debug)]
@@ -18,6 +23,10 @@ struct Args {
     #[arg(short, long, env = "GITHUB_TOKEN")]
     token: String,
 
+    /// JSON file recording hashes of write-only values (e.g. webhook secrets) last applied
+    #[arg(long, global = true, env = "GH_CONFIG_STATE_FILE")]
+    state_file: Option<String>,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -36,6 +45,53 @@ enum Command {
         /// Dry run mode (no changes applied, only validation)
         #[arg(long)]
         dry_run: bool,
+        /// Confirm that repositories may be made public
+        #[arg(long)]
+        allow_visibility_change: bool,
+        /// Delete resources of these kinds that aren't in the config (comma separated)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        prune: Vec<PruneScope>,
+    },
+    /// Revoke a user's access everywhere in the org and remove them from the config file
+    Offboard {
+        /// Path to the config file
+        config: String,
+        /// GitHub login of the user to offboard
+        login: String,
+        /// Dry run mode (only print the revocation plan)
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Replace a deploy key with a freshly generated Ed25519 key pair
+    RotateDeployKey {
+        /// Path to the config file
+        config: String,
+        /// Repository the key belongs to
+        repo: String,
+        /// Title of the deploy key
+        title: String,
+        /// Where to write the private key; the public key is written next to it with a .pub extension
+        #[arg(long)]
+        private_key: String,
+        /// Dry run mode (no key is generated and nothing is changed)
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Report every user's effective permission on every repository
+    AccessReport {
+        /// Path to the config file
+        config: String,
+        /// Report format
+        #[arg(long, value_enum, default_value = "csv")]
+        format: ReportFormat,
+        /// Write the report to this file instead of stdout
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Inspect or repair the state file
+    State {
+        #[command(subcommand)]
+        command: StateCommand,
     },
     /// Generate config from a GitHub org and write to file
     SyncFromOrg {
@@ -50,6 +106,18 @@ enum Command {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum StateCommand {
+    /// List the write-only values tracked in the state file
+    Show,
+    /// Forget tracked values, so the next sync sends them again
+    Rm {
+        /// Keys to remove, as listed by `state show`
+        #[arg(required = true)]
+        keys: Vec<String>,
+    },
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::init();
@@ -71,8 +139,12 @@ async fn run() -> AppResult<bool> {
 
     let (command, config_path, _dry_run, _org) = match &args.command {
         Command::Diff { config } => ("diff", config, false, None),
-        Command::Sync { config, dry_run } => ("sync", config, *dry_run, None),
+        Command::Sync { config, dry_run, .. } => ("sync", config, *dry_run, None),
+        Command::Offboard { config, dry_run, .. } => ("offboard", config, *dry_run, None),
+        Command::RotateDeployKey { config, dry_run, .. } => ("rotate-deploy-key", config, *dry_run, None),
+        Command::AccessReport { config, .. } => ("access-report", config, false, None),
         Command::SyncFromOrg { config, dry_run, org } => ("sync-from-org", config, *dry_run, Some(org)),
+        Command::State { command } => return run_state_command(args.state_file.as_deref(), command),
     };
 
     info!("Starting gh-config-cli with command: {}, config: {}", command, config_path);
@@ -97,16 +169,57 @@ async fn run() -> AppResult<bool> {
             GitHubClient::new(&args.token, &config.org)
         }
     };
+    if let Some(state_file) = &args.state_file {
+        client = client.with_state(StateFile::load(state_file)?);
+    }
 
     match args.command {
         Command::Diff { config: _ } => client.diff(config_path).await,
-        Command::Sync { config: _, dry_run } => {
-            client.sync(config_path, dry_run).await?;
+        Command::Sync { config: _, dry_run, allow_visibility_change, ref prune } => {
+            client.sync(config_path, dry_run, allow_visibility_change, prune).await?;
             Ok(false) // Sync completed, no diffs to report
         }
+        Command::Offboard { ref login, config: _, dry_run } => {
+            client.offboard(config_path, login, dry_run).await?;
+            Ok(false) // Offboarding completed, no diffs to report
+        }
+        Command::RotateDeployKey { ref repo, ref title, config: _, ref private_key, dry_run } => {
+            client.rotate_deploy_key(config_path, repo, title, private_key, dry_run).await?;
+            Ok(false) // Key rotated, no diffs to report
+        }
+        Command::AccessReport { config: _, format, ref output } => {
+            client.access_report(format, output.as_deref()).await?;
+            Ok(false) // Report written, no diffs to report
+        }
         Command::SyncFromOrg { config: _, dry_run, org: _ } => {
             client.generate_config_and_write(config_path, dry_run).await?;
             Ok(false) // Sync-from-org completed, no diffs to report
         }
+        Command::State { .. } => unreachable!("state commands are handled before connecting to GitHub"),
+    }
+}
+
+fn run_state_command(state_file: Option<&str>, command: &StateCommand) -> AppResult<bool> {
+    let state_file = state_file.ok_or_else(|| error::AppError::Validation("The state command requires --state-file".to_string()))?;
+    let mut state = StateFile::load(state_file)?;
+    match command {
+        StateCommand::Show => {
+            if state.entries().is_empty() {
+                println!("No write-only values are tracked in {}", state.path().display());
+            }
+            for (key, entry) in state.entries() {
+                println!("{}  sha256:{}", key, entry.hash.get(..12).unwrap_or(&entry.hash));
+            }
+        }
+        StateCommand::Rm { keys } => {
+            for key in keys {
+                if !state.remove(key) {
+                    return Err(error::AppError::Validation(format!("'{}' is not tracked in {}", key, state.path().display())));
+                }
+            }
+            state.save()?;
+            info!("Removed {} entries from {}", keys.len(), state.path().display());
+        }
     }
+    Ok(false)
 }
\ No newline at end of file
@@ -0,0 +1,175 @@
+//! Local state for write-only values.
+//!
+//! GitHub never returns values such as webhook secrets, so the tool can't compare them with the
+//! config. The state file records a salted SHA-256 hash of the last value applied for each of these
+//! fields, which lets diff and sync tell whether a value changed and needs to be sent again.
+
+use crate::error::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const STATE_VERSION: u32 = 1;
+
+/// Salted hash of the last value applied to a write-only field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateEntry {
+    /// Random salt, hex encoded.
+    pub salt: String,
+    /// SHA-256 of the salt followed by the value, hex encoded.
+    pub hash: String,
+}
+
+/// Whether a write-only value matches what was last applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteOnlyStatus {
+    /// The value is the one last applied.
+    Unchanged,
+    /// A different value was last applied.
+    Changed,
+    /// Nothing is recorded for the field, so it's unknown what GitHub has.
+    Untracked,
+}
+
+/// The local state file, keyed by field, e.g. `webhook/my-org/my-repo/https://example.com/hook`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StateFile {
+    #[serde(skip)]
+    path: PathBuf,
+    version: u32,
+    #[serde(default)]
+    entries: BTreeMap<String, StateEntry>,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn salted_hash(salt: &str, value: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(value.as_bytes());
+    to_hex(&hasher.finalize())
+}
+
+/// State key for a webhook's secret. `target` is the repo (`org/repo`) or org the hook belongs to.
+pub fn webhook_secret_key(target: &str, url: &str) -> String {
+    format!("webhook/{}/{}", target, url)
+}
+
+/// State key for an Actions secret. `scope` is the org, repo (`org/repo`) or environment the secret
+/// belongs to.
+pub fn actions_secret_key(scope: &str, name: &str) -> String {
+    format!("actions-secret/{}/{}", scope, name)
+}
+
+impl StateFile {
+    /// Load the state file at `path`. A missing file is treated as empty state.
+    pub fn load(path: impl AsRef<Path>) -> AppResult<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(StateFile { path: path.to_path_buf(), version: STATE_VERSION, ..Default::default() });
+        }
+        let mut state: StateFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if state.version != STATE_VERSION {
+            return Err(AppError::Validation(format!(
+                "State file '{}' has unsupported version {} (expected {})",
+                path.display(),
+                state.version,
+                STATE_VERSION
+            )));
+        }
+        state.path = path.to_path_buf();
+        Ok(state)
+    }
+
+    /// Write the state back to the file it was loaded from. The state is written to a temporary
+    /// file next to it and renamed into place, so a crash mid-write never leaves a truncated file.
+    pub fn save(&self) -> AppResult<()> {
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+        let mut file = std::fs::File::create(&temp_path)?;
+        file.write_all((serde_json::to_string_pretty(self)? + "\n").as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self) -> &BTreeMap<String, StateEntry> {
+        &self.entries
+    }
+
+    /// Compare `value` with the value last recorded for `key`.
+    pub fn status(&self, key: &str, value: &str) -> WriteOnlyStatus {
+        match self.entries.get(key) {
+            Some(entry) if salted_hash(&entry.salt, value) == entry.hash => WriteOnlyStatus::Unchanged,
+            Some(_) => WriteOnlyStatus::Changed,
+            None => WriteOnlyStatus::Untracked,
+        }
+    }
+
+    /// Record `value` as the value last applied for `key`, with a fresh salt.
+    pub fn record(&mut self, key: &str, value: &str) -> AppResult<()> {
+        let mut salt = [0u8; 16];
+        getrandom::getrandom(&mut salt).map_err(std::io::Error::from)?;
+        let salt = to_hex(&salt);
+        let hash = salted_hash(&salt, value);
+        self.entries.insert(key.to_string(), StateEntry { salt, hash });
+        Ok(())
+    }
+
+    /// Forget the value recorded for `key`. Returns false if nothing was recorded.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.entries.remove(key).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_and_record() {
+        let mut state = StateFile::default();
+        let key = webhook_secret_key("my-org/my-repo", "https://example.com/hook");
+        assert_eq!(key, "webhook/my-org/my-repo/https://example.com/hook");
+        assert_eq!(state.status(&key, "s3cret"), WriteOnlyStatus::Untracked);
+
+        state.record(&key, "s3cret").unwrap();
+        assert_eq!(state.status(&key, "s3cret"), WriteOnlyStatus::Unchanged);
+        assert_eq!(state.status(&key, "other"), WriteOnlyStatus::Changed);
+        assert!(!state.entries()[&key].hash.contains("s3cret"));
+
+        assert!(state.remove(&key));
+        assert!(!state.remove(&key));
+        assert_eq!(state.status(&key, "s3cret"), WriteOnlyStatus::Untracked);
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        let mut state = StateFile::load(&path).unwrap();
+        assert!(state.entries().is_empty());
+
+        state.record("webhook/org/https://example.com", "s3cret").unwrap();
+        state.save().unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("\"version\": 1"));
+        assert!(!contents.contains("s3cret"));
+        assert!(!dir.path().join("state.json.tmp").exists());
+
+        let reloaded = StateFile::load(&path).unwrap();
+        assert_eq!(reloaded.status("webhook/org/https://example.com", "s3cret"), WriteOnlyStatus::Unchanged);
+
+        std::fs::write(&path, r#"{"version": 2, "entries": {}}"#).unwrap();
+        assert!(StateFile::load(&path).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use gh_config::api_mapping::get_repo_settings_mapping;
+use gh_config::api_mapping::{get_code_security_configuration_mapping, get_org_settings_mapping, get_repo_settings_mapping, ORG_READ_ONLY_SETTINGS};
 use gh_config::github_api_mapping_generated::get_github_api_mapping;
 
 #[test]
@@ -22,7 +22,7 @@ fn test_mapping_contains_org_field() {
 #[test]
 fn test_unmapped_field_returns_none() {
     let map = get_github_api_mapping();
-    assert!(map.get("this_field_does_not_exist").is_none());
+    assert!(!map.contains_key("this_field_does_not_exist"));
 }
 
 #[test]
@@ -63,5 +63,54 @@ fn test_repo_settings_mapping_fields_are_correct() {
 #[test]
 fn test_repo_settings_mapping_unmapped_key_returns_none() {
     let map = get_repo_settings_mapping();
-    assert!(map.get("this_key_does_not_exist").is_none());
-}
\ No newline at end of file
+    assert!(!map.contains_key("this_key_does_not_exist"));
+}
+
+#[test]
+fn test_org_settings_mapping_targets_org_endpoint() {
+    let map = get_org_settings_mapping();
+    for key in ["billing_email", "default_repository_permission", "members_can_create_repositories", "blog", "web_commit_signoff_required"] {
+        let mapping = map.get(key).unwrap_or_else(|| panic!("Missing key: {}", key));
+        assert_eq!(mapping.endpoint, "/orgs/{org}");
+        assert_eq!(mapping.method, "PATCH");
+        assert_eq!(mapping.json_path, key);
+    }
+    assert!(map.values().all(|m| m.resource_type == "org"));
+    for key in ORG_READ_ONLY_SETTINGS {
+        assert!(!map.contains_key(key), "Read-only key is mapped: {}", key);
+    }
+}
+
+#[test]
+fn test_mappings_read_back_from_matching_endpoints() {
+    let map = get_github_api_mapping();
+    for key in ["allow_merge_commit", "cname", "enabled", "default_workflow_permissions"] {
+        let mapping = map.get(key).unwrap_or_else(|| panic!("Missing key: {}", key));
+        assert_eq!(mapping.read_endpoint, Some(mapping.endpoint), "Wrong read endpoint for {}", key);
+        assert_eq!(mapping.read_json_path, key);
+    }
+    // Branch protection booleans are written as-is but read back as `{"enabled": ...}`
+    for key in ["enforce_admins", "allow_deletions", "required_linear_history"] {
+        let mapping = map.get(key).unwrap_or_else(|| panic!("Missing key: {}", key));
+        assert_eq!(mapping.json_path, key);
+        assert_eq!(mapping.read_json_path, format!("{}.enabled", key));
+    }
+    // POST-only endpoints and fields missing from the GET response can't be read back
+    assert_eq!(map.get("auto_trigger_checks").expect("mapping for auto_trigger_checks").read_endpoint, None);
+    assert_eq!(map.get("wait_timer").expect("mapping for wait_timer").read_endpoint, None);
+
+    let repo_map = get_repo_settings_mapping();
+    assert!(repo_map.values().all(|m| m.read_endpoint == Some("/repos/{org}/{repo}")));
+    assert!(get_org_settings_mapping().values().all(|m| m.read_endpoint == Some("/orgs/{org}")));
+}
+
+#[test]
+fn test_code_security_configuration_mapping_targets_configuration_endpoint() {
+    let map = get_code_security_configuration_mapping();
+    for key in ["advanced_security", "secret_scanning", "secret_scanning_push_protection", "dependabot_alerts", "enforcement"] {
+        let mapping = map.get(key).unwrap_or_else(|| panic!("Missing key: {}", key));
+        assert_eq!(mapping.endpoint, "/orgs/{org}/code-security/configurations/{configuration_id}");
+        assert_eq!(mapping.method, "PATCH");
+    }
+    assert!(!map.contains_key("name"));
+}